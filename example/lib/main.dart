@@ -8,7 +8,8 @@ import 'package:flutter_realtime_player/rust/core/types.dart'
         VideoConfig,
         VideoConfig_WscRtp,
         PlaybinConfig,
-        VideoConfig_Playbin;
+        VideoConfig_Playbin,
+        VideoConfig_Custom;
 import 'wsc_rtp_player.dart';
 import 'wsc_rtp_seek_demo.dart';
 import 'playbin_player.dart';
@@ -304,6 +305,7 @@ class _StreamGridItemState extends State<_StreamGridItem>
                         PlaybinConfig(
                           uri: stream.urlController.text,
                           mute: stream.mute,
+                          autoRestart: stream.autoRestart,
                         ),
                       )
                       : VideoConfig.wscRtp(
@@ -635,6 +637,9 @@ class _VideoPlayerWithControlsState extends State<_VideoPlayerWithControls> {
     return switch (widget.config) {
       VideoConfig_WscRtp(:final field0) => WscRtpPlayerWidget(config: field0),
       VideoConfig_Playbin(:final field0) => PlaybinPlayerWidget(config: field0),
+      VideoConfig_Custom(:final kind) => Center(
+        child: Text('Unsupported backend: $kind'),
+      ),
     };
   }
 }
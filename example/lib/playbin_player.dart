@@ -84,7 +84,7 @@ class _PlaybinPlayerWidgetState extends State<PlaybinPlayerWidget> {
         });
       } else if (event is StreamEvent_Error) {
         setState(() {
-          _error = event.field0;
+          _error = event.field0.message;
         });
       }
     });
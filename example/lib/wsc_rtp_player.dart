@@ -147,7 +147,7 @@ class _WscRtpPlayerWidgetState extends State<WscRtpPlayerWidget> {
         });
       } else if (event is StreamEvent_Error) {
         setState(() {
-          _controlError = event.field0;
+          _controlError = event.field0.message;
         });
       }
     });
@@ -8,13 +8,13 @@ import '../dart_types.dart';
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `SESSION_COUNTER`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `deref`, `initialize`
-
-Future<void> flutterRealtimePlayerInit({required PlatformInt64 ffiPtr}) =>
-    RustLib.instance.api.crateApiSimpleFlutterRealtimePlayerInit(
-      ffiPtr: ffiPtr,
-    );
+Future<void> flutterRealtimePlayerInit({
+  required PlatformInt64 ffiPtr,
+  RuntimeConfig? runtimeConfig,
+}) => RustLib.instance.api.crateApiSimpleFlutterRealtimePlayerInit(
+  ffiPtr: ffiPtr,
+  runtimeConfig: runtimeConfig,
+);
 
 /// updates the counter and returns a session id
 /// note that this doesn't create any resources apart from raising the counter
@@ -25,10 +25,211 @@ Stream<StreamMessage> createPlayable({
   required PlatformInt64 sessionId,
   required PlatformInt64 engineHandle,
   required VideoConfig config,
+  SessionOptions? options,
 }) => RustLib.instance.api.crateApiSimpleCreatePlayable(
   sessionId: sessionId,
   engineHandle: engineHandle,
   config: config,
+  options: options,
+);
+
+/// Replaces the session's source while keeping its texture: the current backend is
+/// stopped and one for `config` renders into the same texture id. The texture shows
+/// `placeholder` (by default the last frame) until the new source's first frame arrives.
+/// Meanwhile the session stays in its current state instead of going back to `Loading`.
+Future<void> switchSource({
+  required PlatformInt64 sessionId,
+  required VideoConfig config,
+  SwitchPlaceholder? placeholder,
+}) => RustLib.instance.api.crateApiSimpleSwitchSource(
+  sessionId: sessionId,
+  config: config,
+  placeholder: placeholder,
+);
+
+/// Scales the session's frames in the decode pipeline, usually to the widget size
+/// in physical pixels. Takes effect without restarting the session.
+Future<void> setOutputSize({
+  required PlatformInt64 sessionId,
+  required int width,
+  required int height,
+  required ScaleMode mode,
+}) => RustLib.instance.api.crateApiSimpleSetOutputSize(
+  sessionId: sessionId,
+  width: width,
+  height: height,
+  mode: mode,
+);
+
+/// Goes back to rendering frames at the decoded size.
+Future<void> resetOutputSize({required PlatformInt64 sessionId}) =>
+    RustLib.instance.api.crateApiSimpleResetOutputSize(sessionId: sessionId);
+
+/// Sets the session's rotation, flips, crop and zoom; applied in the decode pipeline
+/// without restarting the session. `OriginVideoSize` reports the transformed size.
+Future<void> setVideoTransform({
+  required PlatformInt64 sessionId,
+  required VideoTransform transform,
+}) => RustLib.instance.api.crateApiSimpleSetVideoTransform(
+  sessionId: sessionId,
+  transform: transform,
+);
+
+/// Zooms into the session's video around (`pan_x`, `pan_y`), fractions of the cropped frame.
+/// Keeps the rest of the transform; `zoom` 1.0 shows the whole frame.
+Future<void> setDigitalZoom({
+  required PlatformInt64 sessionId,
+  required double zoom,
+  required double panX,
+  required double panY,
+}) => RustLib.instance.api.crateApiSimpleSetDigitalZoom(
+  sessionId: sessionId,
+  zoom: zoom,
+  panX: panX,
+  panY: panY,
+);
+
+/// Caps how many frames per second the session converts and renders, e.g. 5 for
+/// background tiles; `None` renders every frame. The stream keeps decoding in full.
+Future<void> setMaxFrameRate({
+  required PlatformInt64 sessionId,
+  int? maxFrameRate,
+}) => RustLib.instance.api.crateApiSimpleSetMaxFrameRate(
+  sessionId: sessionId,
+  maxFrameRate: maxFrameRate,
+);
+
+/// Sets deinterlacing, brightness, contrast, saturation, hue and gamma of the session's
+/// video. Applied in the decode pipeline without restarting the session.
+Future<void> setImageAdjustments({
+  required PlatformInt64 sessionId,
+  required ImageAdjustments adjustments,
+}) => RustLib.instance.api.crateApiSimpleSetImageAdjustments(
+  sessionId: sessionId,
+  adjustments: adjustments,
+);
+
+/// Taps the session's decoded frames, after rotation, crop and zoom, at the rate, size
+/// and pixel format of `config`; returns the tap id for `next_frame` and `unsubscribe_frames`.
+/// The session's own `max_frame_rate` doesn't limit the tap.
+/// Up to `queue_len` frames wait for `next_frame`; further ones are dropped, so a slow
+/// isolate never makes frames pile up.
+Future<PlatformInt64> subscribeFrames({
+  required PlatformInt64 sessionId,
+  FrameTapConfig? config,
+}) => RustLib.instance.api.crateApiSimpleSubscribeFrames(
+  sessionId: sessionId,
+  config: config,
+);
+
+/// Waits for the tap's next frame; `None` once it was unsubscribed or the session ended.
+Future<TapFrame?> nextFrame({
+  required PlatformInt64 sessionId,
+  required PlatformInt64 tapId,
+}) => RustLib.instance.api.crateApiSimpleNextFrame(
+  sessionId: sessionId,
+  tapId: tapId,
+);
+
+Future<void> unsubscribeFrames({
+  required PlatformInt64 sessionId,
+  required PlatformInt64 tapId,
+}) => RustLib.instance.api.crateApiSimpleUnsubscribeFrames(
+  sessionId: sessionId,
+  tapId: tapId,
+);
+
+/// Replaces the session's presentation policy (placeholder, stop/error frame, reconnect overlay).
+/// The initial policy comes from `SessionOptions.presentation`.
+Future<void> setPresentationPolicy({
+  required PlatformInt64 sessionId,
+  required PresentationPolicy policy,
+}) => RustLib.instance.api.crateApiSimpleSetPresentationPolicy(
+  sessionId: sessionId,
+  policy: policy,
+);
+
+/// Encodes the session's current frame to PNG or JPEG (`quality` 0-100, default 85),
+/// downscaled so its longer side is at most `max_size`. With `full_resolution` the next
+/// decoded frame is captured before any transform or output scaling instead.
+Future<Snapshot> captureSnapshot({
+  required PlatformInt64 sessionId,
+  required SnapshotFormat format,
+  int? quality,
+  int? maxSize,
+  required bool fullResolution,
+}) => RustLib.instance.api.crateApiSimpleCaptureSnapshot(
+  sessionId: sessionId,
+  format: format,
+  quality: quality,
+  maxSize: maxSize,
+  fullResolution: fullResolution,
+);
+
+/// Like `capture_snapshot`, but writes the image to `path`; the returned `data` is empty.
+Future<Snapshot> captureSnapshotToFile({
+  required PlatformInt64 sessionId,
+  required String path,
+  required SnapshotFormat format,
+  int? quality,
+  int? maxSize,
+  required bool fullResolution,
+}) => RustLib.instance.api.crateApiSimpleCaptureSnapshotToFile(
+  sessionId: sessionId,
+  path: path,
+  format: format,
+  quality: quality,
+  maxSize: maxSize,
+  fullResolution: fullResolution,
+);
+
+/// Records the session's stream to `path` as received, without re-encoding. Writing
+/// starts at the next keyframe; the recording stops by itself after `max_duration_ms`
+/// or `max_size_bytes`. With `segments` the files are numbered, e.g. `clip_00000.mp4`.
+/// `RecordingStarted`, `RecordingStopped` and `RecordingError` events report progress.
+Future<void> startRecording({
+  required PlatformInt64 sessionId,
+  required String path,
+  RecordingContainer? container,
+  BigInt? maxDurationMs,
+  BigInt? maxSizeBytes,
+  RecordingSegments? segments,
+}) => RustLib.instance.api.crateApiSimpleStartRecording(
+  sessionId: sessionId,
+  path: path,
+  container: container,
+  maxDurationMs: maxDurationMs,
+  maxSizeBytes: maxSizeBytes,
+  segments: segments,
+);
+
+/// Finalizes the recording and returns the written files.
+Future<RecordingInfo> stopRecording({required PlatformInt64 sessionId}) =>
+    RustLib.instance.api.crateApiSimpleStopRecording(sessionId: sessionId);
+
+/// Keeps the last `config.duration_ms` of the encoded stream in memory (`None` disables it),
+/// for `save_pre_event_clip`. The initial buffer comes from `SessionOptions.pre_event_buffer`.
+Future<void> setPreEventBuffer({
+  required PlatformInt64 sessionId,
+  PreEventBuffer? config,
+}) => RustLib.instance.api.crateApiSimpleSetPreEventBuffer(
+  sessionId: sessionId,
+  config: config,
+);
+
+/// Saves what happened before and after an incident: the pre-event buffer plus the next
+/// `post_event_ms` of the stream, written to `path` (MP4 by default). Completes with the
+/// written file once it is finalized; `Recording*` events are sent as for `start_recording`.
+Future<RecordingInfo> savePreEventClip({
+  required PlatformInt64 sessionId,
+  required String path,
+  required BigInt postEventMs,
+  RecordingContainer? container,
+}) => RustLib.instance.api.crateApiSimpleSavePreEventClip(
+  sessionId: sessionId,
+  path: path,
+  postEventMs: postEventMs,
+  container: container,
 );
 
 Future<void> seekToTimestamp({
@@ -50,9 +251,22 @@ Future<void> setSpeed({
   speed: speed,
 );
 
+/// Moves the textures of `target_session_id` onto the standby session `standby_session_id`
+/// (created with `SessionOptions.standby`) and stops the target's previous source.
+/// The target keeps its session id and texture id; the standby session id is released.
+Future<void> promoteStandbySession({
+  required PlatformInt64 standbySessionId,
+  required PlatformInt64 targetSessionId,
+}) => RustLib.instance.api.crateApiSimplePromoteStandbySession(
+  standbySessionId: standbySessionId,
+  targetSessionId: targetSessionId,
+);
+
 /// marks the session as required by the ui
-/// if the ui won't call this every 2 seconds
+/// if the ui won't call this within the session's keep-alive timeout
+/// (5 seconds unless set via `SessionOptions.keep_alive`)
 /// this session will be terminate.
+/// a shared decode keeps running as long as any session attached to it is alive.
 Future<void> markSessionAlive({required PlatformInt64 sessionId}) =>
     RustLib.instance.api.crateApiSimpleMarkSessionAlive(sessionId: sessionId);
 
@@ -63,3 +277,13 @@ Future<void> destroyStreamSession({required PlatformInt64 sessionId}) => RustLib
     .instance
     .api
     .crateApiSimpleDestroyStreamSession(sessionId: sessionId);
+
+/// Destroys the session and resolves once its pipeline is stopped and its texture released.
+/// Fails if that didn't happen within `timeout_ms` (default 5000ms).
+Future<void> destroyStreamSessionAndWait({
+  required PlatformInt64 sessionId,
+  BigInt? timeoutMs,
+}) => RustLib.instance.api.crateApiSimpleDestroyStreamSessionAndWait(
+  sessionId: sessionId,
+  timeoutMs: timeoutMs,
+);
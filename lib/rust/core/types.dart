@@ -8,14 +8,263 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'types.freezed.dart';
 
+/// Rectangle of the decoded frame, as fractions (0.0..=1.0) of its width and height.
+class CropRect {
+  final double x;
+  final double y;
+  final double width;
+  final double height;
+
+  const CropRect({
+    required this.x,
+    required this.y,
+    required this.width,
+    required this.height,
+  });
+
+  @override
+  int get hashCode =>
+      x.hashCode ^
+      y.hashCode ^
+      width.hashCode ^
+      height.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CropRect &&
+          runtimeType == other.runtimeType &&
+          x == other.x &&
+          y == other.y &&
+          width == other.width &&
+          height == other.height;
+}
+
+enum DeinterlaceMethod { linear, greedyHigh, greedyLow, vfir, scalerBob, yadif }
+
+enum DeinterlaceMode {
+  /// Deinterlace only streams whose caps say they are interlaced.
+  auto,
+
+  /// Always deinterlace.
+  on,
+  off,
+}
+
+/// What a texture shows once its session stopped or failed.
+@freezed
+sealed class EndFramePolicy with _$EndFramePolicy {
+  const EndFramePolicy._();
+
+  const factory EndFramePolicy.holdLastFrame() = EndFramePolicy_HoldLastFrame;
+
+  /// Hold the last frame at `brightness_percent` of its brightness.
+  const factory EndFramePolicy.dim({required int brightnessPercent}) =
+      EndFramePolicy_Dim;
+
+  /// Go back to the placeholder.
+  const factory EndFramePolicy.clear() = EndFramePolicy_Clear;
+}
+
+/// Where a session's decoded frames go.
+@freezed
+sealed class FrameOutputMode with _$FrameOutputMode {
+  const FrameOutputMode._();
+
+  /// A Flutter texture registered with the session's engine.
+  const factory FrameOutputMode.flutterTexture() =
+      FrameOutputMode_FlutterTexture;
+
+  /// An in-memory sink keeping the latest frame, a frame counter and up to
+  /// `history_len` recent frames. Needs no Flutter engine (tests, CLI hosts).
+  const factory FrameOutputMode.headless({required int historyLen}) =
+      FrameOutputMode_Headless;
+}
+
+/// What a frame tap subscribed with `subscribe_frames` delivers.
+class FrameTapConfig {
+  /// Frames above this rate are skipped; `None` delivers every decoded frame.
+  /// Independent of the session's own `max_frame_rate`.
+  final int? maxFrameRate;
+
+  /// Scale the frames after rotation, crop and zoom; `None` keeps that size.
+  final OutputSize? size;
+  final TapPixelFormat format;
+
+  /// Frames waiting for the consumer; further frames are dropped while it is full.
+  final int queueLen;
+
+  const FrameTapConfig({
+    this.maxFrameRate,
+    this.size,
+    required this.format,
+    required this.queueLen,
+  });
+
+  @override
+  int get hashCode =>
+      maxFrameRate.hashCode ^
+      size.hashCode ^
+      format.hashCode ^
+      queueLen.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrameTapConfig &&
+          runtimeType == other.runtimeType &&
+          maxFrameRate == other.maxFrameRate &&
+          size == other.size &&
+          format == other.format &&
+          queueLen == other.queueLen;
+}
+
+/// Image processing applied in the decode pipeline, ahead of any geometry transform.
+/// The defaults leave the video untouched and cost nothing.
+class ImageAdjustments {
+  final DeinterlaceMode deinterlace;
+  final DeinterlaceMethod deinterlaceMethod;
+
+  /// -1.0..=1.0, default 0.0.
+  final double brightness;
+
+  /// 0.0..=2.0, default 1.0.
+  final double contrast;
+
+  /// 0.0..=2.0, default 1.0.
+  final double saturation;
+
+  /// -1.0..=1.0, default 0.0.
+  final double hue;
+
+  /// 0.01..=10.0, default 1.0.
+  final double gamma;
+
+  const ImageAdjustments({
+    required this.deinterlace,
+    required this.deinterlaceMethod,
+    required this.brightness,
+    required this.contrast,
+    required this.saturation,
+    required this.hue,
+    required this.gamma,
+  });
+
+  @override
+  int get hashCode =>
+      deinterlace.hashCode ^
+      deinterlaceMethod.hashCode ^
+      brightness.hashCode ^
+      contrast.hashCode ^
+      saturation.hashCode ^
+      hue.hashCode ^
+      gamma.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImageAdjustments &&
+          runtimeType == other.runtimeType &&
+          deinterlace == other.deinterlace &&
+          deinterlaceMethod == other.deinterlaceMethod &&
+          brightness == other.brightness &&
+          contrast == other.contrast &&
+          saturation == other.saturation &&
+          hue == other.hue &&
+          gamma == other.gamma;
+}
+
+@freezed
+sealed class KeepAlivePolicy with _$KeepAlivePolicy {
+  const KeepAlivePolicy._();
+
+  /// Reap the session if `mark_session_alive` wasn't called within `timeout_ms`.
+  /// Shared sessions keep decoding until their last consumer is reaped or destroyed.
+  const factory KeepAlivePolicy.timeout({required BigInt timeoutMs}) =
+      KeepAlivePolicy_Timeout;
+
+  /// Never reap the session; it lives until destroyed explicitly (e.g. background sessions).
+  const factory KeepAlivePolicy.disabled() = KeepAlivePolicy_Disabled;
+}
+
+/// Decode-side size of a session's frames, usually the rendered widget size in physical pixels.
+/// `Fit` and `Fill` never upscale.
+class OutputSize {
+  final int width;
+  final int height;
+  final ScaleMode mode;
+
+  const OutputSize({
+    required this.width,
+    required this.height,
+    required this.mode,
+  });
+
+  @override
+  int get hashCode => width.hashCode ^ height.hashCode ^ mode.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OutputSize &&
+          runtimeType == other.runtimeType &&
+          width == other.width &&
+          height == other.height &&
+          mode == other.mode;
+}
+
+/// What a texture shows before its session's first frame.
+@freezed
+sealed class PlaceholderFrame with _$PlaceholderFrame {
+  const PlaceholderFrame._();
+
+  const factory PlaceholderFrame.black() = PlaceholderFrame_Black;
+  const factory PlaceholderFrame.transparent() = PlaceholderFrame_Transparent;
+
+  /// Tightly packed RGBA pixels supplied by the app.
+  const factory PlaceholderFrame.rgba({
+    required int width,
+    required int height,
+    required Uint8List data,
+  }) = PlaceholderFrame_Rgba;
+
+  /// An image decoded with GStreamer, e.g. `file:///path/poster.jpg`.
+  /// Black is shown until it is decoded.
+  const factory PlaceholderFrame.poster({required String uri}) =
+      PlaceholderFrame_Poster;
+}
+
 class PlaybinConfig {
   final String uri;
   final bool mute;
 
-  const PlaybinConfig({required this.uri, required this.mute});
+  /// Rebuild the pipeline with backoff after an error, a stall or the end of a live
+  /// source. Finite files stop at EOS.
+  final bool autoRestart;
+
+  /// Restart if no new sample arrived for this long, for live and non-live sources.
+  /// `None` disables stall detection.
+  final BigInt? stallTimeoutMs;
+
+  /// With `auto_restart`, give up with an `Error` state after this many restarts in a
+  /// row that did not deliver a frame. `None` retries forever.
+  final int? maxRestartAttempts;
+
+  const PlaybinConfig({
+    required this.uri,
+    required this.mute,
+    required this.autoRestart,
+    this.stallTimeoutMs,
+    this.maxRestartAttempts,
+  });
 
   @override
-  int get hashCode => uri.hashCode ^ mute.hashCode;
+  int get hashCode =>
+      uri.hashCode ^
+      mute.hashCode ^
+      autoRestart.hashCode ^
+      stallTimeoutMs.hashCode ^
+      maxRestartAttempts.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -23,7 +272,245 @@ class PlaybinConfig {
       other is PlaybinConfig &&
           runtimeType == other.runtimeType &&
           uri == other.uri &&
-          mute == other.mute;
+          mute == other.mute &&
+          autoRestart == other.autoRestart &&
+          stallTimeoutMs == other.stallTimeoutMs &&
+          maxRestartAttempts == other.maxRestartAttempts;
+}
+
+/// In-memory ring of the latest encoded packets, for `save_pre_event_clip`.
+class PreEventBuffer {
+  /// At least this much is kept, rounded up to the start of a GOP.
+  final BigInt durationMs;
+
+  /// Older GOPs are dropped beyond this, even if the duration isn't covered.
+  /// Defaults to 64 MiB.
+  final BigInt? maxBytes;
+
+  const PreEventBuffer({required this.durationMs, this.maxBytes});
+
+  @override
+  int get hashCode => durationMs.hashCode ^ maxBytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PreEventBuffer &&
+          runtimeType == other.runtimeType &&
+          durationMs == other.durationMs &&
+          maxBytes == other.maxBytes;
+}
+
+/// How a session's textures present the phases without decoded frames.
+class PresentationPolicy {
+  final PlaceholderFrame placeholder;
+  final EndFramePolicy onStop;
+  final EndFramePolicy onError;
+
+  /// The last frame is held while reconnecting; if set, this ARGB color is blended over it.
+  final int? reconnectOverlayArgb;
+
+  const PresentationPolicy({
+    required this.placeholder,
+    required this.onStop,
+    required this.onError,
+    this.reconnectOverlayArgb,
+  });
+
+  @override
+  int get hashCode =>
+      placeholder.hashCode ^
+      onStop.hashCode ^
+      onError.hashCode ^
+      reconnectOverlayArgb.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PresentationPolicy &&
+          runtimeType == other.runtimeType &&
+          placeholder == other.placeholder &&
+          onStop == other.onStop &&
+          onError == other.onError &&
+          reconnectOverlayArgb == other.reconnectOverlayArgb;
+}
+
+enum RecordingContainer { mp4, mkv }
+
+/// Splits a recording into consecutive files.
+class RecordingSegments {
+  /// Files are cut at the first keyframe after this length.
+  final BigInt durationMs;
+
+  /// Keep only the newest files, reusing the oldest file names (ring recording).
+  final int? maxFiles;
+
+  const RecordingSegments({required this.durationMs, this.maxFiles});
+
+  @override
+  int get hashCode => durationMs.hashCode ^ maxFiles.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RecordingSegments &&
+          runtimeType == other.runtimeType &&
+          durationMs == other.durationMs &&
+          maxFiles == other.maxFiles;
+}
+
+/// Clockwise rotation of a session's video.
+enum Rotation { none, clockwise90, clockwise180, clockwise270 }
+
+/// Configuration of the tokio runtime owned by the crate.
+class RuntimeConfig {
+  /// Defaults to the number of CPU cores.
+  final int? workerThreads;
+
+  /// Defaults to "flutter-realtime-player".
+  final String? threadName;
+
+  const RuntimeConfig({this.workerThreads, this.threadName});
+
+  @override
+  int get hashCode => workerThreads.hashCode ^ threadName.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RuntimeConfig &&
+          runtimeType == other.runtimeType &&
+          workerThreads == other.workerThreads &&
+          threadName == other.threadName;
+}
+
+/// How `OutputSize` maps the decoded video onto the requested box.
+enum ScaleMode {
+  /// Largest size inside the box keeping the aspect ratio.
+  fit,
+
+  /// Covers the box keeping the aspect ratio; the overflow is cropped around the center,
+  /// so frames are exactly the box, or smaller with its aspect ratio since they're never upscaled.
+  fill,
+
+  /// Exactly the box, stretching the video.
+  exact,
+}
+
+/// Backend-independent per-session options.
+class SessionOptions {
+  final StallWatchdogConfig? stallWatchdog;
+
+  /// Defaults to a 5 second timeout.
+  final KeepAlivePolicy? keepAlive;
+
+  /// If set, `create_playable` waits up to this long for the first decoded frame
+  /// and fails with the startup error instead of returning right away.
+  final BigInt? awaitFirstFrameTimeoutMs;
+
+  /// Attach to a running session with an equal `VideoConfig` (also created with
+  /// `share_decode`) instead of connecting and decoding a second time.
+  /// The new session id still gets its own texture, sink and keep-alive.
+  final bool shareDecode;
+
+  /// Connect without creating a texture, ready to be moved onto another session's
+  /// texture with `promote_standby_session`. Until then only keyframes are decoded,
+  /// so a stall watchdog threshold should exceed the stream's keyframe interval.
+  /// Not combined with `share_decode`.
+  final bool standby;
+
+  /// Ignored for standby sessions, which have no frame output until promoted.
+  final FrameOutputMode frameOutput;
+
+  /// Shared sessions use the policy of the session that started the decode.
+  final PresentationPolicy presentation;
+
+  /// Cap on rendered frames per second; decoding keeps running at the stream's rate.
+  /// Changeable later with `set_max_frame_rate`.
+  final int? maxFrameRate;
+
+  /// Keep the last seconds of the encoded stream for `save_pre_event_clip`.
+  /// Changeable later with `set_pre_event_buffer`.
+  final PreEventBuffer? preEventBuffer;
+
+  const SessionOptions({
+    this.stallWatchdog,
+    this.keepAlive,
+    this.awaitFirstFrameTimeoutMs,
+    required this.shareDecode,
+    required this.standby,
+    required this.frameOutput,
+    required this.presentation,
+    this.maxFrameRate,
+    this.preEventBuffer,
+  });
+
+  @override
+  int get hashCode =>
+      stallWatchdog.hashCode ^
+      keepAlive.hashCode ^
+      awaitFirstFrameTimeoutMs.hashCode ^
+      shareDecode.hashCode ^
+      standby.hashCode ^
+      frameOutput.hashCode ^
+      presentation.hashCode ^
+      maxFrameRate.hashCode ^
+      preEventBuffer.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SessionOptions &&
+          runtimeType == other.runtimeType &&
+          stallWatchdog == other.stallWatchdog &&
+          keepAlive == other.keepAlive &&
+          awaitFirstFrameTimeoutMs == other.awaitFirstFrameTimeoutMs &&
+          shareDecode == other.shareDecode &&
+          standby == other.standby &&
+          frameOutput == other.frameOutput &&
+          presentation == other.presentation &&
+          maxFrameRate == other.maxFrameRate &&
+          preEventBuffer == other.preEventBuffer;
+}
+
+enum SnapshotFormat { png, jpeg }
+
+class StallWatchdogConfig {
+  /// Report `StreamEvent::Stalled` after this long without a new frame while playing.
+  final BigInt stallThresholdMs;
+
+  /// Ask the backend to reconnect once the stall lasted this long.
+  /// `None` only reports stalls.
+  final BigInt? reconnectAfterMs;
+
+  const StallWatchdogConfig({
+    required this.stallThresholdMs,
+    this.reconnectAfterMs,
+  });
+
+  @override
+  int get hashCode => stallThresholdMs.hashCode ^ reconnectAfterMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StallWatchdogConfig &&
+          runtimeType == other.runtimeType &&
+          stallThresholdMs == other.stallThresholdMs &&
+          reconnectAfterMs == other.reconnectAfterMs;
+}
+
+/// What a session's texture shows between `switch_source` and the new source's first frame.
+enum SwitchPlaceholder { holdLastFrame, black }
+
+/// Pixel format of the frames delivered by a frame tap.
+enum TapPixelFormat {
+  rgba,
+  rgb,
+
+  /// Y plane followed by an interleaved UV plane at half resolution.
+  nv12,
+  gray8,
 }
 
 @freezed
@@ -33,6 +520,61 @@ sealed class VideoConfig with _$VideoConfig {
   const factory VideoConfig.wscRtp(WscRtpSessionConfig field0) =
       VideoConfig_WscRtp;
   const factory VideoConfig.playbin(PlaybinConfig field0) = VideoConfig_Playbin;
+
+  /// A backend registered by the embedding crate with `input::factory::register_backend`.
+  const factory VideoConfig.custom({
+    required String kind,
+    required String jsonParams,
+  }) = VideoConfig_Custom;
+}
+
+/// Geometry applied in the decode pipeline, before the format conversion.
+/// Crop, zoom and pan are in decoded-frame coordinates; flips are applied before the rotation.
+class VideoTransform {
+  final Rotation rotation;
+  final bool flipHorizontal;
+  final bool flipVertical;
+  final CropRect? crop;
+
+  /// 1.0 shows the whole (cropped) frame, 2.0 half of its width and height.
+  final double zoom;
+
+  /// Center of the zoomed region within the cropped frame, 0.0..=1.0.
+  final double panX;
+  final double panY;
+
+  const VideoTransform({
+    required this.rotation,
+    required this.flipHorizontal,
+    required this.flipVertical,
+    this.crop,
+    required this.zoom,
+    required this.panX,
+    required this.panY,
+  });
+
+  @override
+  int get hashCode =>
+      rotation.hashCode ^
+      flipHorizontal.hashCode ^
+      flipVertical.hashCode ^
+      crop.hashCode ^
+      zoom.hashCode ^
+      panX.hashCode ^
+      panY.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is VideoTransform &&
+          runtimeType == other.runtimeType &&
+          rotation == other.rotation &&
+          flipHorizontal == other.flipHorizontal &&
+          flipVertical == other.flipVertical &&
+          crop == other.crop &&
+          zoom == other.zoom &&
+          panX == other.panX &&
+          panY == other.panY;
 }
 
 class WscRtpSessionConfig {
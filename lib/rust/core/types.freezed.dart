@@ -11,25 +11,1209 @@ part of 'types.dart';
 
 // dart format off
 T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$EndFramePolicy {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EndFramePolicy);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'EndFramePolicy()';
+}
+
+
+}
+
+/// @nodoc
+class $EndFramePolicyCopyWith<$Res>  {
+$EndFramePolicyCopyWith(EndFramePolicy _, $Res Function(EndFramePolicy) __);
+}
+
+
+/// Adds pattern-matching-related methods to [EndFramePolicy].
+extension EndFramePolicyPatterns on EndFramePolicy {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( EndFramePolicy_HoldLastFrame value)?  holdLastFrame,TResult Function( EndFramePolicy_Dim value)?  dim,TResult Function( EndFramePolicy_Clear value)?  clear,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case EndFramePolicy_HoldLastFrame() when holdLastFrame != null:
+return holdLastFrame(_that);case EndFramePolicy_Dim() when dim != null:
+return dim(_that);case EndFramePolicy_Clear() when clear != null:
+return clear(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( EndFramePolicy_HoldLastFrame value)  holdLastFrame,required TResult Function( EndFramePolicy_Dim value)  dim,required TResult Function( EndFramePolicy_Clear value)  clear,}){
+final _that = this;
+switch (_that) {
+case EndFramePolicy_HoldLastFrame():
+return holdLastFrame(_that);case EndFramePolicy_Dim():
+return dim(_that);case EndFramePolicy_Clear():
+return clear(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( EndFramePolicy_HoldLastFrame value)?  holdLastFrame,TResult? Function( EndFramePolicy_Dim value)?  dim,TResult? Function( EndFramePolicy_Clear value)?  clear,}){
+final _that = this;
+switch (_that) {
+case EndFramePolicy_HoldLastFrame() when holdLastFrame != null:
+return holdLastFrame(_that);case EndFramePolicy_Dim() when dim != null:
+return dim(_that);case EndFramePolicy_Clear() when clear != null:
+return clear(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function()?  holdLastFrame,TResult Function( int brightnessPercent)?  dim,TResult Function()?  clear,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case EndFramePolicy_HoldLastFrame() when holdLastFrame != null:
+return holdLastFrame();case EndFramePolicy_Dim() when dim != null:
+return dim(_that.brightnessPercent);case EndFramePolicy_Clear() when clear != null:
+return clear();case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function()  holdLastFrame,required TResult Function( int brightnessPercent)  dim,required TResult Function()  clear,}) {final _that = this;
+switch (_that) {
+case EndFramePolicy_HoldLastFrame():
+return holdLastFrame();case EndFramePolicy_Dim():
+return dim(_that.brightnessPercent);case EndFramePolicy_Clear():
+return clear();}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function()?  holdLastFrame,TResult? Function( int brightnessPercent)?  dim,TResult? Function()?  clear,}) {final _that = this;
+switch (_that) {
+case EndFramePolicy_HoldLastFrame() when holdLastFrame != null:
+return holdLastFrame();case EndFramePolicy_Dim() when dim != null:
+return dim(_that.brightnessPercent);case EndFramePolicy_Clear() when clear != null:
+return clear();case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class EndFramePolicy_HoldLastFrame extends EndFramePolicy {
+  const EndFramePolicy_HoldLastFrame(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EndFramePolicy_HoldLastFrame);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'EndFramePolicy.holdLastFrame()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class EndFramePolicy_Dim extends EndFramePolicy {
+  const EndFramePolicy_Dim({required this.brightnessPercent}): super._();
+  
+
+ final  int brightnessPercent;
+
+/// Create a copy of EndFramePolicy
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$EndFramePolicy_DimCopyWith<EndFramePolicy_Dim> get copyWith => _$EndFramePolicy_DimCopyWithImpl<EndFramePolicy_Dim>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EndFramePolicy_Dim&&(identical(other.brightnessPercent, brightnessPercent) || other.brightnessPercent == brightnessPercent));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,brightnessPercent);
+
+@override
+String toString() {
+  return 'EndFramePolicy.dim(brightnessPercent: $brightnessPercent)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $EndFramePolicy_DimCopyWith<$Res> implements $EndFramePolicyCopyWith<$Res> {
+  factory $EndFramePolicy_DimCopyWith(EndFramePolicy_Dim value, $Res Function(EndFramePolicy_Dim) _then) = _$EndFramePolicy_DimCopyWithImpl;
+@useResult
+$Res call({
+ int brightnessPercent
+});
+
+
+
+
+}
+/// @nodoc
+class _$EndFramePolicy_DimCopyWithImpl<$Res>
+    implements $EndFramePolicy_DimCopyWith<$Res> {
+  _$EndFramePolicy_DimCopyWithImpl(this._self, this._then);
+
+  final EndFramePolicy_Dim _self;
+  final $Res Function(EndFramePolicy_Dim) _then;
+
+/// Create a copy of EndFramePolicy
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? brightnessPercent = null,}) {
+  return _then(EndFramePolicy_Dim(
+brightnessPercent: null == brightnessPercent ? _self.brightnessPercent : brightnessPercent // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class EndFramePolicy_Clear extends EndFramePolicy {
+  const EndFramePolicy_Clear(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EndFramePolicy_Clear);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'EndFramePolicy.clear()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+mixin _$FrameOutputMode {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is FrameOutputMode);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'FrameOutputMode()';
+}
+
+
+}
+
+/// @nodoc
+class $FrameOutputModeCopyWith<$Res>  {
+$FrameOutputModeCopyWith(FrameOutputMode _, $Res Function(FrameOutputMode) __);
+}
+
+
+/// Adds pattern-matching-related methods to [FrameOutputMode].
+extension FrameOutputModePatterns on FrameOutputMode {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( FrameOutputMode_FlutterTexture value)?  flutterTexture,TResult Function( FrameOutputMode_Headless value)?  headless,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case FrameOutputMode_FlutterTexture() when flutterTexture != null:
+return flutterTexture(_that);case FrameOutputMode_Headless() when headless != null:
+return headless(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( FrameOutputMode_FlutterTexture value)  flutterTexture,required TResult Function( FrameOutputMode_Headless value)  headless,}){
+final _that = this;
+switch (_that) {
+case FrameOutputMode_FlutterTexture():
+return flutterTexture(_that);case FrameOutputMode_Headless():
+return headless(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( FrameOutputMode_FlutterTexture value)?  flutterTexture,TResult? Function( FrameOutputMode_Headless value)?  headless,}){
+final _that = this;
+switch (_that) {
+case FrameOutputMode_FlutterTexture() when flutterTexture != null:
+return flutterTexture(_that);case FrameOutputMode_Headless() when headless != null:
+return headless(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function()?  flutterTexture,TResult Function( int historyLen)?  headless,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case FrameOutputMode_FlutterTexture() when flutterTexture != null:
+return flutterTexture();case FrameOutputMode_Headless() when headless != null:
+return headless(_that.historyLen);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function()  flutterTexture,required TResult Function( int historyLen)  headless,}) {final _that = this;
+switch (_that) {
+case FrameOutputMode_FlutterTexture():
+return flutterTexture();case FrameOutputMode_Headless():
+return headless(_that.historyLen);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function()?  flutterTexture,TResult? Function( int historyLen)?  headless,}) {final _that = this;
+switch (_that) {
+case FrameOutputMode_FlutterTexture() when flutterTexture != null:
+return flutterTexture();case FrameOutputMode_Headless() when headless != null:
+return headless(_that.historyLen);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class FrameOutputMode_FlutterTexture extends FrameOutputMode {
+  const FrameOutputMode_FlutterTexture(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is FrameOutputMode_FlutterTexture);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'FrameOutputMode.flutterTexture()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class FrameOutputMode_Headless extends FrameOutputMode {
+  const FrameOutputMode_Headless({required this.historyLen}): super._();
+  
+
+ final  int historyLen;
+
+/// Create a copy of FrameOutputMode
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$FrameOutputMode_HeadlessCopyWith<FrameOutputMode_Headless> get copyWith => _$FrameOutputMode_HeadlessCopyWithImpl<FrameOutputMode_Headless>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is FrameOutputMode_Headless&&(identical(other.historyLen, historyLen) || other.historyLen == historyLen));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,historyLen);
+
+@override
+String toString() {
+  return 'FrameOutputMode.headless(historyLen: $historyLen)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $FrameOutputMode_HeadlessCopyWith<$Res> implements $FrameOutputModeCopyWith<$Res> {
+  factory $FrameOutputMode_HeadlessCopyWith(FrameOutputMode_Headless value, $Res Function(FrameOutputMode_Headless) _then) = _$FrameOutputMode_HeadlessCopyWithImpl;
+@useResult
+$Res call({
+ int historyLen
+});
+
+
+
+
+}
+/// @nodoc
+class _$FrameOutputMode_HeadlessCopyWithImpl<$Res>
+    implements $FrameOutputMode_HeadlessCopyWith<$Res> {
+  _$FrameOutputMode_HeadlessCopyWithImpl(this._self, this._then);
+
+  final FrameOutputMode_Headless _self;
+  final $Res Function(FrameOutputMode_Headless) _then;
+
+/// Create a copy of FrameOutputMode
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? historyLen = null,}) {
+  return _then(FrameOutputMode_Headless(
+historyLen: null == historyLen ? _self.historyLen : historyLen // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$KeepAlivePolicy {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is KeepAlivePolicy);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'KeepAlivePolicy()';
+}
+
+
+}
+
+/// @nodoc
+class $KeepAlivePolicyCopyWith<$Res>  {
+$KeepAlivePolicyCopyWith(KeepAlivePolicy _, $Res Function(KeepAlivePolicy) __);
+}
+
+
+/// Adds pattern-matching-related methods to [KeepAlivePolicy].
+extension KeepAlivePolicyPatterns on KeepAlivePolicy {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( KeepAlivePolicy_Timeout value)?  timeout,TResult Function( KeepAlivePolicy_Disabled value)?  disabled,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case KeepAlivePolicy_Timeout() when timeout != null:
+return timeout(_that);case KeepAlivePolicy_Disabled() when disabled != null:
+return disabled(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( KeepAlivePolicy_Timeout value)  timeout,required TResult Function( KeepAlivePolicy_Disabled value)  disabled,}){
+final _that = this;
+switch (_that) {
+case KeepAlivePolicy_Timeout():
+return timeout(_that);case KeepAlivePolicy_Disabled():
+return disabled(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( KeepAlivePolicy_Timeout value)?  timeout,TResult? Function( KeepAlivePolicy_Disabled value)?  disabled,}){
+final _that = this;
+switch (_that) {
+case KeepAlivePolicy_Timeout() when timeout != null:
+return timeout(_that);case KeepAlivePolicy_Disabled() when disabled != null:
+return disabled(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( BigInt timeoutMs)?  timeout,TResult Function()?  disabled,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case KeepAlivePolicy_Timeout() when timeout != null:
+return timeout(_that.timeoutMs);case KeepAlivePolicy_Disabled() when disabled != null:
+return disabled();case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( BigInt timeoutMs)  timeout,required TResult Function()  disabled,}) {final _that = this;
+switch (_that) {
+case KeepAlivePolicy_Timeout():
+return timeout(_that.timeoutMs);case KeepAlivePolicy_Disabled():
+return disabled();}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( BigInt timeoutMs)?  timeout,TResult? Function()?  disabled,}) {final _that = this;
+switch (_that) {
+case KeepAlivePolicy_Timeout() when timeout != null:
+return timeout(_that.timeoutMs);case KeepAlivePolicy_Disabled() when disabled != null:
+return disabled();case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class KeepAlivePolicy_Timeout extends KeepAlivePolicy {
+  const KeepAlivePolicy_Timeout({required this.timeoutMs}): super._();
+  
+
+ final  BigInt timeoutMs;
+
+/// Create a copy of KeepAlivePolicy
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$KeepAlivePolicy_TimeoutCopyWith<KeepAlivePolicy_Timeout> get copyWith => _$KeepAlivePolicy_TimeoutCopyWithImpl<KeepAlivePolicy_Timeout>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is KeepAlivePolicy_Timeout&&(identical(other.timeoutMs, timeoutMs) || other.timeoutMs == timeoutMs));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,timeoutMs);
+
+@override
+String toString() {
+  return 'KeepAlivePolicy.timeout(timeoutMs: $timeoutMs)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $KeepAlivePolicy_TimeoutCopyWith<$Res> implements $KeepAlivePolicyCopyWith<$Res> {
+  factory $KeepAlivePolicy_TimeoutCopyWith(KeepAlivePolicy_Timeout value, $Res Function(KeepAlivePolicy_Timeout) _then) = _$KeepAlivePolicy_TimeoutCopyWithImpl;
+@useResult
+$Res call({
+ BigInt timeoutMs
+});
+
+
+
+
+}
+/// @nodoc
+class _$KeepAlivePolicy_TimeoutCopyWithImpl<$Res>
+    implements $KeepAlivePolicy_TimeoutCopyWith<$Res> {
+  _$KeepAlivePolicy_TimeoutCopyWithImpl(this._self, this._then);
+
+  final KeepAlivePolicy_Timeout _self;
+  final $Res Function(KeepAlivePolicy_Timeout) _then;
+
+/// Create a copy of KeepAlivePolicy
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? timeoutMs = null,}) {
+  return _then(KeepAlivePolicy_Timeout(
+timeoutMs: null == timeoutMs ? _self.timeoutMs : timeoutMs // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class KeepAlivePolicy_Disabled extends KeepAlivePolicy {
+  const KeepAlivePolicy_Disabled(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is KeepAlivePolicy_Disabled);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'KeepAlivePolicy.disabled()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+mixin _$PlaceholderFrame {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PlaceholderFrame);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'PlaceholderFrame()';
+}
+
+
+}
+
+/// @nodoc
+class $PlaceholderFrameCopyWith<$Res>  {
+$PlaceholderFrameCopyWith(PlaceholderFrame _, $Res Function(PlaceholderFrame) __);
+}
+
+
+/// Adds pattern-matching-related methods to [PlaceholderFrame].
+extension PlaceholderFramePatterns on PlaceholderFrame {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( PlaceholderFrame_Black value)?  black,TResult Function( PlaceholderFrame_Transparent value)?  transparent,TResult Function( PlaceholderFrame_Rgba value)?  rgba,TResult Function( PlaceholderFrame_Poster value)?  poster,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case PlaceholderFrame_Black() when black != null:
+return black(_that);case PlaceholderFrame_Transparent() when transparent != null:
+return transparent(_that);case PlaceholderFrame_Rgba() when rgba != null:
+return rgba(_that);case PlaceholderFrame_Poster() when poster != null:
+return poster(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( PlaceholderFrame_Black value)  black,required TResult Function( PlaceholderFrame_Transparent value)  transparent,required TResult Function( PlaceholderFrame_Rgba value)  rgba,required TResult Function( PlaceholderFrame_Poster value)  poster,}){
+final _that = this;
+switch (_that) {
+case PlaceholderFrame_Black():
+return black(_that);case PlaceholderFrame_Transparent():
+return transparent(_that);case PlaceholderFrame_Rgba():
+return rgba(_that);case PlaceholderFrame_Poster():
+return poster(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( PlaceholderFrame_Black value)?  black,TResult? Function( PlaceholderFrame_Transparent value)?  transparent,TResult? Function( PlaceholderFrame_Rgba value)?  rgba,TResult? Function( PlaceholderFrame_Poster value)?  poster,}){
+final _that = this;
+switch (_that) {
+case PlaceholderFrame_Black() when black != null:
+return black(_that);case PlaceholderFrame_Transparent() when transparent != null:
+return transparent(_that);case PlaceholderFrame_Rgba() when rgba != null:
+return rgba(_that);case PlaceholderFrame_Poster() when poster != null:
+return poster(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function()?  black,TResult Function()?  transparent,TResult Function( int width,  int height,  Uint8List data)?  rgba,TResult Function( String uri)?  poster,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case PlaceholderFrame_Black() when black != null:
+return black();case PlaceholderFrame_Transparent() when transparent != null:
+return transparent();case PlaceholderFrame_Rgba() when rgba != null:
+return rgba(_that.width,_that.height,_that.data);case PlaceholderFrame_Poster() when poster != null:
+return poster(_that.uri);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function()  black,required TResult Function()  transparent,required TResult Function( int width,  int height,  Uint8List data)  rgba,required TResult Function( String uri)  poster,}) {final _that = this;
+switch (_that) {
+case PlaceholderFrame_Black():
+return black();case PlaceholderFrame_Transparent():
+return transparent();case PlaceholderFrame_Rgba():
+return rgba(_that.width,_that.height,_that.data);case PlaceholderFrame_Poster():
+return poster(_that.uri);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function()?  black,TResult? Function()?  transparent,TResult? Function( int width,  int height,  Uint8List data)?  rgba,TResult? Function( String uri)?  poster,}) {final _that = this;
+switch (_that) {
+case PlaceholderFrame_Black() when black != null:
+return black();case PlaceholderFrame_Transparent() when transparent != null:
+return transparent();case PlaceholderFrame_Rgba() when rgba != null:
+return rgba(_that.width,_that.height,_that.data);case PlaceholderFrame_Poster() when poster != null:
+return poster(_that.uri);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class PlaceholderFrame_Black extends PlaceholderFrame {
+  const PlaceholderFrame_Black(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PlaceholderFrame_Black);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'PlaceholderFrame.black()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class PlaceholderFrame_Transparent extends PlaceholderFrame {
+  const PlaceholderFrame_Transparent(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PlaceholderFrame_Transparent);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'PlaceholderFrame.transparent()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class PlaceholderFrame_Rgba extends PlaceholderFrame {
+  const PlaceholderFrame_Rgba({required this.width, required this.height, required this.data}): super._();
+  
+
+ final  int width;
+ final  int height;
+ final  Uint8List data;
+
+/// Create a copy of PlaceholderFrame
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$PlaceholderFrame_RgbaCopyWith<PlaceholderFrame_Rgba> get copyWith => _$PlaceholderFrame_RgbaCopyWithImpl<PlaceholderFrame_Rgba>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PlaceholderFrame_Rgba&&(identical(other.width, width) || other.width == width)&&(identical(other.height, height) || other.height == height)&&(identical(other.data, data) || other.data == data));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,width,height,data);
+
+@override
+String toString() {
+  return 'PlaceholderFrame.rgba(width: $width, height: $height, data: $data)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $PlaceholderFrame_RgbaCopyWith<$Res> implements $PlaceholderFrameCopyWith<$Res> {
+  factory $PlaceholderFrame_RgbaCopyWith(PlaceholderFrame_Rgba value, $Res Function(PlaceholderFrame_Rgba) _then) = _$PlaceholderFrame_RgbaCopyWithImpl;
+@useResult
+$Res call({
+ int width, int height, Uint8List data
+});
+
+
+
+
+}
+/// @nodoc
+class _$PlaceholderFrame_RgbaCopyWithImpl<$Res>
+    implements $PlaceholderFrame_RgbaCopyWith<$Res> {
+  _$PlaceholderFrame_RgbaCopyWithImpl(this._self, this._then);
+
+  final PlaceholderFrame_Rgba _self;
+  final $Res Function(PlaceholderFrame_Rgba) _then;
+
+/// Create a copy of PlaceholderFrame
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? width = null,Object? height = null,Object? data = null,}) {
+  return _then(PlaceholderFrame_Rgba(
+width: null == width ? _self.width : width // ignore: cast_nullable_to_non_nullable
+as int,height: null == height ? _self.height : height // ignore: cast_nullable_to_non_nullable
+as int,data: null == data ? _self.data : data // ignore: cast_nullable_to_non_nullable
+as Uint8List,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class PlaceholderFrame_Poster extends PlaceholderFrame {
+  const PlaceholderFrame_Poster({required this.uri}): super._();
+  
+
+ final  String uri;
+
+/// Create a copy of PlaceholderFrame
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$PlaceholderFrame_PosterCopyWith<PlaceholderFrame_Poster> get copyWith => _$PlaceholderFrame_PosterCopyWithImpl<PlaceholderFrame_Poster>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PlaceholderFrame_Poster&&(identical(other.uri, uri) || other.uri == uri));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,uri);
+
+@override
+String toString() {
+  return 'PlaceholderFrame.poster(uri: $uri)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $PlaceholderFrame_PosterCopyWith<$Res> implements $PlaceholderFrameCopyWith<$Res> {
+  factory $PlaceholderFrame_PosterCopyWith(PlaceholderFrame_Poster value, $Res Function(PlaceholderFrame_Poster) _then) = _$PlaceholderFrame_PosterCopyWithImpl;
+@useResult
+$Res call({
+ String uri
+});
+
+
+
+
+}
+/// @nodoc
+class _$PlaceholderFrame_PosterCopyWithImpl<$Res>
+    implements $PlaceholderFrame_PosterCopyWith<$Res> {
+  _$PlaceholderFrame_PosterCopyWithImpl(this._self, this._then);
+
+  final PlaceholderFrame_Poster _self;
+  final $Res Function(PlaceholderFrame_Poster) _then;
+
+/// Create a copy of PlaceholderFrame
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? uri = null,}) {
+  return _then(PlaceholderFrame_Poster(
+uri: null == uri ? _self.uri : uri // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
 /// @nodoc
 mixin _$VideoConfig {
 
- Object get field0;
+
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is VideoConfig&&const DeepCollectionEquality().equals(other.field0, field0));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is VideoConfig);
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(field0));
+int get hashCode => runtimeType.hashCode;
 
 @override
 String toString() {
-  return 'VideoConfig(field0: $field0)';
+  return 'VideoConfig()';
 }
 
 
@@ -55,12 +1239,13 @@ extension VideoConfigPatterns on VideoConfig {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( VideoConfig_WscRtp value)?  wscRtp,TResult Function( VideoConfig_Playbin value)?  playbin,required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( VideoConfig_WscRtp value)?  wscRtp,TResult Function( VideoConfig_Playbin value)?  playbin,TResult Function( VideoConfig_Custom value)?  custom,required TResult orElse(),}){
 final _that = this;
 switch (_that) {
 case VideoConfig_WscRtp() when wscRtp != null:
 return wscRtp(_that);case VideoConfig_Playbin() when playbin != null:
-return playbin(_that);case _:
+return playbin(_that);case VideoConfig_Custom() when custom != null:
+return custom(_that);case _:
   return orElse();
 
 }
@@ -78,12 +1263,13 @@ return playbin(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( VideoConfig_WscRtp value)  wscRtp,required TResult Function( VideoConfig_Playbin value)  playbin,}){
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( VideoConfig_WscRtp value)  wscRtp,required TResult Function( VideoConfig_Playbin value)  playbin,required TResult Function( VideoConfig_Custom value)  custom,}){
 final _that = this;
 switch (_that) {
 case VideoConfig_WscRtp():
 return wscRtp(_that);case VideoConfig_Playbin():
-return playbin(_that);}
+return playbin(_that);case VideoConfig_Custom():
+return custom(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
 ///
@@ -97,12 +1283,13 @@ return playbin(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( VideoConfig_WscRtp value)?  wscRtp,TResult? Function( VideoConfig_Playbin value)?  playbin,}){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( VideoConfig_WscRtp value)?  wscRtp,TResult? Function( VideoConfig_Playbin value)?  playbin,TResult? Function( VideoConfig_Custom value)?  custom,}){
 final _that = this;
 switch (_that) {
 case VideoConfig_WscRtp() when wscRtp != null:
 return wscRtp(_that);case VideoConfig_Playbin() when playbin != null:
-return playbin(_that);case _:
+return playbin(_that);case VideoConfig_Custom() when custom != null:
+return custom(_that);case _:
   return null;
 
 }
@@ -119,11 +1306,12 @@ return playbin(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( WscRtpSessionConfig field0)?  wscRtp,TResult Function( PlaybinConfig field0)?  playbin,required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( WscRtpSessionConfig field0)?  wscRtp,TResult Function( PlaybinConfig field0)?  playbin,TResult Function( String kind,  String jsonParams)?  custom,required TResult orElse(),}) {final _that = this;
 switch (_that) {
 case VideoConfig_WscRtp() when wscRtp != null:
 return wscRtp(_that.field0);case VideoConfig_Playbin() when playbin != null:
-return playbin(_that.field0);case _:
+return playbin(_that.field0);case VideoConfig_Custom() when custom != null:
+return custom(_that.kind,_that.jsonParams);case _:
   return orElse();
 
 }
@@ -141,11 +1329,12 @@ return playbin(_that.field0);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( WscRtpSessionConfig field0)  wscRtp,required TResult Function( PlaybinConfig field0)  playbin,}) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( WscRtpSessionConfig field0)  wscRtp,required TResult Function( PlaybinConfig field0)  playbin,required TResult Function( String kind,  String jsonParams)  custom,}) {final _that = this;
 switch (_that) {
 case VideoConfig_WscRtp():
 return wscRtp(_that.field0);case VideoConfig_Playbin():
-return playbin(_that.field0);}
+return playbin(_that.field0);case VideoConfig_Custom():
+return custom(_that.kind,_that.jsonParams);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -159,11 +1348,12 @@ return playbin(_that.field0);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( WscRtpSessionConfig field0)?  wscRtp,TResult? Function( PlaybinConfig field0)?  playbin,}) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( WscRtpSessionConfig field0)?  wscRtp,TResult? Function( PlaybinConfig field0)?  playbin,TResult? Function( String kind,  String jsonParams)?  custom,}) {final _that = this;
 switch (_that) {
 case VideoConfig_WscRtp() when wscRtp != null:
 return wscRtp(_that.field0);case VideoConfig_Playbin() when playbin != null:
-return playbin(_that.field0);case _:
+return playbin(_that.field0);case VideoConfig_Custom() when custom != null:
+return custom(_that.kind,_that.jsonParams);case _:
   return null;
 
 }
@@ -178,7 +1368,7 @@ class VideoConfig_WscRtp extends VideoConfig {
   const VideoConfig_WscRtp(this.field0): super._();
   
 
-@override final  WscRtpSessionConfig field0;
+ final  WscRtpSessionConfig field0;
 
 /// Create a copy of VideoConfig
 /// with the given fields replaced by the non-null parameter values.
@@ -244,7 +1434,7 @@ class VideoConfig_Playbin extends VideoConfig {
   const VideoConfig_Playbin(this.field0): super._();
   
 
-@override final  PlaybinConfig field0;
+ final  PlaybinConfig field0;
 
 /// Create a copy of VideoConfig
 /// with the given fields replaced by the non-null parameter values.
@@ -301,6 +1491,74 @@ as PlaybinConfig,
 }
 
 
+}
+
+/// @nodoc
+
+
+class VideoConfig_Custom extends VideoConfig {
+  const VideoConfig_Custom({required this.kind, required this.jsonParams}): super._();
+  
+
+ final  String kind;
+ final  String jsonParams;
+
+/// Create a copy of VideoConfig
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$VideoConfig_CustomCopyWith<VideoConfig_Custom> get copyWith => _$VideoConfig_CustomCopyWithImpl<VideoConfig_Custom>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is VideoConfig_Custom&&(identical(other.kind, kind) || other.kind == kind)&&(identical(other.jsonParams, jsonParams) || other.jsonParams == jsonParams));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,kind,jsonParams);
+
+@override
+String toString() {
+  return 'VideoConfig.custom(kind: $kind, jsonParams: $jsonParams)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $VideoConfig_CustomCopyWith<$Res> implements $VideoConfigCopyWith<$Res> {
+  factory $VideoConfig_CustomCopyWith(VideoConfig_Custom value, $Res Function(VideoConfig_Custom) _then) = _$VideoConfig_CustomCopyWithImpl;
+@useResult
+$Res call({
+ String kind, String jsonParams
+});
+
+
+
+
+}
+/// @nodoc
+class _$VideoConfig_CustomCopyWithImpl<$Res>
+    implements $VideoConfig_CustomCopyWith<$Res> {
+  _$VideoConfig_CustomCopyWithImpl(this._self, this._then);
+
+  final VideoConfig_Custom _self;
+  final $Res Function(VideoConfig_Custom) _then;
+
+/// Create a copy of VideoConfig
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? kind = null,Object? jsonParams = null,}) {
+  return _then(VideoConfig_Custom(
+kind: null == kind ? _self.kind : kind // ignore: cast_nullable_to_non_nullable
+as String,jsonParams: null == jsonParams ? _self.jsonParams : jsonParams // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
 }
 
 // dart format on
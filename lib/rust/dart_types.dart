@@ -3,26 +3,208 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
+import 'core/types.dart';
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'dart_types.freezed.dart';
 
+@freezed
+sealed class ErrorBackend with _$ErrorBackend {
+  const ErrorBackend._();
+
+  const factory ErrorBackend.wscRtp() = ErrorBackend_WscRtp;
+  const factory ErrorBackend.playbin() = ErrorBackend_Playbin;
+  const factory ErrorBackend.registry() = ErrorBackend_Registry;
+  const factory ErrorBackend.recorder() = ErrorBackend_Recorder;
+  const factory ErrorBackend.custom(String field0) = ErrorBackend_Custom;
+}
+
+enum ErrorCategory {
+  network,
+  timeout,
+  sourceNotFound,
+  accessDenied,
+  invalidUri,
+  unsupportedCodec,
+  decode,
+  server,
+  unavailable,
+  sessionNotFound,
+  cancelled,
+  internal,
+}
+
+class RecordingInfo {
+  final List<String> files;
+  final BigInt durationMs;
+  final BigInt sizeBytes;
+
+  const RecordingInfo({
+    required this.files,
+    required this.durationMs,
+    required this.sizeBytes,
+  });
+
+  @override
+  int get hashCode => files.hashCode ^ durationMs.hashCode ^ sizeBytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RecordingInfo &&
+          runtimeType == other.runtimeType &&
+          files == other.files &&
+          durationMs == other.durationMs &&
+          sizeBytes == other.sizeBytes;
+}
+
+/// An encoded frame returned by `capture_snapshot`.
+class Snapshot {
+  final Uint8List data;
+  final int width;
+  final int height;
+  final BigInt? ptsMs;
+  final PlatformInt64 capturedAtMs;
+
+  const Snapshot({
+    required this.data,
+    required this.width,
+    required this.height,
+    this.ptsMs,
+    required this.capturedAtMs,
+  });
+
+  @override
+  int get hashCode =>
+      data.hashCode ^
+      width.hashCode ^
+      height.hashCode ^
+      ptsMs.hashCode ^
+      capturedAtMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Snapshot &&
+          runtimeType == other.runtimeType &&
+          data == other.data &&
+          width == other.width &&
+          height == other.height &&
+          ptsMs == other.ptsMs &&
+          capturedAtMs == other.capturedAtMs;
+}
+
+/// Time from session creation until each startup phase completed, in milliseconds.
+/// Phases that don't apply to a backend are `None`.
+class StartupReport {
+  final BigInt? connectMs;
+  final BigInt? handshakeMs;
+  final BigInt? prerollMs;
+  final BigInt firstFrameMs;
+  final int attempts;
+
+  const StartupReport({
+    this.connectMs,
+    this.handshakeMs,
+    this.prerollMs,
+    required this.firstFrameMs,
+    required this.attempts,
+  });
+
+  @override
+  int get hashCode =>
+      connectMs.hashCode ^
+      handshakeMs.hashCode ^
+      prerollMs.hashCode ^
+      firstFrameMs.hashCode ^
+      attempts.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StartupReport &&
+          runtimeType == other.runtimeType &&
+          connectMs == other.connectMs &&
+          handshakeMs == other.handshakeMs &&
+          prerollMs == other.prerollMs &&
+          firstFrameMs == other.firstFrameMs &&
+          attempts == other.attempts;
+}
+
+class StreamError {
+  final ErrorCategory category;
+  final bool retryable;
+  final ErrorBackend backend;
+  final String? cause;
+  final String message;
+
+  const StreamError({
+    required this.category,
+    required this.retryable,
+    required this.backend,
+    this.cause,
+    required this.message,
+  });
+
+  @override
+  int get hashCode =>
+      category.hashCode ^
+      retryable.hashCode ^
+      backend.hashCode ^
+      cause.hashCode ^
+      message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StreamError &&
+          runtimeType == other.runtimeType &&
+          category == other.category &&
+          retryable == other.retryable &&
+          backend == other.backend &&
+          cause == other.cause &&
+          message == other.message;
+}
+
 @freezed
 sealed class StreamEvent with _$StreamEvent {
   const StreamEvent._();
 
-  const factory StreamEvent.error(String field0) = StreamEvent_Error;
+  const factory StreamEvent.error(StreamError field0) = StreamEvent_Error;
   const factory StreamEvent.currentTime(PlatformInt64 field0) =
       StreamEvent_CurrentTime;
   const factory StreamEvent.originVideoSize({
     required BigInt width,
     required BigInt height,
+    required BigInt transformedWidth,
+    required BigInt transformedHeight,
+    required double pixelAspectRatio,
+    required double displayAspectRatio,
+    required double frameRate,
   }) = StreamEvent_OriginVideoSize;
   const factory StreamEvent.wscRtpSessionMode(WscRtpMode field0) =
       StreamEvent_WscRtpSessionMode;
   const factory StreamEvent.wscRtpStreamState(String field0) =
       StreamEvent_WscRtpStreamState;
+  const factory StreamEvent.stalled({required BigInt sinceLastFrameMs}) =
+      StreamEvent_Stalled;
+  const factory StreamEvent.resumed({required BigInt stalledForMs}) =
+      StreamEvent_Resumed;
+  const factory StreamEvent.startupReport(StartupReport field0) =
+      StreamEvent_StartupReport;
+  const factory StreamEvent.startupFailed(StreamError field0) =
+      StreamEvent_StartupFailed;
+  const factory StreamEvent.reconnecting({
+    required int attempt,
+    required BigInt delayMs,
+  }) = StreamEvent_Reconnecting;
+  const factory StreamEvent.recordingStarted({required String path}) =
+      StreamEvent_RecordingStarted;
+  const factory StreamEvent.recordingStopped(RecordingInfo field0) =
+      StreamEvent_RecordingStopped;
+  const factory StreamEvent.recordingError(StreamError field0) =
+      StreamEvent_RecordingError;
 }
 
 @freezed
@@ -37,7 +219,7 @@ sealed class StreamMessage with _$StreamMessage {
 sealed class StreamState with _$StreamState {
   const StreamState._();
 
-  const factory StreamState.error(String field0) = StreamState_Error;
+  const factory StreamState.error(StreamError field0) = StreamState_Error;
   const factory StreamState.loading() = StreamState_Loading;
   const factory StreamState.playing({
     required PlatformInt64 textureId,
@@ -46,6 +228,62 @@ sealed class StreamState with _$StreamState {
   const factory StreamState.stopped() = StreamState_Stopped;
 }
 
+/// A decoded frame delivered by a frame tap.
+class TapFrame {
+  final Uint8List data;
+  final int width;
+  final int height;
+  final TapPixelFormat format;
+  final Uint32List planeStrides;
+  final Uint32List planeOffsets;
+  final BigInt? ptsMs;
+  final PlatformInt64 capturedAtMs;
+  final BigInt sequence;
+  final BigInt dropped;
+
+  const TapFrame({
+    required this.data,
+    required this.width,
+    required this.height,
+    required this.format,
+    required this.planeStrides,
+    required this.planeOffsets,
+    this.ptsMs,
+    required this.capturedAtMs,
+    required this.sequence,
+    required this.dropped,
+  });
+
+  @override
+  int get hashCode =>
+      data.hashCode ^
+      width.hashCode ^
+      height.hashCode ^
+      format.hashCode ^
+      planeStrides.hashCode ^
+      planeOffsets.hashCode ^
+      ptsMs.hashCode ^
+      capturedAtMs.hashCode ^
+      sequence.hashCode ^
+      dropped.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TapFrame &&
+          runtimeType == other.runtimeType &&
+          data == other.data &&
+          width == other.width &&
+          height == other.height &&
+          format == other.format &&
+          planeStrides == other.planeStrides &&
+          planeOffsets == other.planeOffsets &&
+          ptsMs == other.ptsMs &&
+          capturedAtMs == other.capturedAtMs &&
+          sequence == other.sequence &&
+          dropped == other.dropped;
+}
+
 @freezed
 sealed class WscRtpMode with _$WscRtpMode {
   const WscRtpMode._();
@@ -11,6 +11,378 @@ part of 'dart_types.dart';
 
 // dart format off
 T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$ErrorBackend {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ErrorBackend);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ErrorBackend()';
+}
+
+
+}
+
+/// @nodoc
+class $ErrorBackendCopyWith<$Res>  {
+$ErrorBackendCopyWith(ErrorBackend _, $Res Function(ErrorBackend) __);
+}
+
+
+/// Adds pattern-matching-related methods to [ErrorBackend].
+extension ErrorBackendPatterns on ErrorBackend {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( ErrorBackend_WscRtp value)?  wscRtp,TResult Function( ErrorBackend_Playbin value)?  playbin,TResult Function( ErrorBackend_Registry value)?  registry,TResult Function( ErrorBackend_Recorder value)?  recorder,TResult Function( ErrorBackend_Custom value)?  custom,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case ErrorBackend_WscRtp() when wscRtp != null:
+return wscRtp(_that);case ErrorBackend_Playbin() when playbin != null:
+return playbin(_that);case ErrorBackend_Registry() when registry != null:
+return registry(_that);case ErrorBackend_Recorder() when recorder != null:
+return recorder(_that);case ErrorBackend_Custom() when custom != null:
+return custom(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( ErrorBackend_WscRtp value)  wscRtp,required TResult Function( ErrorBackend_Playbin value)  playbin,required TResult Function( ErrorBackend_Registry value)  registry,required TResult Function( ErrorBackend_Recorder value)  recorder,required TResult Function( ErrorBackend_Custom value)  custom,}){
+final _that = this;
+switch (_that) {
+case ErrorBackend_WscRtp():
+return wscRtp(_that);case ErrorBackend_Playbin():
+return playbin(_that);case ErrorBackend_Registry():
+return registry(_that);case ErrorBackend_Recorder():
+return recorder(_that);case ErrorBackend_Custom():
+return custom(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( ErrorBackend_WscRtp value)?  wscRtp,TResult? Function( ErrorBackend_Playbin value)?  playbin,TResult? Function( ErrorBackend_Registry value)?  registry,TResult? Function( ErrorBackend_Recorder value)?  recorder,TResult? Function( ErrorBackend_Custom value)?  custom,}){
+final _that = this;
+switch (_that) {
+case ErrorBackend_WscRtp() when wscRtp != null:
+return wscRtp(_that);case ErrorBackend_Playbin() when playbin != null:
+return playbin(_that);case ErrorBackend_Registry() when registry != null:
+return registry(_that);case ErrorBackend_Recorder() when recorder != null:
+return recorder(_that);case ErrorBackend_Custom() when custom != null:
+return custom(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function()?  wscRtp,TResult Function()?  playbin,TResult Function()?  registry,TResult Function()?  recorder,TResult Function( String field0)?  custom,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case ErrorBackend_WscRtp() when wscRtp != null:
+return wscRtp();case ErrorBackend_Playbin() when playbin != null:
+return playbin();case ErrorBackend_Registry() when registry != null:
+return registry();case ErrorBackend_Recorder() when recorder != null:
+return recorder();case ErrorBackend_Custom() when custom != null:
+return custom(_that.field0);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function()  wscRtp,required TResult Function()  playbin,required TResult Function()  registry,required TResult Function()  recorder,required TResult Function( String field0)  custom,}) {final _that = this;
+switch (_that) {
+case ErrorBackend_WscRtp():
+return wscRtp();case ErrorBackend_Playbin():
+return playbin();case ErrorBackend_Registry():
+return registry();case ErrorBackend_Recorder():
+return recorder();case ErrorBackend_Custom():
+return custom(_that.field0);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function()?  wscRtp,TResult? Function()?  playbin,TResult? Function()?  registry,TResult? Function()?  recorder,TResult? Function( String field0)?  custom,}) {final _that = this;
+switch (_that) {
+case ErrorBackend_WscRtp() when wscRtp != null:
+return wscRtp();case ErrorBackend_Playbin() when playbin != null:
+return playbin();case ErrorBackend_Registry() when registry != null:
+return registry();case ErrorBackend_Recorder() when recorder != null:
+return recorder();case ErrorBackend_Custom() when custom != null:
+return custom(_that.field0);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class ErrorBackend_WscRtp extends ErrorBackend {
+  const ErrorBackend_WscRtp(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ErrorBackend_WscRtp);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ErrorBackend.wscRtp()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class ErrorBackend_Playbin extends ErrorBackend {
+  const ErrorBackend_Playbin(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ErrorBackend_Playbin);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ErrorBackend.playbin()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class ErrorBackend_Registry extends ErrorBackend {
+  const ErrorBackend_Registry(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ErrorBackend_Registry);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ErrorBackend.registry()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class ErrorBackend_Recorder extends ErrorBackend {
+  const ErrorBackend_Recorder(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ErrorBackend_Recorder);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ErrorBackend.recorder()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class ErrorBackend_Custom extends ErrorBackend {
+  const ErrorBackend_Custom(this.field0): super._();
+  
+
+ final  String field0;
+
+/// Create a copy of ErrorBackend
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ErrorBackend_CustomCopyWith<ErrorBackend_Custom> get copyWith => _$ErrorBackend_CustomCopyWithImpl<ErrorBackend_Custom>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ErrorBackend_Custom&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ErrorBackend.custom(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ErrorBackend_CustomCopyWith<$Res> implements $ErrorBackendCopyWith<$Res> {
+  factory $ErrorBackend_CustomCopyWith(ErrorBackend_Custom value, $Res Function(ErrorBackend_Custom) _then) = _$ErrorBackend_CustomCopyWithImpl;
+@useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ErrorBackend_CustomCopyWithImpl<$Res>
+    implements $ErrorBackend_CustomCopyWith<$Res> {
+  _$ErrorBackend_CustomCopyWithImpl(this._self, this._then);
+
+  final ErrorBackend_Custom _self;
+  final $Res Function(ErrorBackend_Custom) _then;
+
+/// Create a copy of ErrorBackend
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ErrorBackend_Custom(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
 /// @nodoc
 mixin _$StreamEvent {
 
@@ -20,195 +392,792 @@ mixin _$StreamEvent {
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is StreamEvent);
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is StreamEvent);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'StreamEvent()';
+}
+
+
+}
+
+/// @nodoc
+class $StreamEventCopyWith<$Res>  {
+$StreamEventCopyWith(StreamEvent _, $Res Function(StreamEvent) __);
+}
+
+
+/// Adds pattern-matching-related methods to [StreamEvent].
+extension StreamEventPatterns on StreamEvent {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( StreamEvent_Error value)?  error,TResult Function( StreamEvent_CurrentTime value)?  currentTime,TResult Function( StreamEvent_OriginVideoSize value)?  originVideoSize,TResult Function( StreamEvent_WscRtpSessionMode value)?  wscRtpSessionMode,TResult Function( StreamEvent_WscRtpStreamState value)?  wscRtpStreamState,TResult Function( StreamEvent_Stalled value)?  stalled,TResult Function( StreamEvent_Resumed value)?  resumed,TResult Function( StreamEvent_StartupReport value)?  startupReport,TResult Function( StreamEvent_StartupFailed value)?  startupFailed,TResult Function( StreamEvent_Reconnecting value)?  reconnecting,TResult Function( StreamEvent_RecordingStarted value)?  recordingStarted,TResult Function( StreamEvent_RecordingStopped value)?  recordingStopped,TResult Function( StreamEvent_RecordingError value)?  recordingError,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case StreamEvent_Error() when error != null:
+return error(_that);case StreamEvent_CurrentTime() when currentTime != null:
+return currentTime(_that);case StreamEvent_OriginVideoSize() when originVideoSize != null:
+return originVideoSize(_that);case StreamEvent_WscRtpSessionMode() when wscRtpSessionMode != null:
+return wscRtpSessionMode(_that);case StreamEvent_WscRtpStreamState() when wscRtpStreamState != null:
+return wscRtpStreamState(_that);case StreamEvent_Stalled() when stalled != null:
+return stalled(_that);case StreamEvent_Resumed() when resumed != null:
+return resumed(_that);case StreamEvent_StartupReport() when startupReport != null:
+return startupReport(_that);case StreamEvent_StartupFailed() when startupFailed != null:
+return startupFailed(_that);case StreamEvent_Reconnecting() when reconnecting != null:
+return reconnecting(_that);case StreamEvent_RecordingStarted() when recordingStarted != null:
+return recordingStarted(_that);case StreamEvent_RecordingStopped() when recordingStopped != null:
+return recordingStopped(_that);case StreamEvent_RecordingError() when recordingError != null:
+return recordingError(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( StreamEvent_Error value)  error,required TResult Function( StreamEvent_CurrentTime value)  currentTime,required TResult Function( StreamEvent_OriginVideoSize value)  originVideoSize,required TResult Function( StreamEvent_WscRtpSessionMode value)  wscRtpSessionMode,required TResult Function( StreamEvent_WscRtpStreamState value)  wscRtpStreamState,required TResult Function( StreamEvent_Stalled value)  stalled,required TResult Function( StreamEvent_Resumed value)  resumed,required TResult Function( StreamEvent_StartupReport value)  startupReport,required TResult Function( StreamEvent_StartupFailed value)  startupFailed,required TResult Function( StreamEvent_Reconnecting value)  reconnecting,required TResult Function( StreamEvent_RecordingStarted value)  recordingStarted,required TResult Function( StreamEvent_RecordingStopped value)  recordingStopped,required TResult Function( StreamEvent_RecordingError value)  recordingError,}){
+final _that = this;
+switch (_that) {
+case StreamEvent_Error():
+return error(_that);case StreamEvent_CurrentTime():
+return currentTime(_that);case StreamEvent_OriginVideoSize():
+return originVideoSize(_that);case StreamEvent_WscRtpSessionMode():
+return wscRtpSessionMode(_that);case StreamEvent_WscRtpStreamState():
+return wscRtpStreamState(_that);case StreamEvent_Stalled():
+return stalled(_that);case StreamEvent_Resumed():
+return resumed(_that);case StreamEvent_StartupReport():
+return startupReport(_that);case StreamEvent_StartupFailed():
+return startupFailed(_that);case StreamEvent_Reconnecting():
+return reconnecting(_that);case StreamEvent_RecordingStarted():
+return recordingStarted(_that);case StreamEvent_RecordingStopped():
+return recordingStopped(_that);case StreamEvent_RecordingError():
+return recordingError(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( StreamEvent_Error value)?  error,TResult? Function( StreamEvent_CurrentTime value)?  currentTime,TResult? Function( StreamEvent_OriginVideoSize value)?  originVideoSize,TResult? Function( StreamEvent_WscRtpSessionMode value)?  wscRtpSessionMode,TResult? Function( StreamEvent_WscRtpStreamState value)?  wscRtpStreamState,TResult? Function( StreamEvent_Stalled value)?  stalled,TResult? Function( StreamEvent_Resumed value)?  resumed,TResult? Function( StreamEvent_StartupReport value)?  startupReport,TResult? Function( StreamEvent_StartupFailed value)?  startupFailed,TResult? Function( StreamEvent_Reconnecting value)?  reconnecting,TResult? Function( StreamEvent_RecordingStarted value)?  recordingStarted,TResult? Function( StreamEvent_RecordingStopped value)?  recordingStopped,TResult? Function( StreamEvent_RecordingError value)?  recordingError,}){
+final _that = this;
+switch (_that) {
+case StreamEvent_Error() when error != null:
+return error(_that);case StreamEvent_CurrentTime() when currentTime != null:
+return currentTime(_that);case StreamEvent_OriginVideoSize() when originVideoSize != null:
+return originVideoSize(_that);case StreamEvent_WscRtpSessionMode() when wscRtpSessionMode != null:
+return wscRtpSessionMode(_that);case StreamEvent_WscRtpStreamState() when wscRtpStreamState != null:
+return wscRtpStreamState(_that);case StreamEvent_Stalled() when stalled != null:
+return stalled(_that);case StreamEvent_Resumed() when resumed != null:
+return resumed(_that);case StreamEvent_StartupReport() when startupReport != null:
+return startupReport(_that);case StreamEvent_StartupFailed() when startupFailed != null:
+return startupFailed(_that);case StreamEvent_Reconnecting() when reconnecting != null:
+return reconnecting(_that);case StreamEvent_RecordingStarted() when recordingStarted != null:
+return recordingStarted(_that);case StreamEvent_RecordingStopped() when recordingStopped != null:
+return recordingStopped(_that);case StreamEvent_RecordingError() when recordingError != null:
+return recordingError(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( StreamError field0)?  error,TResult Function( PlatformInt64 field0)?  currentTime,TResult Function( BigInt width,  BigInt height,  BigInt transformedWidth,  BigInt transformedHeight,  double pixelAspectRatio,  double displayAspectRatio,  double frameRate)?  originVideoSize,TResult Function( WscRtpMode field0)?  wscRtpSessionMode,TResult Function( String field0)?  wscRtpStreamState,TResult Function( BigInt sinceLastFrameMs)?  stalled,TResult Function( BigInt stalledForMs)?  resumed,TResult Function( StartupReport field0)?  startupReport,TResult Function( StreamError field0)?  startupFailed,TResult Function( int attempt,  BigInt delayMs)?  reconnecting,TResult Function( String path)?  recordingStarted,TResult Function( RecordingInfo field0)?  recordingStopped,TResult Function( StreamError field0)?  recordingError,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case StreamEvent_Error() when error != null:
+return error(_that.field0);case StreamEvent_CurrentTime() when currentTime != null:
+return currentTime(_that.field0);case StreamEvent_OriginVideoSize() when originVideoSize != null:
+return originVideoSize(_that.width,_that.height,_that.transformedWidth,_that.transformedHeight,_that.pixelAspectRatio,_that.displayAspectRatio,_that.frameRate);case StreamEvent_WscRtpSessionMode() when wscRtpSessionMode != null:
+return wscRtpSessionMode(_that.field0);case StreamEvent_WscRtpStreamState() when wscRtpStreamState != null:
+return wscRtpStreamState(_that.field0);case StreamEvent_Stalled() when stalled != null:
+return stalled(_that.sinceLastFrameMs);case StreamEvent_Resumed() when resumed != null:
+return resumed(_that.stalledForMs);case StreamEvent_StartupReport() when startupReport != null:
+return startupReport(_that.field0);case StreamEvent_StartupFailed() when startupFailed != null:
+return startupFailed(_that.field0);case StreamEvent_Reconnecting() when reconnecting != null:
+return reconnecting(_that.attempt,_that.delayMs);case StreamEvent_RecordingStarted() when recordingStarted != null:
+return recordingStarted(_that.path);case StreamEvent_RecordingStopped() when recordingStopped != null:
+return recordingStopped(_that.field0);case StreamEvent_RecordingError() when recordingError != null:
+return recordingError(_that.field0);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( StreamError field0)  error,required TResult Function( PlatformInt64 field0)  currentTime,required TResult Function( BigInt width,  BigInt height,  BigInt transformedWidth,  BigInt transformedHeight,  double pixelAspectRatio,  double displayAspectRatio,  double frameRate)  originVideoSize,required TResult Function( WscRtpMode field0)  wscRtpSessionMode,required TResult Function( String field0)  wscRtpStreamState,required TResult Function( BigInt sinceLastFrameMs)  stalled,required TResult Function( BigInt stalledForMs)  resumed,required TResult Function( StartupReport field0)  startupReport,required TResult Function( StreamError field0)  startupFailed,required TResult Function( int attempt,  BigInt delayMs)  reconnecting,required TResult Function( String path)  recordingStarted,required TResult Function( RecordingInfo field0)  recordingStopped,required TResult Function( StreamError field0)  recordingError,}) {final _that = this;
+switch (_that) {
+case StreamEvent_Error():
+return error(_that.field0);case StreamEvent_CurrentTime():
+return currentTime(_that.field0);case StreamEvent_OriginVideoSize():
+return originVideoSize(_that.width,_that.height,_that.transformedWidth,_that.transformedHeight,_that.pixelAspectRatio,_that.displayAspectRatio,_that.frameRate);case StreamEvent_WscRtpSessionMode():
+return wscRtpSessionMode(_that.field0);case StreamEvent_WscRtpStreamState():
+return wscRtpStreamState(_that.field0);case StreamEvent_Stalled():
+return stalled(_that.sinceLastFrameMs);case StreamEvent_Resumed():
+return resumed(_that.stalledForMs);case StreamEvent_StartupReport():
+return startupReport(_that.field0);case StreamEvent_StartupFailed():
+return startupFailed(_that.field0);case StreamEvent_Reconnecting():
+return reconnecting(_that.attempt,_that.delayMs);case StreamEvent_RecordingStarted():
+return recordingStarted(_that.path);case StreamEvent_RecordingStopped():
+return recordingStopped(_that.field0);case StreamEvent_RecordingError():
+return recordingError(_that.field0);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( StreamError field0)?  error,TResult? Function( PlatformInt64 field0)?  currentTime,TResult? Function( BigInt width,  BigInt height,  BigInt transformedWidth,  BigInt transformedHeight,  double pixelAspectRatio,  double displayAspectRatio,  double frameRate)?  originVideoSize,TResult? Function( WscRtpMode field0)?  wscRtpSessionMode,TResult? Function( String field0)?  wscRtpStreamState,TResult? Function( BigInt sinceLastFrameMs)?  stalled,TResult? Function( BigInt stalledForMs)?  resumed,TResult? Function( StartupReport field0)?  startupReport,TResult? Function( StreamError field0)?  startupFailed,TResult? Function( int attempt,  BigInt delayMs)?  reconnecting,TResult? Function( String path)?  recordingStarted,TResult? Function( RecordingInfo field0)?  recordingStopped,TResult? Function( StreamError field0)?  recordingError,}) {final _that = this;
+switch (_that) {
+case StreamEvent_Error() when error != null:
+return error(_that.field0);case StreamEvent_CurrentTime() when currentTime != null:
+return currentTime(_that.field0);case StreamEvent_OriginVideoSize() when originVideoSize != null:
+return originVideoSize(_that.width,_that.height,_that.transformedWidth,_that.transformedHeight,_that.pixelAspectRatio,_that.displayAspectRatio,_that.frameRate);case StreamEvent_WscRtpSessionMode() when wscRtpSessionMode != null:
+return wscRtpSessionMode(_that.field0);case StreamEvent_WscRtpStreamState() when wscRtpStreamState != null:
+return wscRtpStreamState(_that.field0);case StreamEvent_Stalled() when stalled != null:
+return stalled(_that.sinceLastFrameMs);case StreamEvent_Resumed() when resumed != null:
+return resumed(_that.stalledForMs);case StreamEvent_StartupReport() when startupReport != null:
+return startupReport(_that.field0);case StreamEvent_StartupFailed() when startupFailed != null:
+return startupFailed(_that.field0);case StreamEvent_Reconnecting() when reconnecting != null:
+return reconnecting(_that.attempt,_that.delayMs);case StreamEvent_RecordingStarted() when recordingStarted != null:
+return recordingStarted(_that.path);case StreamEvent_RecordingStopped() when recordingStopped != null:
+return recordingStopped(_that.field0);case StreamEvent_RecordingError() when recordingError != null:
+return recordingError(_that.field0);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class StreamEvent_Error extends StreamEvent {
+  const StreamEvent_Error(this.field0): super._();
+  
+
+ final  StreamError field0;
+
+/// Create a copy of StreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$StreamEvent_ErrorCopyWith<StreamEvent_Error> get copyWith => _$StreamEvent_ErrorCopyWithImpl<StreamEvent_Error>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is StreamEvent_Error&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'StreamEvent.error(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $StreamEvent_ErrorCopyWith<$Res> implements $StreamEventCopyWith<$Res> {
+  factory $StreamEvent_ErrorCopyWith(StreamEvent_Error value, $Res Function(StreamEvent_Error) _then) = _$StreamEvent_ErrorCopyWithImpl;
+@useResult
+$Res call({
+ StreamError field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$StreamEvent_ErrorCopyWithImpl<$Res>
+    implements $StreamEvent_ErrorCopyWith<$Res> {
+  _$StreamEvent_ErrorCopyWithImpl(this._self, this._then);
+
+  final StreamEvent_Error _self;
+  final $Res Function(StreamEvent_Error) _then;
+
+/// Create a copy of StreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(StreamEvent_Error(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as StreamError,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class StreamEvent_CurrentTime extends StreamEvent {
+  const StreamEvent_CurrentTime(this.field0): super._();
+  
+
+ final  PlatformInt64 field0;
+
+/// Create a copy of StreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$StreamEvent_CurrentTimeCopyWith<StreamEvent_CurrentTime> get copyWith => _$StreamEvent_CurrentTimeCopyWithImpl<StreamEvent_CurrentTime>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is StreamEvent_CurrentTime&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'StreamEvent.currentTime(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $StreamEvent_CurrentTimeCopyWith<$Res> implements $StreamEventCopyWith<$Res> {
+  factory $StreamEvent_CurrentTimeCopyWith(StreamEvent_CurrentTime value, $Res Function(StreamEvent_CurrentTime) _then) = _$StreamEvent_CurrentTimeCopyWithImpl;
+@useResult
+$Res call({
+ PlatformInt64 field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$StreamEvent_CurrentTimeCopyWithImpl<$Res>
+    implements $StreamEvent_CurrentTimeCopyWith<$Res> {
+  _$StreamEvent_CurrentTimeCopyWithImpl(this._self, this._then);
+
+  final StreamEvent_CurrentTime _self;
+  final $Res Function(StreamEvent_CurrentTime) _then;
+
+/// Create a copy of StreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(StreamEvent_CurrentTime(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class StreamEvent_OriginVideoSize extends StreamEvent {
+  const StreamEvent_OriginVideoSize({required this.width, required this.height, required this.transformedWidth, required this.transformedHeight, required this.pixelAspectRatio, required this.displayAspectRatio, required this.frameRate}): super._();
+  
+
+ final  BigInt width;
+ final  BigInt height;
+ final  BigInt transformedWidth;
+ final  BigInt transformedHeight;
+ final  double pixelAspectRatio;
+ final  double displayAspectRatio;
+ final  double frameRate;
+
+/// Create a copy of StreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$StreamEvent_OriginVideoSizeCopyWith<StreamEvent_OriginVideoSize> get copyWith => _$StreamEvent_OriginVideoSizeCopyWithImpl<StreamEvent_OriginVideoSize>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is StreamEvent_OriginVideoSize&&(identical(other.width, width) || other.width == width)&&(identical(other.height, height) || other.height == height)&&(identical(other.transformedWidth, transformedWidth) || other.transformedWidth == transformedWidth)&&(identical(other.transformedHeight, transformedHeight) || other.transformedHeight == transformedHeight)&&(identical(other.pixelAspectRatio, pixelAspectRatio) || other.pixelAspectRatio == pixelAspectRatio)&&(identical(other.displayAspectRatio, displayAspectRatio) || other.displayAspectRatio == displayAspectRatio)&&(identical(other.frameRate, frameRate) || other.frameRate == frameRate));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,width,height,transformedWidth,transformedHeight,pixelAspectRatio,displayAspectRatio,frameRate);
+
+@override
+String toString() {
+  return 'StreamEvent.originVideoSize(width: $width, height: $height, transformedWidth: $transformedWidth, transformedHeight: $transformedHeight, pixelAspectRatio: $pixelAspectRatio, displayAspectRatio: $displayAspectRatio, frameRate: $frameRate)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $StreamEvent_OriginVideoSizeCopyWith<$Res> implements $StreamEventCopyWith<$Res> {
+  factory $StreamEvent_OriginVideoSizeCopyWith(StreamEvent_OriginVideoSize value, $Res Function(StreamEvent_OriginVideoSize) _then) = _$StreamEvent_OriginVideoSizeCopyWithImpl;
+@useResult
+$Res call({
+ BigInt width, BigInt height, BigInt transformedWidth, BigInt transformedHeight, double pixelAspectRatio, double displayAspectRatio, double frameRate
+});
+
+
+
+
+}
+/// @nodoc
+class _$StreamEvent_OriginVideoSizeCopyWithImpl<$Res>
+    implements $StreamEvent_OriginVideoSizeCopyWith<$Res> {
+  _$StreamEvent_OriginVideoSizeCopyWithImpl(this._self, this._then);
+
+  final StreamEvent_OriginVideoSize _self;
+  final $Res Function(StreamEvent_OriginVideoSize) _then;
+
+/// Create a copy of StreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? width = null,Object? height = null,Object? transformedWidth = null,Object? transformedHeight = null,Object? pixelAspectRatio = null,Object? displayAspectRatio = null,Object? frameRate = null,}) {
+  return _then(StreamEvent_OriginVideoSize(
+width: null == width ? _self.width : width // ignore: cast_nullable_to_non_nullable
+as BigInt,height: null == height ? _self.height : height // ignore: cast_nullable_to_non_nullable
+as BigInt,transformedWidth: null == transformedWidth ? _self.transformedWidth : transformedWidth // ignore: cast_nullable_to_non_nullable
+as BigInt,transformedHeight: null == transformedHeight ? _self.transformedHeight : transformedHeight // ignore: cast_nullable_to_non_nullable
+as BigInt,pixelAspectRatio: null == pixelAspectRatio ? _self.pixelAspectRatio : pixelAspectRatio // ignore: cast_nullable_to_non_nullable
+as double,displayAspectRatio: null == displayAspectRatio ? _self.displayAspectRatio : displayAspectRatio // ignore: cast_nullable_to_non_nullable
+as double,frameRate: null == frameRate ? _self.frameRate : frameRate // ignore: cast_nullable_to_non_nullable
+as double,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class StreamEvent_WscRtpSessionMode extends StreamEvent {
+  const StreamEvent_WscRtpSessionMode(this.field0): super._();
+  
+
+ final  WscRtpMode field0;
+
+/// Create a copy of StreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$StreamEvent_WscRtpSessionModeCopyWith<StreamEvent_WscRtpSessionMode> get copyWith => _$StreamEvent_WscRtpSessionModeCopyWithImpl<StreamEvent_WscRtpSessionMode>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is StreamEvent_WscRtpSessionMode&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'StreamEvent.wscRtpSessionMode(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $StreamEvent_WscRtpSessionModeCopyWith<$Res> implements $StreamEventCopyWith<$Res> {
+  factory $StreamEvent_WscRtpSessionModeCopyWith(StreamEvent_WscRtpSessionMode value, $Res Function(StreamEvent_WscRtpSessionMode) _then) = _$StreamEvent_WscRtpSessionModeCopyWithImpl;
+@useResult
+$Res call({
+ WscRtpMode field0
+});
+
+
+$WscRtpModeCopyWith<$Res> get field0;
+
+}
+/// @nodoc
+class _$StreamEvent_WscRtpSessionModeCopyWithImpl<$Res>
+    implements $StreamEvent_WscRtpSessionModeCopyWith<$Res> {
+  _$StreamEvent_WscRtpSessionModeCopyWithImpl(this._self, this._then);
+
+  final StreamEvent_WscRtpSessionMode _self;
+  final $Res Function(StreamEvent_WscRtpSessionMode) _then;
+
+/// Create a copy of StreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(StreamEvent_WscRtpSessionMode(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as WscRtpMode,
+  ));
+}
+
+/// Create a copy of StreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@override
+@pragma('vm:prefer-inline')
+$WscRtpModeCopyWith<$Res> get field0 {
+  
+  return $WscRtpModeCopyWith<$Res>(_self.field0, (value) {
+    return _then(_self.copyWith(field0: value));
+  });
+}
+}
+
+/// @nodoc
+
+
+class StreamEvent_WscRtpStreamState extends StreamEvent {
+  const StreamEvent_WscRtpStreamState(this.field0): super._();
+  
+
+ final  String field0;
+
+/// Create a copy of StreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$StreamEvent_WscRtpStreamStateCopyWith<StreamEvent_WscRtpStreamState> get copyWith => _$StreamEvent_WscRtpStreamStateCopyWithImpl<StreamEvent_WscRtpStreamState>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is StreamEvent_WscRtpStreamState&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'StreamEvent.wscRtpStreamState(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $StreamEvent_WscRtpStreamStateCopyWith<$Res> implements $StreamEventCopyWith<$Res> {
+  factory $StreamEvent_WscRtpStreamStateCopyWith(StreamEvent_WscRtpStreamState value, $Res Function(StreamEvent_WscRtpStreamState) _then) = _$StreamEvent_WscRtpStreamStateCopyWithImpl;
+@useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$StreamEvent_WscRtpStreamStateCopyWithImpl<$Res>
+    implements $StreamEvent_WscRtpStreamStateCopyWith<$Res> {
+  _$StreamEvent_WscRtpStreamStateCopyWithImpl(this._self, this._then);
+
+  final StreamEvent_WscRtpStreamState _self;
+  final $Res Function(StreamEvent_WscRtpStreamState) _then;
+
+/// Create a copy of StreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(StreamEvent_WscRtpStreamState(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class StreamEvent_Stalled extends StreamEvent {
+  const StreamEvent_Stalled({required this.sinceLastFrameMs}): super._();
+  
+
+ final  BigInt sinceLastFrameMs;
+
+/// Create a copy of StreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$StreamEvent_StalledCopyWith<StreamEvent_Stalled> get copyWith => _$StreamEvent_StalledCopyWithImpl<StreamEvent_Stalled>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is StreamEvent_Stalled&&(identical(other.sinceLastFrameMs, sinceLastFrameMs) || other.sinceLastFrameMs == sinceLastFrameMs));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,sinceLastFrameMs);
+
+@override
+String toString() {
+  return 'StreamEvent.stalled(sinceLastFrameMs: $sinceLastFrameMs)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $StreamEvent_StalledCopyWith<$Res> implements $StreamEventCopyWith<$Res> {
+  factory $StreamEvent_StalledCopyWith(StreamEvent_Stalled value, $Res Function(StreamEvent_Stalled) _then) = _$StreamEvent_StalledCopyWithImpl;
+@useResult
+$Res call({
+ BigInt sinceLastFrameMs
+});
+
+
+
+
+}
+/// @nodoc
+class _$StreamEvent_StalledCopyWithImpl<$Res>
+    implements $StreamEvent_StalledCopyWith<$Res> {
+  _$StreamEvent_StalledCopyWithImpl(this._self, this._then);
+
+  final StreamEvent_Stalled _self;
+  final $Res Function(StreamEvent_Stalled) _then;
+
+/// Create a copy of StreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? sinceLastFrameMs = null,}) {
+  return _then(StreamEvent_Stalled(
+sinceLastFrameMs: null == sinceLastFrameMs ? _self.sinceLastFrameMs : sinceLastFrameMs // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class StreamEvent_Resumed extends StreamEvent {
+  const StreamEvent_Resumed({required this.stalledForMs}): super._();
+  
+
+ final  BigInt stalledForMs;
+
+/// Create a copy of StreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$StreamEvent_ResumedCopyWith<StreamEvent_Resumed> get copyWith => _$StreamEvent_ResumedCopyWithImpl<StreamEvent_Resumed>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is StreamEvent_Resumed&&(identical(other.stalledForMs, stalledForMs) || other.stalledForMs == stalledForMs));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,stalledForMs);
+
+@override
+String toString() {
+  return 'StreamEvent.resumed(stalledForMs: $stalledForMs)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $StreamEvent_ResumedCopyWith<$Res> implements $StreamEventCopyWith<$Res> {
+  factory $StreamEvent_ResumedCopyWith(StreamEvent_Resumed value, $Res Function(StreamEvent_Resumed) _then) = _$StreamEvent_ResumedCopyWithImpl;
+@useResult
+$Res call({
+ BigInt stalledForMs
+});
+
+
+
+
+}
+/// @nodoc
+class _$StreamEvent_ResumedCopyWithImpl<$Res>
+    implements $StreamEvent_ResumedCopyWith<$Res> {
+  _$StreamEvent_ResumedCopyWithImpl(this._self, this._then);
+
+  final StreamEvent_Resumed _self;
+  final $Res Function(StreamEvent_Resumed) _then;
+
+/// Create a copy of StreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? stalledForMs = null,}) {
+  return _then(StreamEvent_Resumed(
+stalledForMs: null == stalledForMs ? _self.stalledForMs : stalledForMs // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class StreamEvent_StartupReport extends StreamEvent {
+  const StreamEvent_StartupReport(this.field0): super._();
+  
+
+ final  StartupReport field0;
+
+/// Create a copy of StreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$StreamEvent_StartupReportCopyWith<StreamEvent_StartupReport> get copyWith => _$StreamEvent_StartupReportCopyWithImpl<StreamEvent_StartupReport>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is StreamEvent_StartupReport&&(identical(other.field0, field0) || other.field0 == field0));
 }
 
 
 @override
-int get hashCode => runtimeType.hashCode;
+int get hashCode => Object.hash(runtimeType,field0);
 
 @override
 String toString() {
-  return 'StreamEvent()';
+  return 'StreamEvent.startupReport(field0: $field0)';
 }
 
 
 }
 
 /// @nodoc
-class $StreamEventCopyWith<$Res>  {
-$StreamEventCopyWith(StreamEvent _, $Res Function(StreamEvent) __);
-}
-
-
-/// Adds pattern-matching-related methods to [StreamEvent].
-extension StreamEventPatterns on StreamEvent {
-/// A variant of `map` that fallback to returning `orElse`.
-///
-/// It is equivalent to doing:
-/// ```dart
-/// switch (sealedClass) {
-///   case final Subclass value:
-///     return ...;
-///   case _:
-///     return orElse();
-/// }
-/// ```
-
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( StreamEvent_Error value)?  error,TResult Function( StreamEvent_CurrentTime value)?  currentTime,TResult Function( StreamEvent_OriginVideoSize value)?  originVideoSize,TResult Function( StreamEvent_WscRtpSessionMode value)?  wscRtpSessionMode,TResult Function( StreamEvent_WscRtpStreamState value)?  wscRtpStreamState,required TResult orElse(),}){
-final _that = this;
-switch (_that) {
-case StreamEvent_Error() when error != null:
-return error(_that);case StreamEvent_CurrentTime() when currentTime != null:
-return currentTime(_that);case StreamEvent_OriginVideoSize() when originVideoSize != null:
-return originVideoSize(_that);case StreamEvent_WscRtpSessionMode() when wscRtpSessionMode != null:
-return wscRtpSessionMode(_that);case StreamEvent_WscRtpStreamState() when wscRtpStreamState != null:
-return wscRtpStreamState(_that);case _:
-  return orElse();
+abstract mixin class $StreamEvent_StartupReportCopyWith<$Res> implements $StreamEventCopyWith<$Res> {
+  factory $StreamEvent_StartupReportCopyWith(StreamEvent_StartupReport value, $Res Function(StreamEvent_StartupReport) _then) = _$StreamEvent_StartupReportCopyWithImpl;
+@useResult
+$Res call({
+ StartupReport field0
+});
 
-}
-}
-/// A `switch`-like method, using callbacks.
-///
-/// Callbacks receives the raw object, upcasted.
-/// It is equivalent to doing:
-/// ```dart
-/// switch (sealedClass) {
-///   case final Subclass value:
-///     return ...;
-///   case final Subclass2 value:
-///     return ...;
-/// }
-/// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( StreamEvent_Error value)  error,required TResult Function( StreamEvent_CurrentTime value)  currentTime,required TResult Function( StreamEvent_OriginVideoSize value)  originVideoSize,required TResult Function( StreamEvent_WscRtpSessionMode value)  wscRtpSessionMode,required TResult Function( StreamEvent_WscRtpStreamState value)  wscRtpStreamState,}){
-final _that = this;
-switch (_that) {
-case StreamEvent_Error():
-return error(_that);case StreamEvent_CurrentTime():
-return currentTime(_that);case StreamEvent_OriginVideoSize():
-return originVideoSize(_that);case StreamEvent_WscRtpSessionMode():
-return wscRtpSessionMode(_that);case StreamEvent_WscRtpStreamState():
-return wscRtpStreamState(_that);}
-}
-/// A variant of `map` that fallback to returning `null`.
-///
-/// It is equivalent to doing:
-/// ```dart
-/// switch (sealedClass) {
-///   case final Subclass value:
-///     return ...;
-///   case _:
-///     return null;
-/// }
-/// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( StreamEvent_Error value)?  error,TResult? Function( StreamEvent_CurrentTime value)?  currentTime,TResult? Function( StreamEvent_OriginVideoSize value)?  originVideoSize,TResult? Function( StreamEvent_WscRtpSessionMode value)?  wscRtpSessionMode,TResult? Function( StreamEvent_WscRtpStreamState value)?  wscRtpStreamState,}){
-final _that = this;
-switch (_that) {
-case StreamEvent_Error() when error != null:
-return error(_that);case StreamEvent_CurrentTime() when currentTime != null:
-return currentTime(_that);case StreamEvent_OriginVideoSize() when originVideoSize != null:
-return originVideoSize(_that);case StreamEvent_WscRtpSessionMode() when wscRtpSessionMode != null:
-return wscRtpSessionMode(_that);case StreamEvent_WscRtpStreamState() when wscRtpStreamState != null:
-return wscRtpStreamState(_that);case _:
-  return null;
 
 }
-}
-/// A variant of `when` that fallback to an `orElse` callback.
-///
-/// It is equivalent to doing:
-/// ```dart
-/// switch (sealedClass) {
-///   case Subclass(:final field):
-///     return ...;
-///   case _:
-///     return orElse();
-/// }
-/// ```
-
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( String field0)?  error,TResult Function( PlatformInt64 field0)?  currentTime,TResult Function( BigInt width,  BigInt height)?  originVideoSize,TResult Function( WscRtpMode field0)?  wscRtpSessionMode,TResult Function( String field0)?  wscRtpStreamState,required TResult orElse(),}) {final _that = this;
-switch (_that) {
-case StreamEvent_Error() when error != null:
-return error(_that.field0);case StreamEvent_CurrentTime() when currentTime != null:
-return currentTime(_that.field0);case StreamEvent_OriginVideoSize() when originVideoSize != null:
-return originVideoSize(_that.width,_that.height);case StreamEvent_WscRtpSessionMode() when wscRtpSessionMode != null:
-return wscRtpSessionMode(_that.field0);case StreamEvent_WscRtpStreamState() when wscRtpStreamState != null:
-return wscRtpStreamState(_that.field0);case _:
-  return orElse();
+/// @nodoc
+class _$StreamEvent_StartupReportCopyWithImpl<$Res>
+    implements $StreamEvent_StartupReportCopyWith<$Res> {
+  _$StreamEvent_StartupReportCopyWithImpl(this._self, this._then);
 
-}
-}
-/// A `switch`-like method, using callbacks.
-///
-/// As opposed to `map`, this offers destructuring.
-/// It is equivalent to doing:
-/// ```dart
-/// switch (sealedClass) {
-///   case Subclass(:final field):
-///     return ...;
-///   case Subclass2(:final field2):
-///     return ...;
-/// }
-/// ```
+  final StreamEvent_StartupReport _self;
+  final $Res Function(StreamEvent_StartupReport) _then;
 
-@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( String field0)  error,required TResult Function( PlatformInt64 field0)  currentTime,required TResult Function( BigInt width,  BigInt height)  originVideoSize,required TResult Function( WscRtpMode field0)  wscRtpSessionMode,required TResult Function( String field0)  wscRtpStreamState,}) {final _that = this;
-switch (_that) {
-case StreamEvent_Error():
-return error(_that.field0);case StreamEvent_CurrentTime():
-return currentTime(_that.field0);case StreamEvent_OriginVideoSize():
-return originVideoSize(_that.width,_that.height);case StreamEvent_WscRtpSessionMode():
-return wscRtpSessionMode(_that.field0);case StreamEvent_WscRtpStreamState():
-return wscRtpStreamState(_that.field0);}
+/// Create a copy of StreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(StreamEvent_StartupReport(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as StartupReport,
+  ));
 }
-/// A variant of `when` that fallback to returning `null`
-///
-/// It is equivalent to doing:
-/// ```dart
-/// switch (sealedClass) {
-///   case Subclass(:final field):
-///     return ...;
-///   case _:
-///     return null;
-/// }
-/// ```
-
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( String field0)?  error,TResult? Function( PlatformInt64 field0)?  currentTime,TResult? Function( BigInt width,  BigInt height)?  originVideoSize,TResult? Function( WscRtpMode field0)?  wscRtpSessionMode,TResult? Function( String field0)?  wscRtpStreamState,}) {final _that = this;
-switch (_that) {
-case StreamEvent_Error() when error != null:
-return error(_that.field0);case StreamEvent_CurrentTime() when currentTime != null:
-return currentTime(_that.field0);case StreamEvent_OriginVideoSize() when originVideoSize != null:
-return originVideoSize(_that.width,_that.height);case StreamEvent_WscRtpSessionMode() when wscRtpSessionMode != null:
-return wscRtpSessionMode(_that.field0);case StreamEvent_WscRtpStreamState() when wscRtpStreamState != null:
-return wscRtpStreamState(_that.field0);case _:
-  return null;
 
-}
-}
 
 }
 
 /// @nodoc
 
 
-class StreamEvent_Error extends StreamEvent {
-  const StreamEvent_Error(this.field0): super._();
+class StreamEvent_StartupFailed extends StreamEvent {
+  const StreamEvent_StartupFailed(this.field0): super._();
   
 
- final  String field0;
+ final  StreamError field0;
 
 /// Create a copy of StreamEvent
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$StreamEvent_ErrorCopyWith<StreamEvent_Error> get copyWith => _$StreamEvent_ErrorCopyWithImpl<StreamEvent_Error>(this, _$identity);
+$StreamEvent_StartupFailedCopyWith<StreamEvent_StartupFailed> get copyWith => _$StreamEvent_StartupFailedCopyWithImpl<StreamEvent_StartupFailed>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is StreamEvent_Error&&(identical(other.field0, field0) || other.field0 == field0));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is StreamEvent_StartupFailed&&(identical(other.field0, field0) || other.field0 == field0));
 }
 
 
@@ -217,18 +1186,18 @@ int get hashCode => Object.hash(runtimeType,field0);
 
 @override
 String toString() {
-  return 'StreamEvent.error(field0: $field0)';
+  return 'StreamEvent.startupFailed(field0: $field0)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $StreamEvent_ErrorCopyWith<$Res> implements $StreamEventCopyWith<$Res> {
-  factory $StreamEvent_ErrorCopyWith(StreamEvent_Error value, $Res Function(StreamEvent_Error) _then) = _$StreamEvent_ErrorCopyWithImpl;
+abstract mixin class $StreamEvent_StartupFailedCopyWith<$Res> implements $StreamEventCopyWith<$Res> {
+  factory $StreamEvent_StartupFailedCopyWith(StreamEvent_StartupFailed value, $Res Function(StreamEvent_StartupFailed) _then) = _$StreamEvent_StartupFailedCopyWithImpl;
 @useResult
 $Res call({
- String field0
+ StreamError field0
 });
 
 
@@ -236,19 +1205,19 @@ $Res call({
 
 }
 /// @nodoc
-class _$StreamEvent_ErrorCopyWithImpl<$Res>
-    implements $StreamEvent_ErrorCopyWith<$Res> {
-  _$StreamEvent_ErrorCopyWithImpl(this._self, this._then);
+class _$StreamEvent_StartupFailedCopyWithImpl<$Res>
+    implements $StreamEvent_StartupFailedCopyWith<$Res> {
+  _$StreamEvent_StartupFailedCopyWithImpl(this._self, this._then);
 
-  final StreamEvent_Error _self;
-  final $Res Function(StreamEvent_Error) _then;
+  final StreamEvent_StartupFailed _self;
+  final $Res Function(StreamEvent_StartupFailed) _then;
 
 /// Create a copy of StreamEvent
 /// with the given fields replaced by the non-null parameter values.
 @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
-  return _then(StreamEvent_Error(
+  return _then(StreamEvent_StartupFailed(
 null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
-as String,
+as StreamError,
   ));
 }
 
@@ -258,43 +1227,44 @@ as String,
 /// @nodoc
 
 
-class StreamEvent_CurrentTime extends StreamEvent {
-  const StreamEvent_CurrentTime(this.field0): super._();
+class StreamEvent_Reconnecting extends StreamEvent {
+  const StreamEvent_Reconnecting({required this.attempt, required this.delayMs}): super._();
   
 
- final  PlatformInt64 field0;
+ final  int attempt;
+ final  BigInt delayMs;
 
 /// Create a copy of StreamEvent
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$StreamEvent_CurrentTimeCopyWith<StreamEvent_CurrentTime> get copyWith => _$StreamEvent_CurrentTimeCopyWithImpl<StreamEvent_CurrentTime>(this, _$identity);
+$StreamEvent_ReconnectingCopyWith<StreamEvent_Reconnecting> get copyWith => _$StreamEvent_ReconnectingCopyWithImpl<StreamEvent_Reconnecting>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is StreamEvent_CurrentTime&&(identical(other.field0, field0) || other.field0 == field0));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is StreamEvent_Reconnecting&&(identical(other.attempt, attempt) || other.attempt == attempt)&&(identical(other.delayMs, delayMs) || other.delayMs == delayMs));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,field0);
+int get hashCode => Object.hash(runtimeType,attempt,delayMs);
 
 @override
 String toString() {
-  return 'StreamEvent.currentTime(field0: $field0)';
+  return 'StreamEvent.reconnecting(attempt: $attempt, delayMs: $delayMs)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $StreamEvent_CurrentTimeCopyWith<$Res> implements $StreamEventCopyWith<$Res> {
-  factory $StreamEvent_CurrentTimeCopyWith(StreamEvent_CurrentTime value, $Res Function(StreamEvent_CurrentTime) _then) = _$StreamEvent_CurrentTimeCopyWithImpl;
+abstract mixin class $StreamEvent_ReconnectingCopyWith<$Res> implements $StreamEventCopyWith<$Res> {
+  factory $StreamEvent_ReconnectingCopyWith(StreamEvent_Reconnecting value, $Res Function(StreamEvent_Reconnecting) _then) = _$StreamEvent_ReconnectingCopyWithImpl;
 @useResult
 $Res call({
- PlatformInt64 field0
+ int attempt, BigInt delayMs
 });
 
 
@@ -302,19 +1272,20 @@ $Res call({
 
 }
 /// @nodoc
-class _$StreamEvent_CurrentTimeCopyWithImpl<$Res>
-    implements $StreamEvent_CurrentTimeCopyWith<$Res> {
-  _$StreamEvent_CurrentTimeCopyWithImpl(this._self, this._then);
+class _$StreamEvent_ReconnectingCopyWithImpl<$Res>
+    implements $StreamEvent_ReconnectingCopyWith<$Res> {
+  _$StreamEvent_ReconnectingCopyWithImpl(this._self, this._then);
 
-  final StreamEvent_CurrentTime _self;
-  final $Res Function(StreamEvent_CurrentTime) _then;
+  final StreamEvent_Reconnecting _self;
+  final $Res Function(StreamEvent_Reconnecting) _then;
 
 /// Create a copy of StreamEvent
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
-  return _then(StreamEvent_CurrentTime(
-null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
-as PlatformInt64,
+@pragma('vm:prefer-inline') $Res call({Object? attempt = null,Object? delayMs = null,}) {
+  return _then(StreamEvent_Reconnecting(
+attempt: null == attempt ? _self.attempt : attempt // ignore: cast_nullable_to_non_nullable
+as int,delayMs: null == delayMs ? _self.delayMs : delayMs // ignore: cast_nullable_to_non_nullable
+as BigInt,
   ));
 }
 
@@ -324,44 +1295,43 @@ as PlatformInt64,
 /// @nodoc
 
 
-class StreamEvent_OriginVideoSize extends StreamEvent {
-  const StreamEvent_OriginVideoSize({required this.width, required this.height}): super._();
+class StreamEvent_RecordingStarted extends StreamEvent {
+  const StreamEvent_RecordingStarted({required this.path}): super._();
   
 
- final  BigInt width;
- final  BigInt height;
+ final  String path;
 
 /// Create a copy of StreamEvent
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$StreamEvent_OriginVideoSizeCopyWith<StreamEvent_OriginVideoSize> get copyWith => _$StreamEvent_OriginVideoSizeCopyWithImpl<StreamEvent_OriginVideoSize>(this, _$identity);
+$StreamEvent_RecordingStartedCopyWith<StreamEvent_RecordingStarted> get copyWith => _$StreamEvent_RecordingStartedCopyWithImpl<StreamEvent_RecordingStarted>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is StreamEvent_OriginVideoSize&&(identical(other.width, width) || other.width == width)&&(identical(other.height, height) || other.height == height));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is StreamEvent_RecordingStarted&&(identical(other.path, path) || other.path == path));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,width,height);
+int get hashCode => Object.hash(runtimeType,path);
 
 @override
 String toString() {
-  return 'StreamEvent.originVideoSize(width: $width, height: $height)';
+  return 'StreamEvent.recordingStarted(path: $path)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $StreamEvent_OriginVideoSizeCopyWith<$Res> implements $StreamEventCopyWith<$Res> {
-  factory $StreamEvent_OriginVideoSizeCopyWith(StreamEvent_OriginVideoSize value, $Res Function(StreamEvent_OriginVideoSize) _then) = _$StreamEvent_OriginVideoSizeCopyWithImpl;
+abstract mixin class $StreamEvent_RecordingStartedCopyWith<$Res> implements $StreamEventCopyWith<$Res> {
+  factory $StreamEvent_RecordingStartedCopyWith(StreamEvent_RecordingStarted value, $Res Function(StreamEvent_RecordingStarted) _then) = _$StreamEvent_RecordingStartedCopyWithImpl;
 @useResult
 $Res call({
- BigInt width, BigInt height
+ String path
 });
 
 
@@ -369,20 +1339,19 @@ $Res call({
 
 }
 /// @nodoc
-class _$StreamEvent_OriginVideoSizeCopyWithImpl<$Res>
-    implements $StreamEvent_OriginVideoSizeCopyWith<$Res> {
-  _$StreamEvent_OriginVideoSizeCopyWithImpl(this._self, this._then);
+class _$StreamEvent_RecordingStartedCopyWithImpl<$Res>
+    implements $StreamEvent_RecordingStartedCopyWith<$Res> {
+  _$StreamEvent_RecordingStartedCopyWithImpl(this._self, this._then);
 
-  final StreamEvent_OriginVideoSize _self;
-  final $Res Function(StreamEvent_OriginVideoSize) _then;
+  final StreamEvent_RecordingStarted _self;
+  final $Res Function(StreamEvent_RecordingStarted) _then;
 
 /// Create a copy of StreamEvent
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') $Res call({Object? width = null,Object? height = null,}) {
-  return _then(StreamEvent_OriginVideoSize(
-width: null == width ? _self.width : width // ignore: cast_nullable_to_non_nullable
-as BigInt,height: null == height ? _self.height : height // ignore: cast_nullable_to_non_nullable
-as BigInt,
+@pragma('vm:prefer-inline') $Res call({Object? path = null,}) {
+  return _then(StreamEvent_RecordingStarted(
+path: null == path ? _self.path : path // ignore: cast_nullable_to_non_nullable
+as String,
   ));
 }
 
@@ -392,23 +1361,23 @@ as BigInt,
 /// @nodoc
 
 
-class StreamEvent_WscRtpSessionMode extends StreamEvent {
-  const StreamEvent_WscRtpSessionMode(this.field0): super._();
+class StreamEvent_RecordingStopped extends StreamEvent {
+  const StreamEvent_RecordingStopped(this.field0): super._();
   
 
- final  WscRtpMode field0;
+ final  RecordingInfo field0;
 
 /// Create a copy of StreamEvent
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$StreamEvent_WscRtpSessionModeCopyWith<StreamEvent_WscRtpSessionMode> get copyWith => _$StreamEvent_WscRtpSessionModeCopyWithImpl<StreamEvent_WscRtpSessionMode>(this, _$identity);
+$StreamEvent_RecordingStoppedCopyWith<StreamEvent_RecordingStopped> get copyWith => _$StreamEvent_RecordingStoppedCopyWithImpl<StreamEvent_RecordingStopped>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is StreamEvent_WscRtpSessionMode&&(identical(other.field0, field0) || other.field0 == field0));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is StreamEvent_RecordingStopped&&(identical(other.field0, field0) || other.field0 == field0));
 }
 
 
@@ -417,73 +1386,64 @@ int get hashCode => Object.hash(runtimeType,field0);
 
 @override
 String toString() {
-  return 'StreamEvent.wscRtpSessionMode(field0: $field0)';
+  return 'StreamEvent.recordingStopped(field0: $field0)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $StreamEvent_WscRtpSessionModeCopyWith<$Res> implements $StreamEventCopyWith<$Res> {
-  factory $StreamEvent_WscRtpSessionModeCopyWith(StreamEvent_WscRtpSessionMode value, $Res Function(StreamEvent_WscRtpSessionMode) _then) = _$StreamEvent_WscRtpSessionModeCopyWithImpl;
+abstract mixin class $StreamEvent_RecordingStoppedCopyWith<$Res> implements $StreamEventCopyWith<$Res> {
+  factory $StreamEvent_RecordingStoppedCopyWith(StreamEvent_RecordingStopped value, $Res Function(StreamEvent_RecordingStopped) _then) = _$StreamEvent_RecordingStoppedCopyWithImpl;
 @useResult
 $Res call({
- WscRtpMode field0
+ RecordingInfo field0
 });
 
 
-$WscRtpModeCopyWith<$Res> get field0;
+
 
 }
 /// @nodoc
-class _$StreamEvent_WscRtpSessionModeCopyWithImpl<$Res>
-    implements $StreamEvent_WscRtpSessionModeCopyWith<$Res> {
-  _$StreamEvent_WscRtpSessionModeCopyWithImpl(this._self, this._then);
+class _$StreamEvent_RecordingStoppedCopyWithImpl<$Res>
+    implements $StreamEvent_RecordingStoppedCopyWith<$Res> {
+  _$StreamEvent_RecordingStoppedCopyWithImpl(this._self, this._then);
 
-  final StreamEvent_WscRtpSessionMode _self;
-  final $Res Function(StreamEvent_WscRtpSessionMode) _then;
+  final StreamEvent_RecordingStopped _self;
+  final $Res Function(StreamEvent_RecordingStopped) _then;
 
 /// Create a copy of StreamEvent
 /// with the given fields replaced by the non-null parameter values.
 @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
-  return _then(StreamEvent_WscRtpSessionMode(
+  return _then(StreamEvent_RecordingStopped(
 null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
-as WscRtpMode,
+as RecordingInfo,
   ));
 }
 
-/// Create a copy of StreamEvent
-/// with the given fields replaced by the non-null parameter values.
-@override
-@pragma('vm:prefer-inline')
-$WscRtpModeCopyWith<$Res> get field0 {
-  
-  return $WscRtpModeCopyWith<$Res>(_self.field0, (value) {
-    return _then(_self.copyWith(field0: value));
-  });
-}
+
 }
 
 /// @nodoc
 
 
-class StreamEvent_WscRtpStreamState extends StreamEvent {
-  const StreamEvent_WscRtpStreamState(this.field0): super._();
+class StreamEvent_RecordingError extends StreamEvent {
+  const StreamEvent_RecordingError(this.field0): super._();
   
 
- final  String field0;
+ final  StreamError field0;
 
 /// Create a copy of StreamEvent
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$StreamEvent_WscRtpStreamStateCopyWith<StreamEvent_WscRtpStreamState> get copyWith => _$StreamEvent_WscRtpStreamStateCopyWithImpl<StreamEvent_WscRtpStreamState>(this, _$identity);
+$StreamEvent_RecordingErrorCopyWith<StreamEvent_RecordingError> get copyWith => _$StreamEvent_RecordingErrorCopyWithImpl<StreamEvent_RecordingError>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is StreamEvent_WscRtpStreamState&&(identical(other.field0, field0) || other.field0 == field0));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is StreamEvent_RecordingError&&(identical(other.field0, field0) || other.field0 == field0));
 }
 
 
@@ -492,18 +1452,18 @@ int get hashCode => Object.hash(runtimeType,field0);
 
 @override
 String toString() {
-  return 'StreamEvent.wscRtpStreamState(field0: $field0)';
+  return 'StreamEvent.recordingError(field0: $field0)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $StreamEvent_WscRtpStreamStateCopyWith<$Res> implements $StreamEventCopyWith<$Res> {
-  factory $StreamEvent_WscRtpStreamStateCopyWith(StreamEvent_WscRtpStreamState value, $Res Function(StreamEvent_WscRtpStreamState) _then) = _$StreamEvent_WscRtpStreamStateCopyWithImpl;
+abstract mixin class $StreamEvent_RecordingErrorCopyWith<$Res> implements $StreamEventCopyWith<$Res> {
+  factory $StreamEvent_RecordingErrorCopyWith(StreamEvent_RecordingError value, $Res Function(StreamEvent_RecordingError) _then) = _$StreamEvent_RecordingErrorCopyWithImpl;
 @useResult
 $Res call({
- String field0
+ StreamError field0
 });
 
 
@@ -511,19 +1471,19 @@ $Res call({
 
 }
 /// @nodoc
-class _$StreamEvent_WscRtpStreamStateCopyWithImpl<$Res>
-    implements $StreamEvent_WscRtpStreamStateCopyWith<$Res> {
-  _$StreamEvent_WscRtpStreamStateCopyWithImpl(this._self, this._then);
+class _$StreamEvent_RecordingErrorCopyWithImpl<$Res>
+    implements $StreamEvent_RecordingErrorCopyWith<$Res> {
+  _$StreamEvent_RecordingErrorCopyWithImpl(this._self, this._then);
 
-  final StreamEvent_WscRtpStreamState _self;
-  final $Res Function(StreamEvent_WscRtpStreamState) _then;
+  final StreamEvent_RecordingError _self;
+  final $Res Function(StreamEvent_RecordingError) _then;
 
 /// Create a copy of StreamEvent
 /// with the given fields replaced by the non-null parameter values.
 @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
-  return _then(StreamEvent_WscRtpStreamState(
+  return _then(StreamEvent_RecordingError(
 null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
-as String,
+as StreamError,
   ));
 }
 
@@ -954,7 +1914,7 @@ return stopped(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( String field0)?  error,TResult Function()?  loading,TResult Function( PlatformInt64 textureId,  bool seekable)?  playing,TResult Function()?  stopped,required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( StreamError field0)?  error,TResult Function()?  loading,TResult Function( PlatformInt64 textureId,  bool seekable)?  playing,TResult Function()?  stopped,required TResult orElse(),}) {final _that = this;
 switch (_that) {
 case StreamState_Error() when error != null:
 return error(_that.field0);case StreamState_Loading() when loading != null:
@@ -978,7 +1938,7 @@ return stopped();case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( String field0)  error,required TResult Function()  loading,required TResult Function( PlatformInt64 textureId,  bool seekable)  playing,required TResult Function()  stopped,}) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( StreamError field0)  error,required TResult Function()  loading,required TResult Function( PlatformInt64 textureId,  bool seekable)  playing,required TResult Function()  stopped,}) {final _that = this;
 switch (_that) {
 case StreamState_Error():
 return error(_that.field0);case StreamState_Loading():
@@ -998,7 +1958,7 @@ return stopped();}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( String field0)?  error,TResult? Function()?  loading,TResult? Function( PlatformInt64 textureId,  bool seekable)?  playing,TResult? Function()?  stopped,}) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( StreamError field0)?  error,TResult? Function()?  loading,TResult? Function( PlatformInt64 textureId,  bool seekable)?  playing,TResult? Function()?  stopped,}) {final _that = this;
 switch (_that) {
 case StreamState_Error() when error != null:
 return error(_that.field0);case StreamState_Loading() when loading != null:
@@ -1019,7 +1979,7 @@ class StreamState_Error extends StreamState {
   const StreamState_Error(this.field0): super._();
   
 
- final  String field0;
+ final  StreamError field0;
 
 /// Create a copy of StreamState
 /// with the given fields replaced by the non-null parameter values.
@@ -1051,7 +2011,7 @@ abstract mixin class $StreamState_ErrorCopyWith<$Res> implements $StreamStateCop
   factory $StreamState_ErrorCopyWith(StreamState_Error value, $Res Function(StreamState_Error) _then) = _$StreamState_ErrorCopyWithImpl;
 @useResult
 $Res call({
- String field0
+ StreamError field0
 });
 
 
@@ -1071,7 +2031,7 @@ class _$StreamState_ErrorCopyWithImpl<$Res>
 @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
   return _then(StreamState_Error(
 null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
-as String,
+as StreamError,
   ));
 }
 
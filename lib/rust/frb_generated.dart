@@ -68,7 +68,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => 454442348;
+  int get rustContentHash => 573161397;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -79,12 +79,30 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<Snapshot> crateApiSimpleCaptureSnapshot({
+    required PlatformInt64 sessionId,
+    required SnapshotFormat format,
+    int? quality,
+    int? maxSize,
+    required bool fullResolution,
+  });
+
+  Future<Snapshot> crateApiSimpleCaptureSnapshotToFile({
+    required PlatformInt64 sessionId,
+    required String path,
+    required SnapshotFormat format,
+    int? quality,
+    int? maxSize,
+    required bool fullResolution,
+  });
+
   Future<PlatformInt64> crateApiSimpleCreateNewSession();
 
   Stream<StreamMessage> crateApiSimpleCreatePlayable({
     required PlatformInt64 sessionId,
     required PlatformInt64 engineHandle,
     required VideoConfig config,
+    SessionOptions? options,
   });
 
   Future<void> crateApiSimpleDestroyEngineStreams({
@@ -95,8 +113,14 @@ abstract class RustLibApi extends BaseApi {
     required PlatformInt64 sessionId,
   });
 
+  Future<void> crateApiSimpleDestroyStreamSessionAndWait({
+    required PlatformInt64 sessionId,
+    BigInt? timeoutMs,
+  });
+
   Future<void> crateApiSimpleFlutterRealtimePlayerInit({
     required PlatformInt64 ffiPtr,
+    RuntimeConfig? runtimeConfig,
   });
 
   Future<void> crateApiSimpleInitApp();
@@ -105,16 +129,105 @@ abstract class RustLibApi extends BaseApi {
     required PlatformInt64 sessionId,
   });
 
+  Future<TapFrame?> crateApiSimpleNextFrame({
+    required PlatformInt64 sessionId,
+    required PlatformInt64 tapId,
+  });
+
+  Future<void> crateApiSimplePromoteStandbySession({
+    required PlatformInt64 standbySessionId,
+    required PlatformInt64 targetSessionId,
+  });
+
+  Future<void> crateApiSimpleResetOutputSize({
+    required PlatformInt64 sessionId,
+  });
+
+  Future<RecordingInfo> crateApiSimpleSavePreEventClip({
+    required PlatformInt64 sessionId,
+    required String path,
+    required BigInt postEventMs,
+    RecordingContainer? container,
+  });
+
   Future<void> crateApiSimpleSeekToTimestamp({
     required PlatformInt64 sessionId,
     required BigInt ts,
   });
 
+  Future<void> crateApiSimpleSetDigitalZoom({
+    required PlatformInt64 sessionId,
+    required double zoom,
+    required double panX,
+    required double panY,
+  });
+
+  Future<void> crateApiSimpleSetImageAdjustments({
+    required PlatformInt64 sessionId,
+    required ImageAdjustments adjustments,
+  });
+
+  Future<void> crateApiSimpleSetMaxFrameRate({
+    required PlatformInt64 sessionId,
+    int? maxFrameRate,
+  });
+
+  Future<void> crateApiSimpleSetOutputSize({
+    required PlatformInt64 sessionId,
+    required int width,
+    required int height,
+    required ScaleMode mode,
+  });
+
+  Future<void> crateApiSimpleSetPreEventBuffer({
+    required PlatformInt64 sessionId,
+    PreEventBuffer? config,
+  });
+
+  Future<void> crateApiSimpleSetPresentationPolicy({
+    required PlatformInt64 sessionId,
+    required PresentationPolicy policy,
+  });
+
   Future<void> crateApiSimpleSetSpeed({
     required PlatformInt64 sessionId,
     required double speed,
   });
 
+  Future<void> crateApiSimpleSetVideoTransform({
+    required PlatformInt64 sessionId,
+    required VideoTransform transform,
+  });
+
+  Future<void> crateApiSimpleStartRecording({
+    required PlatformInt64 sessionId,
+    required String path,
+    RecordingContainer? container,
+    BigInt? maxDurationMs,
+    BigInt? maxSizeBytes,
+    RecordingSegments? segments,
+  });
+
+  Future<RecordingInfo> crateApiSimpleStopRecording({
+    required PlatformInt64 sessionId,
+  });
+
+  Future<PlatformInt64> crateApiSimpleSubscribeFrames({
+    required PlatformInt64 sessionId,
+    FrameTapConfig? config,
+  });
+
+  Future<void> crateApiSimpleSwitchSource({
+    required PlatformInt64 sessionId,
+    required VideoConfig config,
+    SwitchPlaceholder? placeholder,
+  });
+
+  Future<void> crateApiSimpleUnsubscribeFrames({
+    required PlatformInt64 sessionId,
+    required PlatformInt64 tapId,
+  });
+
   Future<void> crateApiSimpleWscRtpGoLive({required PlatformInt64 sessionId});
 }
 
@@ -127,11 +240,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Future<PlatformInt64> crateApiSimpleCreateNewSession() {
+  Future<Snapshot> crateApiSimpleCaptureSnapshot({
+    required PlatformInt64 sessionId,
+    required SnapshotFormat format,
+    int? quality,
+    int? maxSize,
+    required bool fullResolution,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(sessionId, serializer);
+          sse_encode_snapshot_format(format, serializer);
+          sse_encode_opt_box_autoadd_u_8(quality, serializer);
+          sse_encode_opt_box_autoadd_u_32(maxSize, serializer);
+          sse_encode_bool(fullResolution, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -139,6 +263,92 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             port: port_,
           );
         },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_snapshot,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleCaptureSnapshotConstMeta,
+        argValues: [sessionId, format, quality, maxSize, fullResolution],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleCaptureSnapshotConstMeta =>
+      const TaskConstMeta(
+        debugName: "capture_snapshot",
+        argNames: [
+          "sessionId",
+          "format",
+          "quality",
+          "maxSize",
+          "fullResolution",
+        ],
+      );
+
+  @override
+  Future<Snapshot> crateApiSimpleCaptureSnapshotToFile({
+    required PlatformInt64 sessionId,
+    required String path,
+    required SnapshotFormat format,
+    int? quality,
+    int? maxSize,
+    required bool fullResolution,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(sessionId, serializer);
+          sse_encode_String(path, serializer);
+          sse_encode_snapshot_format(format, serializer);
+          sse_encode_opt_box_autoadd_u_8(quality, serializer);
+          sse_encode_opt_box_autoadd_u_32(maxSize, serializer);
+          sse_encode_bool(fullResolution, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 2,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_snapshot,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleCaptureSnapshotToFileConstMeta,
+        argValues: [sessionId, path, format, quality, maxSize, fullResolution],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleCaptureSnapshotToFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "capture_snapshot_to_file",
+        argNames: [
+          "sessionId",
+          "path",
+          "format",
+          "quality",
+          "maxSize",
+          "fullResolution",
+        ],
+      );
+
+  @override
+  Future<PlatformInt64> crateApiSimpleCreateNewSession() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 3,
+            port: port_,
+          );
+        },
         codec: SseCodec(
           decodeSuccessData: sse_decode_i_64,
           decodeErrorData: null,
@@ -158,6 +368,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     required PlatformInt64 sessionId,
     required PlatformInt64 engineHandle,
     required VideoConfig config,
+    SessionOptions? options,
   }) {
     final combinedSink = RustStreamSink<StreamMessage>();
     unawaited(
@@ -168,11 +379,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             sse_encode_i_64(sessionId, serializer);
             sse_encode_i_64(engineHandle, serializer);
             sse_encode_box_autoadd_video_config(config, serializer);
+            sse_encode_opt_box_autoadd_session_options(options, serializer);
             sse_encode_StreamSink_stream_message_Sse(combinedSink, serializer);
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 2,
+              funcId: 4,
               port: port_,
             );
           },
@@ -181,7 +393,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             decodeErrorData: sse_decode_AnyhowException,
           ),
           constMeta: kCrateApiSimpleCreatePlayableConstMeta,
-          argValues: [sessionId, engineHandle, config, combinedSink],
+          argValues: [sessionId, engineHandle, config, options, combinedSink],
           apiImpl: this,
         ),
       ),
@@ -192,7 +404,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiSimpleCreatePlayableConstMeta =>
       const TaskConstMeta(
         debugName: "create_playable",
-        argNames: ["sessionId", "engineHandle", "config", "combinedSink"],
+        argNames: [
+          "sessionId",
+          "engineHandle",
+          "config",
+          "options",
+          "combinedSink",
+        ],
       );
 
   @override
@@ -207,7 +425,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 3,
+            funcId: 5,
             port: port_,
           );
         },
@@ -240,7 +458,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 4,
+            funcId: 6,
             port: port_,
           );
         },
@@ -261,19 +479,56 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["sessionId"],
       );
 
+  @override
+  Future<void> crateApiSimpleDestroyStreamSessionAndWait({
+    required PlatformInt64 sessionId,
+    BigInt? timeoutMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(sessionId, serializer);
+          sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 7,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleDestroyStreamSessionAndWaitConstMeta,
+        argValues: [sessionId, timeoutMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleDestroyStreamSessionAndWaitConstMeta =>
+      const TaskConstMeta(
+        debugName: "destroy_stream_session_and_wait",
+        argNames: ["sessionId", "timeoutMs"],
+      );
+
   @override
   Future<void> crateApiSimpleFlutterRealtimePlayerInit({
     required PlatformInt64 ffiPtr,
+    RuntimeConfig? runtimeConfig,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_i_64(ffiPtr, serializer);
+          sse_encode_opt_box_autoadd_runtime_config(runtimeConfig, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 5,
+            funcId: 8,
             port: port_,
           );
         },
@@ -282,7 +537,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: null,
         ),
         constMeta: kCrateApiSimpleFlutterRealtimePlayerInitConstMeta,
-        argValues: [ffiPtr],
+        argValues: [ffiPtr, runtimeConfig],
         apiImpl: this,
       ),
     );
@@ -291,7 +546,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiSimpleFlutterRealtimePlayerInitConstMeta =>
       const TaskConstMeta(
         debugName: "flutter_realtime_player_init",
-        argNames: ["ffiPtr"],
+        argNames: ["ffiPtr", "runtimeConfig"],
       );
 
   @override
@@ -303,7 +558,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 6,
+            funcId: 9,
             port: port_,
           );
         },
@@ -333,7 +588,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 7,
+            funcId: 10,
             port: port_,
           );
         },
@@ -355,20 +610,54 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Future<void> crateApiSimpleSeekToTimestamp({
+  Future<TapFrame?> crateApiSimpleNextFrame({
     required PlatformInt64 sessionId,
-    required BigInt ts,
+    required PlatformInt64 tapId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_i_64(sessionId, serializer);
-          sse_encode_u_64(ts, serializer);
+          sse_encode_i_64(tapId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 8,
+            funcId: 11,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_tap_frame,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleNextFrameConstMeta,
+        argValues: [sessionId, tapId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleNextFrameConstMeta => const TaskConstMeta(
+    debugName: "next_frame",
+    argNames: ["sessionId", "tapId"],
+  );
+
+  @override
+  Future<void> crateApiSimplePromoteStandbySession({
+    required PlatformInt64 standbySessionId,
+    required PlatformInt64 targetSessionId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(standbySessionId, serializer);
+          sse_encode_i_64(targetSessionId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 12,
             port: port_,
           );
         },
@@ -376,34 +665,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSimpleSeekToTimestampConstMeta,
-        argValues: [sessionId, ts],
+        constMeta: kCrateApiSimplePromoteStandbySessionConstMeta,
+        argValues: [standbySessionId, targetSessionId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleSeekToTimestampConstMeta =>
+  TaskConstMeta get kCrateApiSimplePromoteStandbySessionConstMeta =>
       const TaskConstMeta(
-        debugName: "seek_to_timestamp",
-        argNames: ["sessionId", "ts"],
+        debugName: "promote_standby_session",
+        argNames: ["standbySessionId", "targetSessionId"],
       );
 
   @override
-  Future<void> crateApiSimpleSetSpeed({
+  Future<void> crateApiSimpleResetOutputSize({
     required PlatformInt64 sessionId,
-    required double speed,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_i_64(sessionId, serializer);
-          sse_encode_f_64(speed, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 9,
+            funcId: 13,
             port: port_,
           );
         },
@@ -411,695 +698,3471 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSimpleSetSpeedConstMeta,
-        argValues: [sessionId, speed],
+        constMeta: kCrateApiSimpleResetOutputSizeConstMeta,
+        argValues: [sessionId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleSetSpeedConstMeta => const TaskConstMeta(
-    debugName: "set_speed",
-    argNames: ["sessionId", "speed"],
-  );
+  TaskConstMeta get kCrateApiSimpleResetOutputSizeConstMeta =>
+      const TaskConstMeta(
+        debugName: "reset_output_size",
+        argNames: ["sessionId"],
+      );
 
   @override
-  Future<void> crateApiSimpleWscRtpGoLive({required PlatformInt64 sessionId}) {
+  Future<RecordingInfo> crateApiSimpleSavePreEventClip({
+    required PlatformInt64 sessionId,
+    required String path,
+    required BigInt postEventMs,
+    RecordingContainer? container,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_i_64(sessionId, serializer);
+          sse_encode_String(path, serializer);
+          sse_encode_u_64(postEventMs, serializer);
+          sse_encode_opt_box_autoadd_recording_container(container, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 10,
+            funcId: 14,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_recording_info,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSimpleWscRtpGoLiveConstMeta,
-        argValues: [sessionId],
+        constMeta: kCrateApiSimpleSavePreEventClipConstMeta,
+        argValues: [sessionId, path, postEventMs, container],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleWscRtpGoLiveConstMeta => const TaskConstMeta(
-    debugName: "wsc_rtp_go_live",
-    argNames: ["sessionId"],
-  );
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
+  TaskConstMeta get kCrateApiSimpleSavePreEventClipConstMeta =>
+      const TaskConstMeta(
+        debugName: "save_pre_event_clip",
+        argNames: ["sessionId", "path", "postEventMs", "container"],
+      );
 
-  @protected
-  RustStreamSink<StreamMessage> dco_decode_StreamSink_stream_message_Sse(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+  @override
+  Future<void> crateApiSimpleSeekToTimestamp({
+    required PlatformInt64 sessionId,
+    required BigInt ts,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(sessionId, serializer);
+          sse_encode_u_64(ts, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 15,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleSeekToTimestampConstMeta,
+        argValues: [sessionId, ts],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
+  TaskConstMeta get kCrateApiSimpleSeekToTimestampConstMeta =>
+      const TaskConstMeta(
+        debugName: "seek_to_timestamp",
+        argNames: ["sessionId", "ts"],
+      );
 
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  @override
+  Future<void> crateApiSimpleSetDigitalZoom({
+    required PlatformInt64 sessionId,
+    required double zoom,
+    required double panX,
+    required double panY,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(sessionId, serializer);
+          sse_encode_f_64(zoom, serializer);
+          sse_encode_f_64(panX, serializer);
+          sse_encode_f_64(panY, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 16,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleSetDigitalZoomConstMeta,
+        argValues: [sessionId, zoom, panX, panY],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSetDigitalZoomConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_digital_zoom",
+        argNames: ["sessionId", "zoom", "panX", "panY"],
+      );
+
+  @override
+  Future<void> crateApiSimpleSetImageAdjustments({
+    required PlatformInt64 sessionId,
+    required ImageAdjustments adjustments,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(sessionId, serializer);
+          sse_encode_box_autoadd_image_adjustments(adjustments, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 17,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleSetImageAdjustmentsConstMeta,
+        argValues: [sessionId, adjustments],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSetImageAdjustmentsConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_image_adjustments",
+        argNames: ["sessionId", "adjustments"],
+      );
+
+  @override
+  Future<void> crateApiSimpleSetMaxFrameRate({
+    required PlatformInt64 sessionId,
+    int? maxFrameRate,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(sessionId, serializer);
+          sse_encode_opt_box_autoadd_u_32(maxFrameRate, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 18,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleSetMaxFrameRateConstMeta,
+        argValues: [sessionId, maxFrameRate],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSetMaxFrameRateConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_max_frame_rate",
+        argNames: ["sessionId", "maxFrameRate"],
+      );
+
+  @override
+  Future<void> crateApiSimpleSetOutputSize({
+    required PlatformInt64 sessionId,
+    required int width,
+    required int height,
+    required ScaleMode mode,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(sessionId, serializer);
+          sse_encode_u_32(width, serializer);
+          sse_encode_u_32(height, serializer);
+          sse_encode_scale_mode(mode, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 19,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleSetOutputSizeConstMeta,
+        argValues: [sessionId, width, height, mode],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSetOutputSizeConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_output_size",
+        argNames: ["sessionId", "width", "height", "mode"],
+      );
+
+  @override
+  Future<void> crateApiSimpleSetPreEventBuffer({
+    required PlatformInt64 sessionId,
+    PreEventBuffer? config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(sessionId, serializer);
+          sse_encode_opt_box_autoadd_pre_event_buffer(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 20,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleSetPreEventBufferConstMeta,
+        argValues: [sessionId, config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSetPreEventBufferConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_pre_event_buffer",
+        argNames: ["sessionId", "config"],
+      );
+
+  @override
+  Future<void> crateApiSimpleSetPresentationPolicy({
+    required PlatformInt64 sessionId,
+    required PresentationPolicy policy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(sessionId, serializer);
+          sse_encode_box_autoadd_presentation_policy(policy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 21,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleSetPresentationPolicyConstMeta,
+        argValues: [sessionId, policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSetPresentationPolicyConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_presentation_policy",
+        argNames: ["sessionId", "policy"],
+      );
+
+  @override
+  Future<void> crateApiSimpleSetSpeed({
+    required PlatformInt64 sessionId,
+    required double speed,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(sessionId, serializer);
+          sse_encode_f_64(speed, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 22,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleSetSpeedConstMeta,
+        argValues: [sessionId, speed],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSetSpeedConstMeta => const TaskConstMeta(
+    debugName: "set_speed",
+    argNames: ["sessionId", "speed"],
+  );
+
+  @override
+  Future<void> crateApiSimpleSetVideoTransform({
+    required PlatformInt64 sessionId,
+    required VideoTransform transform,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(sessionId, serializer);
+          sse_encode_box_autoadd_video_transform(transform, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 23,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleSetVideoTransformConstMeta,
+        argValues: [sessionId, transform],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSetVideoTransformConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_video_transform",
+        argNames: ["sessionId", "transform"],
+      );
+
+  @override
+  Future<void> crateApiSimpleStartRecording({
+    required PlatformInt64 sessionId,
+    required String path,
+    RecordingContainer? container,
+    BigInt? maxDurationMs,
+    BigInt? maxSizeBytes,
+    RecordingSegments? segments,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(sessionId, serializer);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_box_autoadd_recording_container(container, serializer);
+          sse_encode_opt_box_autoadd_u_64(maxDurationMs, serializer);
+          sse_encode_opt_box_autoadd_u_64(maxSizeBytes, serializer);
+          sse_encode_opt_box_autoadd_recording_segments(segments, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 24,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleStartRecordingConstMeta,
+        argValues: [
+          sessionId,
+          path,
+          container,
+          maxDurationMs,
+          maxSizeBytes,
+          segments,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleStartRecordingConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_recording",
+        argNames: [
+          "sessionId",
+          "path",
+          "container",
+          "maxDurationMs",
+          "maxSizeBytes",
+          "segments",
+        ],
+      );
+
+  @override
+  Future<RecordingInfo> crateApiSimpleStopRecording({
+    required PlatformInt64 sessionId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(sessionId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 25,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_recording_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleStopRecordingConstMeta,
+        argValues: [sessionId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleStopRecordingConstMeta =>
+      const TaskConstMeta(debugName: "stop_recording", argNames: ["sessionId"]);
+
+  @override
+  Future<PlatformInt64> crateApiSimpleSubscribeFrames({
+    required PlatformInt64 sessionId,
+    FrameTapConfig? config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(sessionId, serializer);
+          sse_encode_opt_box_autoadd_frame_tap_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 26,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleSubscribeFramesConstMeta,
+        argValues: [sessionId, config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSubscribeFramesConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_frames",
+        argNames: ["sessionId", "config"],
+      );
+
+  @override
+  Future<void> crateApiSimpleSwitchSource({
+    required PlatformInt64 sessionId,
+    required VideoConfig config,
+    SwitchPlaceholder? placeholder,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(sessionId, serializer);
+          sse_encode_box_autoadd_video_config(config, serializer);
+          sse_encode_opt_box_autoadd_switch_placeholder(
+            placeholder,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 27,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleSwitchSourceConstMeta,
+        argValues: [sessionId, config, placeholder],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSwitchSourceConstMeta => const TaskConstMeta(
+    debugName: "switch_source",
+    argNames: ["sessionId", "config", "placeholder"],
+  );
+
+  @override
+  Future<void> crateApiSimpleUnsubscribeFrames({
+    required PlatformInt64 sessionId,
+    required PlatformInt64 tapId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(sessionId, serializer);
+          sse_encode_i_64(tapId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 28,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleUnsubscribeFramesConstMeta,
+        argValues: [sessionId, tapId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleUnsubscribeFramesConstMeta =>
+      const TaskConstMeta(
+        debugName: "unsubscribe_frames",
+        argNames: ["sessionId", "tapId"],
+      );
+
+  @override
+  Future<void> crateApiSimpleWscRtpGoLive({required PlatformInt64 sessionId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(sessionId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSimpleWscRtpGoLiveConstMeta,
+        argValues: [sessionId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleWscRtpGoLiveConstMeta => const TaskConstMeta(
+    debugName: "wsc_rtp_go_live",
+    argNames: ["sessionId"],
+  );
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  RustStreamSink<StreamMessage> dco_decode_StreamSink_stream_message_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  CropRect dco_decode_box_autoadd_crop_rect(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_crop_rect(raw);
+  }
+
+  @protected
+  FrameTapConfig dco_decode_box_autoadd_frame_tap_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_frame_tap_config(raw);
+  }
+
+  @protected
+  ImageAdjustments dco_decode_box_autoadd_image_adjustments(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_image_adjustments(raw);
+  }
+
+  @protected
+  KeepAlivePolicy dco_decode_box_autoadd_keep_alive_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_keep_alive_policy(raw);
+  }
+
+  @protected
+  OutputSize dco_decode_box_autoadd_output_size(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_output_size(raw);
+  }
+
+  @protected
+  PlaybinConfig dco_decode_box_autoadd_playbin_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_playbin_config(raw);
+  }
+
+  @protected
+  PreEventBuffer dco_decode_box_autoadd_pre_event_buffer(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_pre_event_buffer(raw);
+  }
+
+  @protected
+  PresentationPolicy dco_decode_box_autoadd_presentation_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_presentation_policy(raw);
+  }
+
+  @protected
+  RecordingContainer dco_decode_box_autoadd_recording_container(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_recording_container(raw);
+  }
+
+  @protected
+  RecordingInfo dco_decode_box_autoadd_recording_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_recording_info(raw);
+  }
+
+  @protected
+  RecordingSegments dco_decode_box_autoadd_recording_segments(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_recording_segments(raw);
+  }
+
+  @protected
+  RuntimeConfig dco_decode_box_autoadd_runtime_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_runtime_config(raw);
+  }
+
+  @protected
+  SessionOptions dco_decode_box_autoadd_session_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_session_options(raw);
+  }
+
+  @protected
+  StallWatchdogConfig dco_decode_box_autoadd_stall_watchdog_config(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_stall_watchdog_config(raw);
+  }
+
+  @protected
+  StartupReport dco_decode_box_autoadd_startup_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_startup_report(raw);
+  }
+
+  @protected
+  StreamError dco_decode_box_autoadd_stream_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_stream_error(raw);
+  }
+
+  @protected
+  StreamEvent dco_decode_box_autoadd_stream_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_stream_event(raw);
+  }
+
+  @protected
+  StreamState dco_decode_box_autoadd_stream_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_stream_state(raw);
+  }
+
+  @protected
+  SwitchPlaceholder dco_decode_box_autoadd_switch_placeholder(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_switch_placeholder(raw);
+  }
+
+  @protected
+  TapFrame dco_decode_box_autoadd_tap_frame(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_tap_frame(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  VideoConfig dco_decode_box_autoadd_video_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_video_config(raw);
+  }
+
+  @protected
+  VideoTransform dco_decode_box_autoadd_video_transform(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_video_transform(raw);
+  }
+
+  @protected
+  WscRtpMode dco_decode_box_autoadd_wsc_rtp_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_wsc_rtp_mode(raw);
+  }
+
+  @protected
+  WscRtpSessionConfig dco_decode_box_autoadd_wsc_rtp_session_config(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_wsc_rtp_session_config(raw);
+  }
+
+  @protected
+  CropRect dco_decode_crop_rect(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return CropRect(
+      x: dco_decode_f_64(arr[0]),
+      y: dco_decode_f_64(arr[1]),
+      width: dco_decode_f_64(arr[2]),
+      height: dco_decode_f_64(arr[3]),
+    );
+  }
+
+  @protected
+  DeinterlaceMethod dco_decode_deinterlace_method(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DeinterlaceMethod.values[raw as int];
+  }
+
+  @protected
+  DeinterlaceMode dco_decode_deinterlace_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DeinterlaceMode.values[raw as int];
+  }
+
+  @protected
+  EndFramePolicy dco_decode_end_frame_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return EndFramePolicy_HoldLastFrame();
+      case 1:
+        return EndFramePolicy_Dim(brightnessPercent: dco_decode_u_8(raw[1]));
+      case 2:
+        return EndFramePolicy_Clear();
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  ErrorBackend dco_decode_error_backend(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ErrorBackend_WscRtp();
+      case 1:
+        return ErrorBackend_Playbin();
+      case 2:
+        return ErrorBackend_Registry();
+      case 3:
+        return ErrorBackend_Recorder();
+      case 4:
+        return ErrorBackend_Custom(dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  ErrorCategory dco_decode_error_category(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ErrorCategory.values[raw as int];
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FrameOutputMode dco_decode_frame_output_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return FrameOutputMode_FlutterTexture();
+      case 1:
+        return FrameOutputMode_Headless(historyLen: dco_decode_u_32(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  FrameTapConfig dco_decode_frame_tap_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return FrameTapConfig(
+      maxFrameRate: dco_decode_opt_box_autoadd_u_32(arr[0]),
+      size: dco_decode_opt_box_autoadd_output_size(arr[1]),
+      format: dco_decode_tap_pixel_format(arr[2]),
+      queueLen: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  ImageAdjustments dco_decode_image_adjustments(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return ImageAdjustments(
+      deinterlace: dco_decode_deinterlace_mode(arr[0]),
+      deinterlaceMethod: dco_decode_deinterlace_method(arr[1]),
+      brightness: dco_decode_f_64(arr[2]),
+      contrast: dco_decode_f_64(arr[3]),
+      saturation: dco_decode_f_64(arr[4]),
+      hue: dco_decode_f_64(arr[5]),
+      gamma: dco_decode_f_64(arr[6]),
+    );
+  }
+
+  @protected
+  KeepAlivePolicy dco_decode_keep_alive_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return KeepAlivePolicy_Timeout(timeoutMs: dco_decode_u_64(raw[1]));
+      case 1:
+        return KeepAlivePolicy_Disabled();
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint32List;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  CropRect? dco_decode_opt_box_autoadd_crop_rect(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_crop_rect(raw);
+  }
+
+  @protected
+  FrameTapConfig? dco_decode_opt_box_autoadd_frame_tap_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_frame_tap_config(raw);
+  }
+
+  @protected
+  KeepAlivePolicy? dco_decode_opt_box_autoadd_keep_alive_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_keep_alive_policy(raw);
+  }
+
+  @protected
+  OutputSize? dco_decode_opt_box_autoadd_output_size(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_output_size(raw);
+  }
+
+  @protected
+  PreEventBuffer? dco_decode_opt_box_autoadd_pre_event_buffer(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_pre_event_buffer(raw);
+  }
+
+  @protected
+  RecordingContainer? dco_decode_opt_box_autoadd_recording_container(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_recording_container(raw);
+  }
+
+  @protected
+  RecordingSegments? dco_decode_opt_box_autoadd_recording_segments(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_recording_segments(raw);
+  }
+
+  @protected
+  RuntimeConfig? dco_decode_opt_box_autoadd_runtime_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_runtime_config(raw);
+  }
+
+  @protected
+  SessionOptions? dco_decode_opt_box_autoadd_session_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_session_options(raw);
+  }
+
+  @protected
+  StallWatchdogConfig? dco_decode_opt_box_autoadd_stall_watchdog_config(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_stall_watchdog_config(raw);
+  }
+
+  @protected
+  SwitchPlaceholder? dco_decode_opt_box_autoadd_switch_placeholder(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_switch_placeholder(raw);
+  }
+
+  @protected
+  TapFrame? dco_decode_opt_box_autoadd_tap_frame(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_tap_frame(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_16(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_8(raw);
+  }
+
+  @protected
+  OutputSize dco_decode_output_size(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return OutputSize(
+      width: dco_decode_u_32(arr[0]),
+      height: dco_decode_u_32(arr[1]),
+      mode: dco_decode_scale_mode(arr[2]),
+    );
+  }
+
+  @protected
+  PlaceholderFrame dco_decode_placeholder_frame(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return PlaceholderFrame_Black();
+      case 1:
+        return PlaceholderFrame_Transparent();
+      case 2:
+        return PlaceholderFrame_Rgba(
+          width: dco_decode_u_32(raw[1]),
+          height: dco_decode_u_32(raw[2]),
+          data: dco_decode_list_prim_u_8_strict(raw[3]),
+        );
+      case 3:
+        return PlaceholderFrame_Poster(uri: dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  PlaybinConfig dco_decode_playbin_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PlaybinConfig(
+      uri: dco_decode_String(arr[0]),
+      mute: dco_decode_bool(arr[1]),
+      autoRestart: dco_decode_bool(arr[2]),
+      stallTimeoutMs: dco_decode_opt_box_autoadd_u_64(arr[3]),
+      maxRestartAttempts: dco_decode_opt_box_autoadd_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  PreEventBuffer dco_decode_pre_event_buffer(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PreEventBuffer(
+      durationMs: dco_decode_u_64(arr[0]),
+      maxBytes: dco_decode_opt_box_autoadd_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  PresentationPolicy dco_decode_presentation_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PresentationPolicy(
+      placeholder: dco_decode_placeholder_frame(arr[0]),
+      onStop: dco_decode_end_frame_policy(arr[1]),
+      onError: dco_decode_end_frame_policy(arr[2]),
+      reconnectOverlayArgb: dco_decode_opt_box_autoadd_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  RecordingContainer dco_decode_recording_container(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RecordingContainer.values[raw as int];
+  }
+
+  @protected
+  RecordingInfo dco_decode_recording_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return RecordingInfo(
+      files: dco_decode_list_String(arr[0]),
+      durationMs: dco_decode_u_64(arr[1]),
+      sizeBytes: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  RecordingSegments dco_decode_recording_segments(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RecordingSegments(
+      durationMs: dco_decode_u_64(arr[0]),
+      maxFiles: dco_decode_opt_box_autoadd_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  Rotation dco_decode_rotation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Rotation.values[raw as int];
+  }
+
+  @protected
+  RuntimeConfig dco_decode_runtime_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RuntimeConfig(
+      workerThreads: dco_decode_opt_box_autoadd_u_32(arr[0]),
+      threadName: dco_decode_opt_String(arr[1]),
+    );
+  }
+
+  @protected
+  ScaleMode dco_decode_scale_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ScaleMode.values[raw as int];
+  }
+
+  @protected
+  SessionOptions dco_decode_session_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return SessionOptions(
+      stallWatchdog: dco_decode_opt_box_autoadd_stall_watchdog_config(arr[0]),
+      keepAlive: dco_decode_opt_box_autoadd_keep_alive_policy(arr[1]),
+      awaitFirstFrameTimeoutMs: dco_decode_opt_box_autoadd_u_64(arr[2]),
+      shareDecode: dco_decode_bool(arr[3]),
+      standby: dco_decode_bool(arr[4]),
+      frameOutput: dco_decode_frame_output_mode(arr[5]),
+      presentation: dco_decode_presentation_policy(arr[6]),
+      maxFrameRate: dco_decode_opt_box_autoadd_u_32(arr[7]),
+      preEventBuffer: dco_decode_opt_box_autoadd_pre_event_buffer(arr[8]),
+    );
+  }
+
+  @protected
+  Snapshot dco_decode_snapshot(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return Snapshot(
+      data: dco_decode_list_prim_u_8_strict(arr[0]),
+      width: dco_decode_u_32(arr[1]),
+      height: dco_decode_u_32(arr[2]),
+      ptsMs: dco_decode_opt_box_autoadd_u_64(arr[3]),
+      capturedAtMs: dco_decode_i_64(arr[4]),
+    );
+  }
+
+  @protected
+  SnapshotFormat dco_decode_snapshot_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SnapshotFormat.values[raw as int];
+  }
+
+  @protected
+  StallWatchdogConfig dco_decode_stall_watchdog_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return StallWatchdogConfig(
+      stallThresholdMs: dco_decode_u_64(arr[0]),
+      reconnectAfterMs: dco_decode_opt_box_autoadd_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  StartupReport dco_decode_startup_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return StartupReport(
+      connectMs: dco_decode_opt_box_autoadd_u_64(arr[0]),
+      handshakeMs: dco_decode_opt_box_autoadd_u_64(arr[1]),
+      prerollMs: dco_decode_opt_box_autoadd_u_64(arr[2]),
+      firstFrameMs: dco_decode_u_64(arr[3]),
+      attempts: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  StreamError dco_decode_stream_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return StreamError(
+      category: dco_decode_error_category(arr[0]),
+      retryable: dco_decode_bool(arr[1]),
+      backend: dco_decode_error_backend(arr[2]),
+      cause: dco_decode_opt_String(arr[3]),
+      message: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  StreamEvent dco_decode_stream_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return StreamEvent_Error(dco_decode_box_autoadd_stream_error(raw[1]));
+      case 1:
+        return StreamEvent_CurrentTime(dco_decode_i_64(raw[1]));
+      case 2:
+        return StreamEvent_OriginVideoSize(
+          width: dco_decode_u_64(raw[1]),
+          height: dco_decode_u_64(raw[2]),
+          transformedWidth: dco_decode_u_64(raw[3]),
+          transformedHeight: dco_decode_u_64(raw[4]),
+          pixelAspectRatio: dco_decode_f_64(raw[5]),
+          displayAspectRatio: dco_decode_f_64(raw[6]),
+          frameRate: dco_decode_f_64(raw[7]),
+        );
+      case 3:
+        return StreamEvent_WscRtpSessionMode(
+          dco_decode_box_autoadd_wsc_rtp_mode(raw[1]),
+        );
+      case 4:
+        return StreamEvent_WscRtpStreamState(dco_decode_String(raw[1]));
+      case 5:
+        return StreamEvent_Stalled(sinceLastFrameMs: dco_decode_u_64(raw[1]));
+      case 6:
+        return StreamEvent_Resumed(stalledForMs: dco_decode_u_64(raw[1]));
+      case 7:
+        return StreamEvent_StartupReport(
+          dco_decode_box_autoadd_startup_report(raw[1]),
+        );
+      case 8:
+        return StreamEvent_StartupFailed(
+          dco_decode_box_autoadd_stream_error(raw[1]),
+        );
+      case 9:
+        return StreamEvent_Reconnecting(
+          attempt: dco_decode_u_32(raw[1]),
+          delayMs: dco_decode_u_64(raw[2]),
+        );
+      case 10:
+        return StreamEvent_RecordingStarted(path: dco_decode_String(raw[1]));
+      case 11:
+        return StreamEvent_RecordingStopped(
+          dco_decode_box_autoadd_recording_info(raw[1]),
+        );
+      case 12:
+        return StreamEvent_RecordingError(
+          dco_decode_box_autoadd_stream_error(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  StreamMessage dco_decode_stream_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return StreamMessage_State(dco_decode_box_autoadd_stream_state(raw[1]));
+      case 1:
+        return StreamMessage_Event(dco_decode_box_autoadd_stream_event(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  StreamState dco_decode_stream_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return StreamState_Error(dco_decode_box_autoadd_stream_error(raw[1]));
+      case 1:
+        return StreamState_Loading();
+      case 2:
+        return StreamState_Playing(
+          textureId: dco_decode_i_64(raw[1]),
+          seekable: dco_decode_bool(raw[2]),
+        );
+      case 3:
+        return StreamState_Stopped();
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  SwitchPlaceholder dco_decode_switch_placeholder(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SwitchPlaceholder.values[raw as int];
+  }
+
+  @protected
+  TapFrame dco_decode_tap_frame(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return TapFrame(
+      data: dco_decode_list_prim_u_8_strict(arr[0]),
+      width: dco_decode_u_32(arr[1]),
+      height: dco_decode_u_32(arr[2]),
+      format: dco_decode_tap_pixel_format(arr[3]),
+      planeStrides: dco_decode_list_prim_u_32_strict(arr[4]),
+      planeOffsets: dco_decode_list_prim_u_32_strict(arr[5]),
+      ptsMs: dco_decode_opt_box_autoadd_u_64(arr[6]),
+      capturedAtMs: dco_decode_i_64(arr[7]),
+      sequence: dco_decode_u_64(arr[8]),
+      dropped: dco_decode_u_64(arr[9]),
+    );
+  }
+
+  @protected
+  TapPixelFormat dco_decode_tap_pixel_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TapPixelFormat.values[raw as int];
+  }
+
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  VideoConfig dco_decode_video_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return VideoConfig_WscRtp(
+          dco_decode_box_autoadd_wsc_rtp_session_config(raw[1]),
+        );
+      case 1:
+        return VideoConfig_Playbin(
+          dco_decode_box_autoadd_playbin_config(raw[1]),
+        );
+      case 2:
+        return VideoConfig_Custom(
+          kind: dco_decode_String(raw[1]),
+          jsonParams: dco_decode_String(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  VideoTransform dco_decode_video_transform(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return VideoTransform(
+      rotation: dco_decode_rotation(arr[0]),
+      flipHorizontal: dco_decode_bool(arr[1]),
+      flipVertical: dco_decode_bool(arr[2]),
+      crop: dco_decode_opt_box_autoadd_crop_rect(arr[3]),
+      zoom: dco_decode_f_64(arr[4]),
+      panX: dco_decode_f_64(arr[5]),
+      panY: dco_decode_f_64(arr[6]),
+    );
+  }
+
+  @protected
+  WscRtpMode dco_decode_wsc_rtp_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return WscRtpMode_Live();
+      case 1:
+        return WscRtpMode_Dvr(
+          currentTimeMs: dco_decode_i_64(raw[1]),
+          speed: dco_decode_f_64(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  WscRtpSessionConfig dco_decode_wsc_rtp_session_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return WscRtpSessionConfig(
+      baseUrl: dco_decode_String(arr[0]),
+      sourceId: dco_decode_String(arr[1]),
+      clientPort: dco_decode_opt_box_autoadd_u_16(arr[2]),
+      forceWebsocketTransport: dco_decode_bool(arr[3]),
+      autoRestart: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  RustStreamSink<StreamMessage> sse_decode_StreamSink_stream_message_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  CropRect sse_decode_box_autoadd_crop_rect(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_crop_rect(deserializer));
+  }
+
+  @protected
+  FrameTapConfig sse_decode_box_autoadd_frame_tap_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_frame_tap_config(deserializer));
+  }
+
+  @protected
+  ImageAdjustments sse_decode_box_autoadd_image_adjustments(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_image_adjustments(deserializer));
+  }
+
+  @protected
+  KeepAlivePolicy sse_decode_box_autoadd_keep_alive_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_keep_alive_policy(deserializer));
+  }
+
+  @protected
+  OutputSize sse_decode_box_autoadd_output_size(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_output_size(deserializer));
+  }
+
+  @protected
+  PlaybinConfig sse_decode_box_autoadd_playbin_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_playbin_config(deserializer));
+  }
+
+  @protected
+  PreEventBuffer sse_decode_box_autoadd_pre_event_buffer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_pre_event_buffer(deserializer));
+  }
+
+  @protected
+  PresentationPolicy sse_decode_box_autoadd_presentation_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_presentation_policy(deserializer));
+  }
+
+  @protected
+  RecordingContainer sse_decode_box_autoadd_recording_container(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_recording_container(deserializer));
+  }
+
+  @protected
+  RecordingInfo sse_decode_box_autoadd_recording_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_recording_info(deserializer));
+  }
+
+  @protected
+  RecordingSegments sse_decode_box_autoadd_recording_segments(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_recording_segments(deserializer));
+  }
+
+  @protected
+  RuntimeConfig sse_decode_box_autoadd_runtime_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_runtime_config(deserializer));
+  }
+
+  @protected
+  SessionOptions sse_decode_box_autoadd_session_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_session_options(deserializer));
+  }
+
+  @protected
+  StallWatchdogConfig sse_decode_box_autoadd_stall_watchdog_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_stall_watchdog_config(deserializer));
+  }
+
+  @protected
+  StartupReport sse_decode_box_autoadd_startup_report(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_startup_report(deserializer));
+  }
+
+  @protected
+  StreamError sse_decode_box_autoadd_stream_error(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_stream_error(deserializer));
+  }
+
+  @protected
+  StreamEvent sse_decode_box_autoadd_stream_event(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_stream_event(deserializer));
+  }
+
+  @protected
+  StreamState sse_decode_box_autoadd_stream_state(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_stream_state(deserializer));
+  }
+
+  @protected
+  SwitchPlaceholder sse_decode_box_autoadd_switch_placeholder(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_switch_placeholder(deserializer));
+  }
+
+  @protected
+  TapFrame sse_decode_box_autoadd_tap_frame(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_tap_frame(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_16(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_8(deserializer));
+  }
+
+  @protected
+  VideoConfig sse_decode_box_autoadd_video_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_video_config(deserializer));
+  }
+
+  @protected
+  VideoTransform sse_decode_box_autoadd_video_transform(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_video_transform(deserializer));
+  }
+
+  @protected
+  WscRtpMode sse_decode_box_autoadd_wsc_rtp_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_wsc_rtp_mode(deserializer));
+  }
+
+  @protected
+  WscRtpSessionConfig sse_decode_box_autoadd_wsc_rtp_session_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_wsc_rtp_session_config(deserializer));
+  }
+
+  @protected
+  CropRect sse_decode_crop_rect(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_x = sse_decode_f_64(deserializer);
+    var var_y = sse_decode_f_64(deserializer);
+    var var_width = sse_decode_f_64(deserializer);
+    var var_height = sse_decode_f_64(deserializer);
+    return CropRect(x: var_x, y: var_y, width: var_width, height: var_height);
+  }
+
+  @protected
+  DeinterlaceMethod sse_decode_deinterlace_method(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DeinterlaceMethod.values[inner];
+  }
+
+  @protected
+  DeinterlaceMode sse_decode_deinterlace_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DeinterlaceMode.values[inner];
+  }
+
+  @protected
+  EndFramePolicy sse_decode_end_frame_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return EndFramePolicy_HoldLastFrame();
+      case 1:
+        var var_brightnessPercent = sse_decode_u_8(deserializer);
+        return EndFramePolicy_Dim(brightnessPercent: var_brightnessPercent);
+      case 2:
+        return EndFramePolicy_Clear();
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  ErrorBackend sse_decode_error_backend(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return ErrorBackend_WscRtp();
+      case 1:
+        return ErrorBackend_Playbin();
+      case 2:
+        return ErrorBackend_Registry();
+      case 3:
+        return ErrorBackend_Recorder();
+      case 4:
+        var var_field0 = sse_decode_String(deserializer);
+        return ErrorBackend_Custom(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  ErrorCategory sse_decode_error_category(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ErrorCategory.values[inner];
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  FrameOutputMode sse_decode_frame_output_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return FrameOutputMode_FlutterTexture();
+      case 1:
+        var var_historyLen = sse_decode_u_32(deserializer);
+        return FrameOutputMode_Headless(historyLen: var_historyLen);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  FrameTapConfig sse_decode_frame_tap_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxFrameRate = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_size = sse_decode_opt_box_autoadd_output_size(deserializer);
+    var var_format = sse_decode_tap_pixel_format(deserializer);
+    var var_queueLen = sse_decode_u_32(deserializer);
+    return FrameTapConfig(
+      maxFrameRate: var_maxFrameRate,
+      size: var_size,
+      format: var_format,
+      queueLen: var_queueLen,
+    );
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  ImageAdjustments sse_decode_image_adjustments(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_deinterlace = sse_decode_deinterlace_mode(deserializer);
+    var var_deinterlaceMethod = sse_decode_deinterlace_method(deserializer);
+    var var_brightness = sse_decode_f_64(deserializer);
+    var var_contrast = sse_decode_f_64(deserializer);
+    var var_saturation = sse_decode_f_64(deserializer);
+    var var_hue = sse_decode_f_64(deserializer);
+    var var_gamma = sse_decode_f_64(deserializer);
+    return ImageAdjustments(
+      deinterlace: var_deinterlace,
+      deinterlaceMethod: var_deinterlaceMethod,
+      brightness: var_brightness,
+      contrast: var_contrast,
+      saturation: var_saturation,
+      hue: var_hue,
+      gamma: var_gamma,
+    );
+  }
+
+  @protected
+  KeepAlivePolicy sse_decode_keep_alive_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_timeoutMs = sse_decode_u_64(deserializer);
+        return KeepAlivePolicy_Timeout(timeoutMs: var_timeoutMs);
+      case 1:
+        return KeepAlivePolicy_Disabled();
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint32List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  CropRect? sse_decode_opt_box_autoadd_crop_rect(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_crop_rect(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  FrameTapConfig? sse_decode_opt_box_autoadd_frame_tap_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_frame_tap_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  KeepAlivePolicy? sse_decode_opt_box_autoadd_keep_alive_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_keep_alive_policy(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  OutputSize? sse_decode_opt_box_autoadd_output_size(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_output_size(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PreEventBuffer? sse_decode_opt_box_autoadd_pre_event_buffer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_pre_event_buffer(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  RecordingContainer? sse_decode_opt_box_autoadd_recording_container(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_recording_container(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  RecordingSegments? sse_decode_opt_box_autoadd_recording_segments(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_recording_segments(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  RuntimeConfig? sse_decode_opt_box_autoadd_runtime_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_runtime_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SessionOptions? sse_decode_opt_box_autoadd_session_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_session_options(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  StallWatchdogConfig? sse_decode_opt_box_autoadd_stall_watchdog_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_stall_watchdog_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SwitchPlaceholder? sse_decode_opt_box_autoadd_switch_placeholder(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_switch_placeholder(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  TapFrame? sse_decode_opt_box_autoadd_tap_frame(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_tap_frame(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_16(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_8(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  OutputSize sse_decode_output_size(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_mode = sse_decode_scale_mode(deserializer);
+    return OutputSize(width: var_width, height: var_height, mode: var_mode);
+  }
+
+  @protected
+  PlaceholderFrame sse_decode_placeholder_frame(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return PlaceholderFrame_Black();
+      case 1:
+        return PlaceholderFrame_Transparent();
+      case 2:
+        var var_width = sse_decode_u_32(deserializer);
+        var var_height = sse_decode_u_32(deserializer);
+        var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+        return PlaceholderFrame_Rgba(
+          width: var_width,
+          height: var_height,
+          data: var_data,
+        );
+      case 3:
+        var var_uri = sse_decode_String(deserializer);
+        return PlaceholderFrame_Poster(uri: var_uri);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  PlaybinConfig sse_decode_playbin_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_uri = sse_decode_String(deserializer);
+    var var_mute = sse_decode_bool(deserializer);
+    var var_autoRestart = sse_decode_bool(deserializer);
+    var var_stallTimeoutMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_maxRestartAttempts = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return PlaybinConfig(
+      uri: var_uri,
+      mute: var_mute,
+      autoRestart: var_autoRestart,
+      stallTimeoutMs: var_stallTimeoutMs,
+      maxRestartAttempts: var_maxRestartAttempts,
+    );
+  }
+
+  @protected
+  PreEventBuffer sse_decode_pre_event_buffer(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_durationMs = sse_decode_u_64(deserializer);
+    var var_maxBytes = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return PreEventBuffer(durationMs: var_durationMs, maxBytes: var_maxBytes);
+  }
+
+  @protected
+  PresentationPolicy sse_decode_presentation_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_placeholder = sse_decode_placeholder_frame(deserializer);
+    var var_onStop = sse_decode_end_frame_policy(deserializer);
+    var var_onError = sse_decode_end_frame_policy(deserializer);
+    var var_reconnectOverlayArgb = sse_decode_opt_box_autoadd_u_32(
+      deserializer,
+    );
+    return PresentationPolicy(
+      placeholder: var_placeholder,
+      onStop: var_onStop,
+      onError: var_onError,
+      reconnectOverlayArgb: var_reconnectOverlayArgb,
+    );
+  }
+
+  @protected
+  RecordingContainer sse_decode_recording_container(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return RecordingContainer.values[inner];
+  }
+
+  @protected
+  RecordingInfo sse_decode_recording_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_files = sse_decode_list_String(deserializer);
+    var var_durationMs = sse_decode_u_64(deserializer);
+    var var_sizeBytes = sse_decode_u_64(deserializer);
+    return RecordingInfo(
+      files: var_files,
+      durationMs: var_durationMs,
+      sizeBytes: var_sizeBytes,
+    );
+  }
+
+  @protected
+  RecordingSegments sse_decode_recording_segments(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_durationMs = sse_decode_u_64(deserializer);
+    var var_maxFiles = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return RecordingSegments(
+      durationMs: var_durationMs,
+      maxFiles: var_maxFiles,
+    );
+  }
+
+  @protected
+  Rotation sse_decode_rotation(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return Rotation.values[inner];
+  }
+
+  @protected
+  RuntimeConfig sse_decode_runtime_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_workerThreads = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_threadName = sse_decode_opt_String(deserializer);
+    return RuntimeConfig(
+      workerThreads: var_workerThreads,
+      threadName: var_threadName,
+    );
+  }
+
+  @protected
+  ScaleMode sse_decode_scale_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ScaleMode.values[inner];
+  }
+
+  @protected
+  SessionOptions sse_decode_session_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_stallWatchdog = sse_decode_opt_box_autoadd_stall_watchdog_config(
+      deserializer,
+    );
+    var var_keepAlive = sse_decode_opt_box_autoadd_keep_alive_policy(
+      deserializer,
+    );
+    var var_awaitFirstFrameTimeoutMs = sse_decode_opt_box_autoadd_u_64(
+      deserializer,
+    );
+    var var_shareDecode = sse_decode_bool(deserializer);
+    var var_standby = sse_decode_bool(deserializer);
+    var var_frameOutput = sse_decode_frame_output_mode(deserializer);
+    var var_presentation = sse_decode_presentation_policy(deserializer);
+    var var_maxFrameRate = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_preEventBuffer = sse_decode_opt_box_autoadd_pre_event_buffer(
+      deserializer,
+    );
+    return SessionOptions(
+      stallWatchdog: var_stallWatchdog,
+      keepAlive: var_keepAlive,
+      awaitFirstFrameTimeoutMs: var_awaitFirstFrameTimeoutMs,
+      shareDecode: var_shareDecode,
+      standby: var_standby,
+      frameOutput: var_frameOutput,
+      presentation: var_presentation,
+      maxFrameRate: var_maxFrameRate,
+      preEventBuffer: var_preEventBuffer,
+    );
+  }
+
+  @protected
+  Snapshot sse_decode_snapshot(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_ptsMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_capturedAtMs = sse_decode_i_64(deserializer);
+    return Snapshot(
+      data: var_data,
+      width: var_width,
+      height: var_height,
+      ptsMs: var_ptsMs,
+      capturedAtMs: var_capturedAtMs,
+    );
+  }
+
+  @protected
+  SnapshotFormat sse_decode_snapshot_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SnapshotFormat.values[inner];
+  }
+
+  @protected
+  StallWatchdogConfig sse_decode_stall_watchdog_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_stallThresholdMs = sse_decode_u_64(deserializer);
+    var var_reconnectAfterMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return StallWatchdogConfig(
+      stallThresholdMs: var_stallThresholdMs,
+      reconnectAfterMs: var_reconnectAfterMs,
+    );
+  }
+
+  @protected
+  StartupReport sse_decode_startup_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_connectMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_handshakeMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_prerollMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_firstFrameMs = sse_decode_u_64(deserializer);
+    var var_attempts = sse_decode_u_32(deserializer);
+    return StartupReport(
+      connectMs: var_connectMs,
+      handshakeMs: var_handshakeMs,
+      prerollMs: var_prerollMs,
+      firstFrameMs: var_firstFrameMs,
+      attempts: var_attempts,
+    );
+  }
+
+  @protected
+  StreamError sse_decode_stream_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_category = sse_decode_error_category(deserializer);
+    var var_retryable = sse_decode_bool(deserializer);
+    var var_backend = sse_decode_error_backend(deserializer);
+    var var_cause = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return StreamError(
+      category: var_category,
+      retryable: var_retryable,
+      backend: var_backend,
+      cause: var_cause,
+      message: var_message,
+    );
+  }
+
+  @protected
+  StreamEvent sse_decode_stream_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_box_autoadd_stream_error(deserializer);
+        return StreamEvent_Error(var_field0);
+      case 1:
+        var var_field0 = sse_decode_i_64(deserializer);
+        return StreamEvent_CurrentTime(var_field0);
+      case 2:
+        var var_width = sse_decode_u_64(deserializer);
+        var var_height = sse_decode_u_64(deserializer);
+        var var_transformedWidth = sse_decode_u_64(deserializer);
+        var var_transformedHeight = sse_decode_u_64(deserializer);
+        var var_pixelAspectRatio = sse_decode_f_64(deserializer);
+        var var_displayAspectRatio = sse_decode_f_64(deserializer);
+        var var_frameRate = sse_decode_f_64(deserializer);
+        return StreamEvent_OriginVideoSize(
+          width: var_width,
+          height: var_height,
+          transformedWidth: var_transformedWidth,
+          transformedHeight: var_transformedHeight,
+          pixelAspectRatio: var_pixelAspectRatio,
+          displayAspectRatio: var_displayAspectRatio,
+          frameRate: var_frameRate,
+        );
+      case 3:
+        var var_field0 = sse_decode_box_autoadd_wsc_rtp_mode(deserializer);
+        return StreamEvent_WscRtpSessionMode(var_field0);
+      case 4:
+        var var_field0 = sse_decode_String(deserializer);
+        return StreamEvent_WscRtpStreamState(var_field0);
+      case 5:
+        var var_sinceLastFrameMs = sse_decode_u_64(deserializer);
+        return StreamEvent_Stalled(sinceLastFrameMs: var_sinceLastFrameMs);
+      case 6:
+        var var_stalledForMs = sse_decode_u_64(deserializer);
+        return StreamEvent_Resumed(stalledForMs: var_stalledForMs);
+      case 7:
+        var var_field0 = sse_decode_box_autoadd_startup_report(deserializer);
+        return StreamEvent_StartupReport(var_field0);
+      case 8:
+        var var_field0 = sse_decode_box_autoadd_stream_error(deserializer);
+        return StreamEvent_StartupFailed(var_field0);
+      case 9:
+        var var_attempt = sse_decode_u_32(deserializer);
+        var var_delayMs = sse_decode_u_64(deserializer);
+        return StreamEvent_Reconnecting(
+          attempt: var_attempt,
+          delayMs: var_delayMs,
+        );
+      case 10:
+        var var_path = sse_decode_String(deserializer);
+        return StreamEvent_RecordingStarted(path: var_path);
+      case 11:
+        var var_field0 = sse_decode_box_autoadd_recording_info(deserializer);
+        return StreamEvent_RecordingStopped(var_field0);
+      case 12:
+        var var_field0 = sse_decode_box_autoadd_stream_error(deserializer);
+        return StreamEvent_RecordingError(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  StreamMessage sse_decode_stream_message(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_box_autoadd_stream_state(deserializer);
+        return StreamMessage_State(var_field0);
+      case 1:
+        var var_field0 = sse_decode_box_autoadd_stream_event(deserializer);
+        return StreamMessage_Event(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  StreamState sse_decode_stream_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_box_autoadd_stream_error(deserializer);
+        return StreamState_Error(var_field0);
+      case 1:
+        return StreamState_Loading();
+      case 2:
+        var var_textureId = sse_decode_i_64(deserializer);
+        var var_seekable = sse_decode_bool(deserializer);
+        return StreamState_Playing(
+          textureId: var_textureId,
+          seekable: var_seekable,
+        );
+      case 3:
+        return StreamState_Stopped();
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  SwitchPlaceholder sse_decode_switch_placeholder(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SwitchPlaceholder.values[inner];
+  }
+
+  @protected
+  TapFrame sse_decode_tap_frame(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_format = sse_decode_tap_pixel_format(deserializer);
+    var var_planeStrides = sse_decode_list_prim_u_32_strict(deserializer);
+    var var_planeOffsets = sse_decode_list_prim_u_32_strict(deserializer);
+    var var_ptsMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_capturedAtMs = sse_decode_i_64(deserializer);
+    var var_sequence = sse_decode_u_64(deserializer);
+    var var_dropped = sse_decode_u_64(deserializer);
+    return TapFrame(
+      data: var_data,
+      width: var_width,
+      height: var_height,
+      format: var_format,
+      planeStrides: var_planeStrides,
+      planeOffsets: var_planeOffsets,
+      ptsMs: var_ptsMs,
+      capturedAtMs: var_capturedAtMs,
+      sequence: var_sequence,
+      dropped: var_dropped,
+    );
+  }
+
+  @protected
+  TapPixelFormat sse_decode_tap_pixel_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TapPixelFormat.values[inner];
+  }
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  VideoConfig sse_decode_video_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_box_autoadd_wsc_rtp_session_config(
+          deserializer,
+        );
+        return VideoConfig_WscRtp(var_field0);
+      case 1:
+        var var_field0 = sse_decode_box_autoadd_playbin_config(deserializer);
+        return VideoConfig_Playbin(var_field0);
+      case 2:
+        var var_kind = sse_decode_String(deserializer);
+        var var_jsonParams = sse_decode_String(deserializer);
+        return VideoConfig_Custom(kind: var_kind, jsonParams: var_jsonParams);
+      default:
+        throw UnimplementedError('');
+    }
   }
 
   @protected
-  PlaybinConfig dco_decode_box_autoadd_playbin_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_playbin_config(raw);
+  VideoTransform sse_decode_video_transform(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_rotation = sse_decode_rotation(deserializer);
+    var var_flipHorizontal = sse_decode_bool(deserializer);
+    var var_flipVertical = sse_decode_bool(deserializer);
+    var var_crop = sse_decode_opt_box_autoadd_crop_rect(deserializer);
+    var var_zoom = sse_decode_f_64(deserializer);
+    var var_panX = sse_decode_f_64(deserializer);
+    var var_panY = sse_decode_f_64(deserializer);
+    return VideoTransform(
+      rotation: var_rotation,
+      flipHorizontal: var_flipHorizontal,
+      flipVertical: var_flipVertical,
+      crop: var_crop,
+      zoom: var_zoom,
+      panX: var_panX,
+      panY: var_panY,
+    );
   }
 
   @protected
-  StreamEvent dco_decode_box_autoadd_stream_event(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_stream_event(raw);
+  WscRtpMode sse_decode_wsc_rtp_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return WscRtpMode_Live();
+      case 1:
+        var var_currentTimeMs = sse_decode_i_64(deserializer);
+        var var_speed = sse_decode_f_64(deserializer);
+        return WscRtpMode_Dvr(
+          currentTimeMs: var_currentTimeMs,
+          speed: var_speed,
+        );
+      default:
+        throw UnimplementedError('');
+    }
   }
 
   @protected
-  StreamState dco_decode_box_autoadd_stream_state(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_stream_state(raw);
+  WscRtpSessionConfig sse_decode_wsc_rtp_session_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_baseUrl = sse_decode_String(deserializer);
+    var var_sourceId = sse_decode_String(deserializer);
+    var var_clientPort = sse_decode_opt_box_autoadd_u_16(deserializer);
+    var var_forceWebsocketTransport = sse_decode_bool(deserializer);
+    var var_autoRestart = sse_decode_bool(deserializer);
+    return WscRtpSessionConfig(
+      baseUrl: var_baseUrl,
+      sourceId: var_sourceId,
+      clientPort: var_clientPort,
+      forceWebsocketTransport: var_forceWebsocketTransport,
+      autoRestart: var_autoRestart,
+    );
   }
 
   @protected
-  int dco_decode_box_autoadd_u_16(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
   }
 
   @protected
-  VideoConfig dco_decode_box_autoadd_video_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_video_config(raw);
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  WscRtpMode dco_decode_box_autoadd_wsc_rtp_mode(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_wsc_rtp_mode(raw);
+  void sse_encode_StreamSink_stream_message_Sse(
+    RustStreamSink<StreamMessage> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_stream_message,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  WscRtpSessionConfig dco_decode_box_autoadd_wsc_rtp_session_config(
-    dynamic raw,
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_crop_rect(
+    CropRect self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_wsc_rtp_session_config(raw);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_crop_rect(self, serializer);
   }
 
   @protected
-  double dco_decode_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  void sse_encode_box_autoadd_frame_tap_config(
+    FrameTapConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_frame_tap_config(self, serializer);
   }
 
   @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
+  void sse_encode_box_autoadd_image_adjustments(
+    ImageAdjustments self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_image_adjustments(self, serializer);
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void sse_encode_box_autoadd_keep_alive_policy(
+    KeepAlivePolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_keep_alive_policy(self, serializer);
   }
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_16(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_16(raw);
+  void sse_encode_box_autoadd_output_size(
+    OutputSize self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_output_size(self, serializer);
   }
 
   @protected
-  PlaybinConfig dco_decode_playbin_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return PlaybinConfig(
-      uri: dco_decode_String(arr[0]),
-      mute: dco_decode_bool(arr[1]),
-    );
+  void sse_encode_box_autoadd_playbin_config(
+    PlaybinConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_playbin_config(self, serializer);
   }
 
   @protected
-  StreamEvent dco_decode_stream_event(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return StreamEvent_Error(dco_decode_String(raw[1]));
-      case 1:
-        return StreamEvent_CurrentTime(dco_decode_i_64(raw[1]));
-      case 2:
-        return StreamEvent_OriginVideoSize(
-          width: dco_decode_u_64(raw[1]),
-          height: dco_decode_u_64(raw[2]),
-        );
-      case 3:
-        return StreamEvent_WscRtpSessionMode(
-          dco_decode_box_autoadd_wsc_rtp_mode(raw[1]),
-        );
-      case 4:
-        return StreamEvent_WscRtpStreamState(dco_decode_String(raw[1]));
-      default:
-        throw Exception("unreachable");
-    }
+  void sse_encode_box_autoadd_pre_event_buffer(
+    PreEventBuffer self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_pre_event_buffer(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_presentation_policy(
+    PresentationPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_presentation_policy(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_recording_container(
+    RecordingContainer self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_recording_container(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_recording_info(
+    RecordingInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_recording_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_recording_segments(
+    RecordingSegments self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_recording_segments(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_runtime_config(
+    RuntimeConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_runtime_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_session_options(
+    SessionOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_session_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_stall_watchdog_config(
+    StallWatchdogConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_stall_watchdog_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_startup_report(
+    StartupReport self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_startup_report(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_stream_error(
+    StreamError self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_stream_error(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_stream_event(
+    StreamEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_stream_event(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_stream_state(
+    StreamState self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_stream_state(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_switch_placeholder(
+    SwitchPlaceholder self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_switch_placeholder(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_tap_frame(
+    TapFrame self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_tap_frame(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_16(self, serializer);
   }
 
   @protected
-  StreamMessage dco_decode_stream_message(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return StreamMessage_State(dco_decode_box_autoadd_stream_state(raw[1]));
-      case 1:
-        return StreamMessage_Event(dco_decode_box_autoadd_stream_event(raw[1]));
-      default:
-        throw Exception("unreachable");
-    }
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
   }
 
   @protected
-  StreamState dco_decode_stream_state(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return StreamState_Error(dco_decode_String(raw[1]));
-      case 1:
-        return StreamState_Loading();
-      case 2:
-        return StreamState_Playing(
-          textureId: dco_decode_i_64(raw[1]),
-          seekable: dco_decode_bool(raw[2]),
-        );
-      case 3:
-        return StreamState_Stopped();
-      default:
-        throw Exception("unreachable");
-    }
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
   }
 
   @protected
-  int dco_decode_u_16(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_8(self, serializer);
   }
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_box_autoadd_video_config(
+    VideoConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_video_config(self, serializer);
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_box_autoadd_video_transform(
+    VideoTransform self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_video_transform(self, serializer);
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_box_autoadd_wsc_rtp_mode(
+    WscRtpMode self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_wsc_rtp_mode(self, serializer);
   }
 
   @protected
-  VideoConfig dco_decode_video_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return VideoConfig_WscRtp(
-          dco_decode_box_autoadd_wsc_rtp_session_config(raw[1]),
-        );
-      case 1:
-        return VideoConfig_Playbin(
-          dco_decode_box_autoadd_playbin_config(raw[1]),
-        );
-      default:
-        throw Exception("unreachable");
-    }
+  void sse_encode_box_autoadd_wsc_rtp_session_config(
+    WscRtpSessionConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_wsc_rtp_session_config(self, serializer);
   }
 
   @protected
-  WscRtpMode dco_decode_wsc_rtp_mode(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return WscRtpMode_Live();
-      case 1:
-        return WscRtpMode_Dvr(
-          currentTimeMs: dco_decode_i_64(raw[1]),
-          speed: dco_decode_f_64(raw[2]),
-        );
-      default:
-        throw Exception("unreachable");
-    }
+  void sse_encode_crop_rect(CropRect self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self.x, serializer);
+    sse_encode_f_64(self.y, serializer);
+    sse_encode_f_64(self.width, serializer);
+    sse_encode_f_64(self.height, serializer);
   }
 
   @protected
-  WscRtpSessionConfig dco_decode_wsc_rtp_session_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return WscRtpSessionConfig(
-      baseUrl: dco_decode_String(arr[0]),
-      sourceId: dco_decode_String(arr[1]),
-      clientPort: dco_decode_opt_box_autoadd_u_16(arr[2]),
-      forceWebsocketTransport: dco_decode_bool(arr[3]),
-      autoRestart: dco_decode_bool(arr[4]),
-    );
+  void sse_encode_deinterlace_method(
+    DeinterlaceMethod self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_deinterlace_mode(
+    DeinterlaceMode self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  RustStreamSink<StreamMessage> sse_decode_StreamSink_stream_message_Sse(
-    SseDeserializer deserializer,
+  void sse_encode_end_frame_policy(
+    EndFramePolicy self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
+    switch (self) {
+      case EndFramePolicy_HoldLastFrame():
+        sse_encode_i_32(0, serializer);
+      case EndFramePolicy_Dim(brightnessPercent: final brightnessPercent):
+        sse_encode_i_32(1, serializer);
+        sse_encode_u_8(brightnessPercent, serializer);
+      case EndFramePolicy_Clear():
+        sse_encode_i_32(2, serializer);
+    }
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_error_backend(ErrorBackend self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    switch (self) {
+      case ErrorBackend_WscRtp():
+        sse_encode_i_32(0, serializer);
+      case ErrorBackend_Playbin():
+        sse_encode_i_32(1, serializer);
+      case ErrorBackend_Registry():
+        sse_encode_i_32(2, serializer);
+      case ErrorBackend_Recorder():
+        sse_encode_i_32(3, serializer);
+      case ErrorBackend_Custom(field0: final field0):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(field0, serializer);
+    }
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_error_category(ErrorCategory self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  PlaybinConfig sse_decode_box_autoadd_playbin_config(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_playbin_config(deserializer));
+    serializer.buffer.putFloat64(self);
   }
 
   @protected
-  StreamEvent sse_decode_box_autoadd_stream_event(
-    SseDeserializer deserializer,
+  void sse_encode_frame_output_mode(
+    FrameOutputMode self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_stream_event(deserializer));
+    switch (self) {
+      case FrameOutputMode_FlutterTexture():
+        sse_encode_i_32(0, serializer);
+      case FrameOutputMode_Headless(historyLen: final historyLen):
+        sse_encode_i_32(1, serializer);
+        sse_encode_u_32(historyLen, serializer);
+    }
   }
 
   @protected
-  StreamState sse_decode_box_autoadd_stream_state(
-    SseDeserializer deserializer,
+  void sse_encode_frame_tap_config(
+    FrameTapConfig self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_stream_state(deserializer));
+    sse_encode_opt_box_autoadd_u_32(self.maxFrameRate, serializer);
+    sse_encode_opt_box_autoadd_output_size(self.size, serializer);
+    sse_encode_tap_pixel_format(self.format, serializer);
+    sse_encode_u_32(self.queueLen, serializer);
   }
 
   @protected
-  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer) {
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_16(deserializer));
+    serializer.buffer.putPlatformInt64(self);
   }
 
   @protected
-  VideoConfig sse_decode_box_autoadd_video_config(
-    SseDeserializer deserializer,
+  void sse_encode_image_adjustments(
+    ImageAdjustments self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_video_config(deserializer));
+    sse_encode_deinterlace_mode(self.deinterlace, serializer);
+    sse_encode_deinterlace_method(self.deinterlaceMethod, serializer);
+    sse_encode_f_64(self.brightness, serializer);
+    sse_encode_f_64(self.contrast, serializer);
+    sse_encode_f_64(self.saturation, serializer);
+    sse_encode_f_64(self.hue, serializer);
+    sse_encode_f_64(self.gamma, serializer);
   }
 
   @protected
-  WscRtpMode sse_decode_box_autoadd_wsc_rtp_mode(SseDeserializer deserializer) {
+  void sse_encode_keep_alive_policy(
+    KeepAlivePolicy self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_wsc_rtp_mode(deserializer));
+    switch (self) {
+      case KeepAlivePolicy_Timeout(timeoutMs: final timeoutMs):
+        sse_encode_i_32(0, serializer);
+        sse_encode_u_64(timeoutMs, serializer);
+      case KeepAlivePolicy_Disabled():
+        sse_encode_i_32(1, serializer);
+    }
   }
 
   @protected
-  WscRtpSessionConfig sse_decode_box_autoadd_wsc_rtp_session_config(
-    SseDeserializer deserializer,
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_wsc_rtp_session_config(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint32List(self);
   }
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_crop_rect(
+    CropRect? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_crop_rect(self, serializer);
+    }
   }
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_frame_tap_config(
+    FrameTapConfig? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_16(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_frame_tap_config(self, serializer);
     }
   }
 
   @protected
-  PlaybinConfig sse_decode_playbin_config(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_keep_alive_policy(
+    KeepAlivePolicy? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_uri = sse_decode_String(deserializer);
-    var var_mute = sse_decode_bool(deserializer);
-    return PlaybinConfig(uri: var_uri, mute: var_mute);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_keep_alive_policy(self, serializer);
+    }
   }
 
   @protected
-  StreamEvent sse_decode_stream_event(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_output_size(
+    OutputSize? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_field0 = sse_decode_String(deserializer);
-        return StreamEvent_Error(var_field0);
-      case 1:
-        var var_field0 = sse_decode_i_64(deserializer);
-        return StreamEvent_CurrentTime(var_field0);
-      case 2:
-        var var_width = sse_decode_u_64(deserializer);
-        var var_height = sse_decode_u_64(deserializer);
-        return StreamEvent_OriginVideoSize(
-          width: var_width,
-          height: var_height,
-        );
-      case 3:
-        var var_field0 = sse_decode_box_autoadd_wsc_rtp_mode(deserializer);
-        return StreamEvent_WscRtpSessionMode(var_field0);
-      case 4:
-        var var_field0 = sse_decode_String(deserializer);
-        return StreamEvent_WscRtpStreamState(var_field0);
-      default:
-        throw UnimplementedError('');
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_output_size(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_pre_event_buffer(
+    PreEventBuffer? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_pre_event_buffer(self, serializer);
     }
   }
 
   @protected
-  StreamMessage sse_decode_stream_message(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_recording_container(
+    RecordingContainer? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_field0 = sse_decode_box_autoadd_stream_state(deserializer);
-        return StreamMessage_State(var_field0);
-      case 1:
-        var var_field0 = sse_decode_box_autoadd_stream_event(deserializer);
-        return StreamMessage_Event(var_field0);
-      default:
-        throw UnimplementedError('');
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_recording_container(self, serializer);
     }
   }
 
   @protected
-  StreamState sse_decode_stream_state(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_recording_segments(
+    RecordingSegments? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_field0 = sse_decode_String(deserializer);
-        return StreamState_Error(var_field0);
-      case 1:
-        return StreamState_Loading();
-      case 2:
-        var var_textureId = sse_decode_i_64(deserializer);
-        var var_seekable = sse_decode_bool(deserializer);
-        return StreamState_Playing(
-          textureId: var_textureId,
-          seekable: var_seekable,
-        );
-      case 3:
-        return StreamState_Stopped();
-      default:
-        throw UnimplementedError('');
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_recording_segments(self, serializer);
     }
   }
 
   @protected
-  int sse_decode_u_16(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_runtime_config(
+    RuntimeConfig? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint16();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_runtime_config(self, serializer);
+    }
   }
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_session_options(
+    SessionOptions? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_session_options(self, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_stall_watchdog_config(
+    StallWatchdogConfig? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_stall_watchdog_config(self, serializer);
+    }
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_switch_placeholder(
+    SwitchPlaceholder? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_switch_placeholder(self, serializer);
+    }
   }
 
   @protected
-  VideoConfig sse_decode_video_config(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_tap_frame(
+    TapFrame? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_field0 = sse_decode_box_autoadd_wsc_rtp_session_config(
-          deserializer,
-        );
-        return VideoConfig_WscRtp(var_field0);
-      case 1:
-        var var_field0 = sse_decode_box_autoadd_playbin_config(deserializer);
-        return VideoConfig_Playbin(var_field0);
-      default:
-        throw UnimplementedError('');
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_tap_frame(self, serializer);
     }
   }
 
   @protected
-  WscRtpMode sse_decode_wsc_rtp_mode(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        return WscRtpMode_Live();
-      case 1:
-        var var_currentTimeMs = sse_decode_i_64(deserializer);
-        var var_speed = sse_decode_f_64(deserializer);
-        return WscRtpMode_Dvr(
-          currentTimeMs: var_currentTimeMs,
-          speed: var_speed,
-        );
-      default:
-        throw UnimplementedError('');
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_16(self, serializer);
     }
   }
 
   @protected
-  WscRtpSessionConfig sse_decode_wsc_rtp_session_config(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_baseUrl = sse_decode_String(deserializer);
-    var var_sourceId = sse_decode_String(deserializer);
-    var var_clientPort = sse_decode_opt_box_autoadd_u_16(deserializer);
-    var var_forceWebsocketTransport = sse_decode_bool(deserializer);
-    var var_autoRestart = sse_decode_bool(deserializer);
-    return WscRtpSessionConfig(
-      baseUrl: var_baseUrl,
-      sourceId: var_sourceId,
-      clientPort: var_clientPort,
-      forceWebsocketTransport: var_forceWebsocketTransport,
-      autoRestart: var_autoRestart,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_8(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_StreamSink_stream_message_Sse(
-    RustStreamSink<StreamMessage> self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_output_size(OutputSize self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-      self.setupAndSerialize(
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_stream_message,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-      ),
-      serializer,
-    );
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_scale_mode(self.mode, serializer);
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_placeholder_frame(
+    PlaceholderFrame self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    switch (self) {
+      case PlaceholderFrame_Black():
+        sse_encode_i_32(0, serializer);
+      case PlaceholderFrame_Transparent():
+        sse_encode_i_32(1, serializer);
+      case PlaceholderFrame_Rgba(
+        width: final width,
+        height: final height,
+        data: final data,
+      ):
+        sse_encode_i_32(2, serializer);
+        sse_encode_u_32(width, serializer);
+        sse_encode_u_32(height, serializer);
+        sse_encode_list_prim_u_8_strict(data, serializer);
+      case PlaceholderFrame_Poster(uri: final uri):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(uri, serializer);
+    }
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_playbin_config(PlaybinConfig self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_String(self.uri, serializer);
+    sse_encode_bool(self.mute, serializer);
+    sse_encode_bool(self.autoRestart, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.stallTimeoutMs, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.maxRestartAttempts, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_playbin_config(
-    PlaybinConfig self,
+  void sse_encode_pre_event_buffer(
+    PreEventBuffer self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_playbin_config(self, serializer);
+    sse_encode_u_64(self.durationMs, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.maxBytes, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_stream_event(
-    StreamEvent self,
+  void sse_encode_presentation_policy(
+    PresentationPolicy self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_stream_event(self, serializer);
+    sse_encode_placeholder_frame(self.placeholder, serializer);
+    sse_encode_end_frame_policy(self.onStop, serializer);
+    sse_encode_end_frame_policy(self.onError, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.reconnectOverlayArgb, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_stream_state(
-    StreamState self,
+  void sse_encode_recording_container(
+    RecordingContainer self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_stream_state(self, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer) {
+  void sse_encode_recording_info(RecordingInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_16(self, serializer);
+    sse_encode_list_String(self.files, serializer);
+    sse_encode_u_64(self.durationMs, serializer);
+    sse_encode_u_64(self.sizeBytes, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_video_config(
-    VideoConfig self,
+  void sse_encode_recording_segments(
+    RecordingSegments self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_video_config(self, serializer);
+    sse_encode_u_64(self.durationMs, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.maxFiles, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_wsc_rtp_mode(
-    WscRtpMode self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_rotation(Rotation self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_wsc_rtp_mode(self, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_wsc_rtp_session_config(
-    WscRtpSessionConfig self,
+  void sse_encode_runtime_config(RuntimeConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_u_32(self.workerThreads, serializer);
+    sse_encode_opt_String(self.threadName, serializer);
+  }
+
+  @protected
+  void sse_encode_scale_mode(ScaleMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_session_options(
+    SessionOptions self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_wsc_rtp_session_config(self, serializer);
+    sse_encode_opt_box_autoadd_stall_watchdog_config(
+      self.stallWatchdog,
+      serializer,
+    );
+    sse_encode_opt_box_autoadd_keep_alive_policy(self.keepAlive, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.awaitFirstFrameTimeoutMs, serializer);
+    sse_encode_bool(self.shareDecode, serializer);
+    sse_encode_bool(self.standby, serializer);
+    sse_encode_frame_output_mode(self.frameOutput, serializer);
+    sse_encode_presentation_policy(self.presentation, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.maxFrameRate, serializer);
+    sse_encode_opt_box_autoadd_pre_event_buffer(
+      self.preEventBuffer,
+      serializer,
+    );
   }
 
   @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
+  void sse_encode_snapshot(Snapshot self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
+    sse_encode_list_prim_u_8_strict(self.data, serializer);
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.ptsMs, serializer);
+    sse_encode_i_64(self.capturedAtMs, serializer);
   }
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+  void sse_encode_snapshot_format(
+    SnapshotFormat self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_stall_watchdog_config(
+    StallWatchdogConfig self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sse_encode_u_64(self.stallThresholdMs, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.reconnectAfterMs, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer) {
+  void sse_encode_startup_report(StartupReport self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_16(self, serializer);
-    }
+    sse_encode_opt_box_autoadd_u_64(self.connectMs, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.handshakeMs, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.prerollMs, serializer);
+    sse_encode_u_64(self.firstFrameMs, serializer);
+    sse_encode_u_32(self.attempts, serializer);
   }
 
   @protected
-  void sse_encode_playbin_config(PlaybinConfig self, SseSerializer serializer) {
+  void sse_encode_stream_error(StreamError self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.uri, serializer);
-    sse_encode_bool(self.mute, serializer);
+    sse_encode_error_category(self.category, serializer);
+    sse_encode_bool(self.retryable, serializer);
+    sse_encode_error_backend(self.backend, serializer);
+    sse_encode_opt_String(self.cause, serializer);
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
@@ -1108,23 +4171,61 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     switch (self) {
       case StreamEvent_Error(field0: final field0):
         sse_encode_i_32(0, serializer);
-        sse_encode_String(field0, serializer);
+        sse_encode_box_autoadd_stream_error(field0, serializer);
       case StreamEvent_CurrentTime(field0: final field0):
         sse_encode_i_32(1, serializer);
         sse_encode_i_64(field0, serializer);
       case StreamEvent_OriginVideoSize(
         width: final width,
         height: final height,
+        transformedWidth: final transformedWidth,
+        transformedHeight: final transformedHeight,
+        pixelAspectRatio: final pixelAspectRatio,
+        displayAspectRatio: final displayAspectRatio,
+        frameRate: final frameRate,
       ):
         sse_encode_i_32(2, serializer);
         sse_encode_u_64(width, serializer);
         sse_encode_u_64(height, serializer);
+        sse_encode_u_64(transformedWidth, serializer);
+        sse_encode_u_64(transformedHeight, serializer);
+        sse_encode_f_64(pixelAspectRatio, serializer);
+        sse_encode_f_64(displayAspectRatio, serializer);
+        sse_encode_f_64(frameRate, serializer);
       case StreamEvent_WscRtpSessionMode(field0: final field0):
         sse_encode_i_32(3, serializer);
         sse_encode_box_autoadd_wsc_rtp_mode(field0, serializer);
       case StreamEvent_WscRtpStreamState(field0: final field0):
         sse_encode_i_32(4, serializer);
         sse_encode_String(field0, serializer);
+      case StreamEvent_Stalled(sinceLastFrameMs: final sinceLastFrameMs):
+        sse_encode_i_32(5, serializer);
+        sse_encode_u_64(sinceLastFrameMs, serializer);
+      case StreamEvent_Resumed(stalledForMs: final stalledForMs):
+        sse_encode_i_32(6, serializer);
+        sse_encode_u_64(stalledForMs, serializer);
+      case StreamEvent_StartupReport(field0: final field0):
+        sse_encode_i_32(7, serializer);
+        sse_encode_box_autoadd_startup_report(field0, serializer);
+      case StreamEvent_StartupFailed(field0: final field0):
+        sse_encode_i_32(8, serializer);
+        sse_encode_box_autoadd_stream_error(field0, serializer);
+      case StreamEvent_Reconnecting(
+        attempt: final attempt,
+        delayMs: final delayMs,
+      ):
+        sse_encode_i_32(9, serializer);
+        sse_encode_u_32(attempt, serializer);
+        sse_encode_u_64(delayMs, serializer);
+      case StreamEvent_RecordingStarted(path: final path):
+        sse_encode_i_32(10, serializer);
+        sse_encode_String(path, serializer);
+      case StreamEvent_RecordingStopped(field0: final field0):
+        sse_encode_i_32(11, serializer);
+        sse_encode_box_autoadd_recording_info(field0, serializer);
+      case StreamEvent_RecordingError(field0: final field0):
+        sse_encode_i_32(12, serializer);
+        sse_encode_box_autoadd_stream_error(field0, serializer);
     }
   }
 
@@ -1147,7 +4248,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     switch (self) {
       case StreamState_Error(field0: final field0):
         sse_encode_i_32(0, serializer);
-        sse_encode_String(field0, serializer);
+        sse_encode_box_autoadd_stream_error(field0, serializer);
       case StreamState_Loading():
         sse_encode_i_32(1, serializer);
       case StreamState_Playing(
@@ -1162,12 +4263,51 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_switch_placeholder(
+    SwitchPlaceholder self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_tap_frame(TapFrame self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.data, serializer);
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_tap_pixel_format(self.format, serializer);
+    sse_encode_list_prim_u_32_strict(self.planeStrides, serializer);
+    sse_encode_list_prim_u_32_strict(self.planeOffsets, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.ptsMs, serializer);
+    sse_encode_i_64(self.capturedAtMs, serializer);
+    sse_encode_u_64(self.sequence, serializer);
+    sse_encode_u_64(self.dropped, serializer);
+  }
+
+  @protected
+  void sse_encode_tap_pixel_format(
+    TapPixelFormat self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_u_16(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putUint16(self);
   }
 
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint32(self);
+  }
+
   @protected
   void sse_encode_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1195,9 +4335,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case VideoConfig_Playbin(field0: final field0):
         sse_encode_i_32(1, serializer);
         sse_encode_box_autoadd_playbin_config(field0, serializer);
+      case VideoConfig_Custom(kind: final kind, jsonParams: final jsonParams):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(kind, serializer);
+        sse_encode_String(jsonParams, serializer);
     }
   }
 
+  @protected
+  void sse_encode_video_transform(
+    VideoTransform self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_rotation(self.rotation, serializer);
+    sse_encode_bool(self.flipHorizontal, serializer);
+    sse_encode_bool(self.flipVertical, serializer);
+    sse_encode_opt_box_autoadd_crop_rect(self.crop, serializer);
+    sse_encode_f_64(self.zoom, serializer);
+    sse_encode_f_64(self.panX, serializer);
+    sse_encode_f_64(self.panY, serializer);
+  }
+
   @protected
   void sse_encode_wsc_rtp_mode(WscRtpMode self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
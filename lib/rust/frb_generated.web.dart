@@ -36,238 +36,1049 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  CropRect dco_decode_box_autoadd_crop_rect(dynamic raw);
+
+  @protected
+  FrameTapConfig dco_decode_box_autoadd_frame_tap_config(dynamic raw);
+
+  @protected
+  ImageAdjustments dco_decode_box_autoadd_image_adjustments(dynamic raw);
+
+  @protected
+  KeepAlivePolicy dco_decode_box_autoadd_keep_alive_policy(dynamic raw);
+
+  @protected
+  OutputSize dco_decode_box_autoadd_output_size(dynamic raw);
+
   @protected
   PlaybinConfig dco_decode_box_autoadd_playbin_config(dynamic raw);
 
   @protected
-  StreamEvent dco_decode_box_autoadd_stream_event(dynamic raw);
+  PreEventBuffer dco_decode_box_autoadd_pre_event_buffer(dynamic raw);
+
+  @protected
+  PresentationPolicy dco_decode_box_autoadd_presentation_policy(dynamic raw);
+
+  @protected
+  RecordingContainer dco_decode_box_autoadd_recording_container(dynamic raw);
+
+  @protected
+  RecordingInfo dco_decode_box_autoadd_recording_info(dynamic raw);
+
+  @protected
+  RecordingSegments dco_decode_box_autoadd_recording_segments(dynamic raw);
+
+  @protected
+  RuntimeConfig dco_decode_box_autoadd_runtime_config(dynamic raw);
+
+  @protected
+  SessionOptions dco_decode_box_autoadd_session_options(dynamic raw);
+
+  @protected
+  StallWatchdogConfig dco_decode_box_autoadd_stall_watchdog_config(dynamic raw);
+
+  @protected
+  StartupReport dco_decode_box_autoadd_startup_report(dynamic raw);
+
+  @protected
+  StreamError dco_decode_box_autoadd_stream_error(dynamic raw);
+
+  @protected
+  StreamEvent dco_decode_box_autoadd_stream_event(dynamic raw);
+
+  @protected
+  StreamState dco_decode_box_autoadd_stream_state(dynamic raw);
+
+  @protected
+  SwitchPlaceholder dco_decode_box_autoadd_switch_placeholder(dynamic raw);
+
+  @protected
+  TapFrame dco_decode_box_autoadd_tap_frame(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_16(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_8(dynamic raw);
+
+  @protected
+  VideoConfig dco_decode_box_autoadd_video_config(dynamic raw);
+
+  @protected
+  VideoTransform dco_decode_box_autoadd_video_transform(dynamic raw);
+
+  @protected
+  WscRtpMode dco_decode_box_autoadd_wsc_rtp_mode(dynamic raw);
+
+  @protected
+  WscRtpSessionConfig dco_decode_box_autoadd_wsc_rtp_session_config(
+    dynamic raw,
+  );
+
+  @protected
+  CropRect dco_decode_crop_rect(dynamic raw);
+
+  @protected
+  DeinterlaceMethod dco_decode_deinterlace_method(dynamic raw);
+
+  @protected
+  DeinterlaceMode dco_decode_deinterlace_mode(dynamic raw);
+
+  @protected
+  EndFramePolicy dco_decode_end_frame_policy(dynamic raw);
+
+  @protected
+  ErrorBackend dco_decode_error_backend(dynamic raw);
+
+  @protected
+  ErrorCategory dco_decode_error_category(dynamic raw);
+
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
+  @protected
+  FrameOutputMode dco_decode_frame_output_mode(dynamic raw);
+
+  @protected
+  FrameTapConfig dco_decode_frame_tap_config(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw);
+
+  @protected
+  ImageAdjustments dco_decode_image_adjustments(dynamic raw);
+
+  @protected
+  KeepAlivePolicy dco_decode_keep_alive_policy(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  CropRect? dco_decode_opt_box_autoadd_crop_rect(dynamic raw);
+
+  @protected
+  FrameTapConfig? dco_decode_opt_box_autoadd_frame_tap_config(dynamic raw);
+
+  @protected
+  KeepAlivePolicy? dco_decode_opt_box_autoadd_keep_alive_policy(dynamic raw);
+
+  @protected
+  OutputSize? dco_decode_opt_box_autoadd_output_size(dynamic raw);
+
+  @protected
+  PreEventBuffer? dco_decode_opt_box_autoadd_pre_event_buffer(dynamic raw);
+
+  @protected
+  RecordingContainer? dco_decode_opt_box_autoadd_recording_container(
+    dynamic raw,
+  );
+
+  @protected
+  RecordingSegments? dco_decode_opt_box_autoadd_recording_segments(dynamic raw);
+
+  @protected
+  RuntimeConfig? dco_decode_opt_box_autoadd_runtime_config(dynamic raw);
+
+  @protected
+  SessionOptions? dco_decode_opt_box_autoadd_session_options(dynamic raw);
+
+  @protected
+  StallWatchdogConfig? dco_decode_opt_box_autoadd_stall_watchdog_config(
+    dynamic raw,
+  );
+
+  @protected
+  SwitchPlaceholder? dco_decode_opt_box_autoadd_switch_placeholder(dynamic raw);
+
+  @protected
+  TapFrame? dco_decode_opt_box_autoadd_tap_frame(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_8(dynamic raw);
+
+  @protected
+  OutputSize dco_decode_output_size(dynamic raw);
+
+  @protected
+  PlaceholderFrame dco_decode_placeholder_frame(dynamic raw);
+
+  @protected
+  PlaybinConfig dco_decode_playbin_config(dynamic raw);
+
+  @protected
+  PreEventBuffer dco_decode_pre_event_buffer(dynamic raw);
+
+  @protected
+  PresentationPolicy dco_decode_presentation_policy(dynamic raw);
+
+  @protected
+  RecordingContainer dco_decode_recording_container(dynamic raw);
+
+  @protected
+  RecordingInfo dco_decode_recording_info(dynamic raw);
+
+  @protected
+  RecordingSegments dco_decode_recording_segments(dynamic raw);
+
+  @protected
+  Rotation dco_decode_rotation(dynamic raw);
+
+  @protected
+  RuntimeConfig dco_decode_runtime_config(dynamic raw);
+
+  @protected
+  ScaleMode dco_decode_scale_mode(dynamic raw);
+
+  @protected
+  SessionOptions dco_decode_session_options(dynamic raw);
+
+  @protected
+  Snapshot dco_decode_snapshot(dynamic raw);
+
+  @protected
+  SnapshotFormat dco_decode_snapshot_format(dynamic raw);
+
+  @protected
+  StallWatchdogConfig dco_decode_stall_watchdog_config(dynamic raw);
+
+  @protected
+  StartupReport dco_decode_startup_report(dynamic raw);
+
+  @protected
+  StreamError dco_decode_stream_error(dynamic raw);
+
+  @protected
+  StreamEvent dco_decode_stream_event(dynamic raw);
+
+  @protected
+  StreamMessage dco_decode_stream_message(dynamic raw);
+
+  @protected
+  StreamState dco_decode_stream_state(dynamic raw);
+
+  @protected
+  SwitchPlaceholder dco_decode_switch_placeholder(dynamic raw);
+
+  @protected
+  TapFrame dco_decode_tap_frame(dynamic raw);
+
+  @protected
+  TapPixelFormat dco_decode_tap_pixel_format(dynamic raw);
+
+  @protected
+  int dco_decode_u_16(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  VideoConfig dco_decode_video_config(dynamic raw);
+
+  @protected
+  VideoTransform dco_decode_video_transform(dynamic raw);
+
+  @protected
+  WscRtpMode dco_decode_wsc_rtp_mode(dynamic raw);
+
+  @protected
+  WscRtpSessionConfig dco_decode_wsc_rtp_session_config(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<StreamMessage> sse_decode_StreamSink_stream_message_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  CropRect sse_decode_box_autoadd_crop_rect(SseDeserializer deserializer);
+
+  @protected
+  FrameTapConfig sse_decode_box_autoadd_frame_tap_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ImageAdjustments sse_decode_box_autoadd_image_adjustments(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  KeepAlivePolicy sse_decode_box_autoadd_keep_alive_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  OutputSize sse_decode_box_autoadd_output_size(SseDeserializer deserializer);
+
+  @protected
+  PlaybinConfig sse_decode_box_autoadd_playbin_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PreEventBuffer sse_decode_box_autoadd_pre_event_buffer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PresentationPolicy sse_decode_box_autoadd_presentation_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RecordingContainer sse_decode_box_autoadd_recording_container(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RecordingInfo sse_decode_box_autoadd_recording_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RecordingSegments sse_decode_box_autoadd_recording_segments(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RuntimeConfig sse_decode_box_autoadd_runtime_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SessionOptions sse_decode_box_autoadd_session_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  StallWatchdogConfig sse_decode_box_autoadd_stall_watchdog_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  StartupReport sse_decode_box_autoadd_startup_report(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  StreamError sse_decode_box_autoadd_stream_error(SseDeserializer deserializer);
+
+  @protected
+  StreamEvent sse_decode_box_autoadd_stream_event(SseDeserializer deserializer);
+
+  @protected
+  StreamState sse_decode_box_autoadd_stream_state(SseDeserializer deserializer);
+
+  @protected
+  SwitchPlaceholder sse_decode_box_autoadd_switch_placeholder(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TapFrame sse_decode_box_autoadd_tap_frame(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_8(SseDeserializer deserializer);
+
+  @protected
+  VideoConfig sse_decode_box_autoadd_video_config(SseDeserializer deserializer);
+
+  @protected
+  VideoTransform sse_decode_box_autoadd_video_transform(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WscRtpMode sse_decode_box_autoadd_wsc_rtp_mode(SseDeserializer deserializer);
+
+  @protected
+  WscRtpSessionConfig sse_decode_box_autoadd_wsc_rtp_session_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CropRect sse_decode_crop_rect(SseDeserializer deserializer);
+
+  @protected
+  DeinterlaceMethod sse_decode_deinterlace_method(SseDeserializer deserializer);
+
+  @protected
+  DeinterlaceMode sse_decode_deinterlace_mode(SseDeserializer deserializer);
+
+  @protected
+  EndFramePolicy sse_decode_end_frame_policy(SseDeserializer deserializer);
+
+  @protected
+  ErrorBackend sse_decode_error_backend(SseDeserializer deserializer);
+
+  @protected
+  ErrorCategory sse_decode_error_category(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  FrameOutputMode sse_decode_frame_output_mode(SseDeserializer deserializer);
+
+  @protected
+  FrameTapConfig sse_decode_frame_tap_config(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  ImageAdjustments sse_decode_image_adjustments(SseDeserializer deserializer);
+
+  @protected
+  KeepAlivePolicy sse_decode_keep_alive_policy(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  CropRect? sse_decode_opt_box_autoadd_crop_rect(SseDeserializer deserializer);
+
+  @protected
+  FrameTapConfig? sse_decode_opt_box_autoadd_frame_tap_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  KeepAlivePolicy? sse_decode_opt_box_autoadd_keep_alive_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  OutputSize? sse_decode_opt_box_autoadd_output_size(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PreEventBuffer? sse_decode_opt_box_autoadd_pre_event_buffer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RecordingContainer? sse_decode_opt_box_autoadd_recording_container(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RecordingSegments? sse_decode_opt_box_autoadd_recording_segments(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RuntimeConfig? sse_decode_opt_box_autoadd_runtime_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SessionOptions? sse_decode_opt_box_autoadd_session_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  StallWatchdogConfig? sse_decode_opt_box_autoadd_stall_watchdog_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SwitchPlaceholder? sse_decode_opt_box_autoadd_switch_placeholder(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TapFrame? sse_decode_opt_box_autoadd_tap_frame(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer);
+
+  @protected
+  OutputSize sse_decode_output_size(SseDeserializer deserializer);
+
+  @protected
+  PlaceholderFrame sse_decode_placeholder_frame(SseDeserializer deserializer);
+
+  @protected
+  PlaybinConfig sse_decode_playbin_config(SseDeserializer deserializer);
+
+  @protected
+  PreEventBuffer sse_decode_pre_event_buffer(SseDeserializer deserializer);
+
+  @protected
+  PresentationPolicy sse_decode_presentation_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RecordingContainer sse_decode_recording_container(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RecordingInfo sse_decode_recording_info(SseDeserializer deserializer);
+
+  @protected
+  RecordingSegments sse_decode_recording_segments(SseDeserializer deserializer);
+
+  @protected
+  Rotation sse_decode_rotation(SseDeserializer deserializer);
+
+  @protected
+  RuntimeConfig sse_decode_runtime_config(SseDeserializer deserializer);
+
+  @protected
+  ScaleMode sse_decode_scale_mode(SseDeserializer deserializer);
+
+  @protected
+  SessionOptions sse_decode_session_options(SseDeserializer deserializer);
+
+  @protected
+  Snapshot sse_decode_snapshot(SseDeserializer deserializer);
+
+  @protected
+  SnapshotFormat sse_decode_snapshot_format(SseDeserializer deserializer);
+
+  @protected
+  StallWatchdogConfig sse_decode_stall_watchdog_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  StartupReport sse_decode_startup_report(SseDeserializer deserializer);
+
+  @protected
+  StreamError sse_decode_stream_error(SseDeserializer deserializer);
+
+  @protected
+  StreamEvent sse_decode_stream_event(SseDeserializer deserializer);
+
+  @protected
+  StreamMessage sse_decode_stream_message(SseDeserializer deserializer);
+
+  @protected
+  StreamState sse_decode_stream_state(SseDeserializer deserializer);
+
+  @protected
+  SwitchPlaceholder sse_decode_switch_placeholder(SseDeserializer deserializer);
+
+  @protected
+  TapFrame sse_decode_tap_frame(SseDeserializer deserializer);
+
+  @protected
+  TapPixelFormat sse_decode_tap_pixel_format(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
 
   @protected
-  StreamState dco_decode_box_autoadd_stream_state(dynamic raw);
+  VideoConfig sse_decode_video_config(SseDeserializer deserializer);
 
   @protected
-  int dco_decode_box_autoadd_u_16(dynamic raw);
+  VideoTransform sse_decode_video_transform(SseDeserializer deserializer);
 
   @protected
-  VideoConfig dco_decode_box_autoadd_video_config(dynamic raw);
+  WscRtpMode sse_decode_wsc_rtp_mode(SseDeserializer deserializer);
 
   @protected
-  WscRtpMode dco_decode_box_autoadd_wsc_rtp_mode(dynamic raw);
+  WscRtpSessionConfig sse_decode_wsc_rtp_session_config(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  WscRtpSessionConfig dco_decode_box_autoadd_wsc_rtp_session_config(
-    dynamic raw,
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
   );
 
   @protected
-  double dco_decode_f_64(dynamic raw);
+  void sse_encode_StreamSink_stream_message_Sse(
+    RustStreamSink<StreamMessage> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw);
+  void sse_encode_String(String self, SseSerializer serializer);
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  void sse_encode_bool(bool self, SseSerializer serializer);
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_16(dynamic raw);
+  void sse_encode_box_autoadd_crop_rect(
+    CropRect self,
+    SseSerializer serializer,
+  );
 
   @protected
-  PlaybinConfig dco_decode_playbin_config(dynamic raw);
+  void sse_encode_box_autoadd_frame_tap_config(
+    FrameTapConfig self,
+    SseSerializer serializer,
+  );
 
   @protected
-  StreamEvent dco_decode_stream_event(dynamic raw);
+  void sse_encode_box_autoadd_image_adjustments(
+    ImageAdjustments self,
+    SseSerializer serializer,
+  );
 
   @protected
-  StreamMessage dco_decode_stream_message(dynamic raw);
+  void sse_encode_box_autoadd_keep_alive_policy(
+    KeepAlivePolicy self,
+    SseSerializer serializer,
+  );
 
   @protected
-  StreamState dco_decode_stream_state(dynamic raw);
+  void sse_encode_box_autoadd_output_size(
+    OutputSize self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int dco_decode_u_16(dynamic raw);
+  void sse_encode_box_autoadd_playbin_config(
+    PlaybinConfig self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw);
+  void sse_encode_box_autoadd_pre_event_buffer(
+    PreEventBuffer self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  void sse_encode_box_autoadd_presentation_policy(
+    PresentationPolicy self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  void sse_encode_box_autoadd_recording_container(
+    RecordingContainer self,
+    SseSerializer serializer,
+  );
 
   @protected
-  VideoConfig dco_decode_video_config(dynamic raw);
+  void sse_encode_box_autoadd_recording_info(
+    RecordingInfo self,
+    SseSerializer serializer,
+  );
 
   @protected
-  WscRtpMode dco_decode_wsc_rtp_mode(dynamic raw);
+  void sse_encode_box_autoadd_recording_segments(
+    RecordingSegments self,
+    SseSerializer serializer,
+  );
 
   @protected
-  WscRtpSessionConfig dco_decode_wsc_rtp_session_config(dynamic raw);
+  void sse_encode_box_autoadd_runtime_config(
+    RuntimeConfig self,
+    SseSerializer serializer,
+  );
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_session_options(
+    SessionOptions self,
+    SseSerializer serializer,
+  );
 
   @protected
-  RustStreamSink<StreamMessage> sse_decode_StreamSink_stream_message_Sse(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_stall_watchdog_config(
+    StallWatchdogConfig self,
+    SseSerializer serializer,
   );
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_startup_report(
+    StartupReport self,
+    SseSerializer serializer,
+  );
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_stream_error(
+    StreamError self,
+    SseSerializer serializer,
+  );
 
   @protected
-  PlaybinConfig sse_decode_box_autoadd_playbin_config(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_stream_event(
+    StreamEvent self,
+    SseSerializer serializer,
   );
 
   @protected
-  StreamEvent sse_decode_box_autoadd_stream_event(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_stream_state(
+    StreamState self,
+    SseSerializer serializer,
+  );
 
   @protected
-  StreamState sse_decode_box_autoadd_stream_state(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_switch_placeholder(
+    SwitchPlaceholder self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_tap_frame(
+    TapFrame self,
+    SseSerializer serializer,
+  );
 
   @protected
-  VideoConfig sse_decode_box_autoadd_video_config(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer);
 
   @protected
-  WscRtpMode sse_decode_box_autoadd_wsc_rtp_mode(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
 
   @protected
-  WscRtpSessionConfig sse_decode_box_autoadd_wsc_rtp_session_config(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_video_config(
+    VideoConfig self,
+    SseSerializer serializer,
   );
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_video_transform(
+    VideoTransform self,
+    SseSerializer serializer,
+  );
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_wsc_rtp_mode(
+    WscRtpMode self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_wsc_rtp_session_config(
+    WscRtpSessionConfig self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer);
+  void sse_encode_crop_rect(CropRect self, SseSerializer serializer);
 
   @protected
-  PlaybinConfig sse_decode_playbin_config(SseDeserializer deserializer);
+  void sse_encode_deinterlace_method(
+    DeinterlaceMethod self,
+    SseSerializer serializer,
+  );
 
   @protected
-  StreamEvent sse_decode_stream_event(SseDeserializer deserializer);
+  void sse_encode_deinterlace_mode(
+    DeinterlaceMode self,
+    SseSerializer serializer,
+  );
 
   @protected
-  StreamMessage sse_decode_stream_message(SseDeserializer deserializer);
+  void sse_encode_end_frame_policy(
+    EndFramePolicy self,
+    SseSerializer serializer,
+  );
 
   @protected
-  StreamState sse_decode_stream_state(SseDeserializer deserializer);
+  void sse_encode_error_backend(ErrorBackend self, SseSerializer serializer);
 
   @protected
-  int sse_decode_u_16(SseDeserializer deserializer);
+  void sse_encode_error_category(ErrorCategory self, SseSerializer serializer);
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+  void sse_encode_f_64(double self, SseSerializer serializer);
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  void sse_encode_frame_output_mode(
+    FrameOutputMode self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  void sse_encode_frame_tap_config(
+    FrameTapConfig self,
+    SseSerializer serializer,
+  );
 
   @protected
-  VideoConfig sse_decode_video_config(SseDeserializer deserializer);
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
   @protected
-  WscRtpMode sse_decode_wsc_rtp_mode(SseDeserializer deserializer);
+  void sse_encode_image_adjustments(
+    ImageAdjustments self,
+    SseSerializer serializer,
+  );
 
   @protected
-  WscRtpSessionConfig sse_decode_wsc_rtp_session_config(
-    SseDeserializer deserializer,
+  void sse_encode_keep_alive_policy(
+    KeepAlivePolicy self,
+    SseSerializer serializer,
   );
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_StreamSink_stream_message_Sse(
-    RustStreamSink<StreamMessage> self,
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_crop_rect(
+    CropRect? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_box_autoadd_playbin_config(
-    PlaybinConfig self,
+  void sse_encode_opt_box_autoadd_frame_tap_config(
+    FrameTapConfig? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_stream_event(
-    StreamEvent self,
+  void sse_encode_opt_box_autoadd_keep_alive_policy(
+    KeepAlivePolicy? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_stream_state(
-    StreamState self,
+  void sse_encode_opt_box_autoadd_output_size(
+    OutputSize? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_pre_event_buffer(
+    PreEventBuffer? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_box_autoadd_video_config(
-    VideoConfig self,
+  void sse_encode_opt_box_autoadd_recording_container(
+    RecordingContainer? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_wsc_rtp_mode(
-    WscRtpMode self,
+  void sse_encode_opt_box_autoadd_recording_segments(
+    RecordingSegments? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_wsc_rtp_session_config(
-    WscRtpSessionConfig self,
+  void sse_encode_opt_box_autoadd_runtime_config(
+    RuntimeConfig? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_f_64(double self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_session_options(
+    SessionOptions? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_stall_watchdog_config(
+    StallWatchdogConfig? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_opt_box_autoadd_switch_placeholder(
+    SwitchPlaceholder? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_tap_frame(
+    TapFrame? self,
     SseSerializer serializer,
   );
 
   @protected
   void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_output_size(OutputSize self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_placeholder_frame(
+    PlaceholderFrame self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_playbin_config(PlaybinConfig self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_pre_event_buffer(
+    PreEventBuffer self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_presentation_policy(
+    PresentationPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_recording_container(
+    RecordingContainer self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_recording_info(RecordingInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_recording_segments(
+    RecordingSegments self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_rotation(Rotation self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_runtime_config(RuntimeConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_scale_mode(ScaleMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_session_options(
+    SessionOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_snapshot(Snapshot self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_snapshot_format(
+    SnapshotFormat self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_stall_watchdog_config(
+    StallWatchdogConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_startup_report(StartupReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_stream_error(StreamError self, SseSerializer serializer);
+
   @protected
   void sse_encode_stream_event(StreamEvent self, SseSerializer serializer);
 
@@ -277,9 +1088,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_stream_state(StreamState self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_switch_placeholder(
+    SwitchPlaceholder self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_tap_frame(TapFrame self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_tap_pixel_format(
+    TapPixelFormat self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_u_16(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_64(BigInt self, SseSerializer serializer);
 
@@ -292,6 +1121,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_video_config(VideoConfig self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_video_transform(
+    VideoTransform self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_wsc_rtp_mode(WscRtpMode self, SseSerializer serializer);
 
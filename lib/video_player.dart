@@ -30,6 +30,7 @@ class VideoController {
   final StreamSubscription _combinedSub;
   final Stream<StreamEvent> eventsStream;
   bool _running = false;
+  int? _textureId;
 
   VideoController(
     StreamSubscription combinedSub, {
@@ -39,6 +40,11 @@ class VideoController {
     required this.config,
   }) : _combinedSub = combinedSub;
 
+  /// Texture of the last `Playing` state; `null` for standby and headless sessions.
+  /// It stays registered until [dispose] and shows the session's end frame
+  /// after an error or stop.
+  int? get textureId => _textureId;
+
   Future<void> dispose() async {
     _running = false;
     await rlib.destroyStreamSession(sessionId: sessionId);
@@ -47,6 +53,7 @@ class VideoController {
 
   static Future<(VideoController?, String?)> create({
     required VideoConfig config,
+    SessionOptions? options,
   }) async {
     final handle = await EngineContext.instance.getEngineHandle();
     final sessionId = await rlib.createNewSession();
@@ -63,13 +70,20 @@ class VideoController {
         sessionId: sessionId,
         engineHandle: handle,
         config: config,
+        options: options,
       );
 
+      // assigned below, before the first message can arrive
+      late final VideoController ret;
+
       // Listen to the combined stream and split into state and events
       final combinedSub = combinedStream.listen(
         (message) {
           switch (message) {
             case StreamMessage_State(field0: final state):
+              if (state is StreamState_Playing && state.textureId >= 0) {
+                ret._textureId = state.textureId;
+              }
               if (!stateSubject.isClosed) {
                 stateSubject.add(state);
               }
@@ -91,7 +105,7 @@ class VideoController {
         },
       );
 
-      final ret = VideoController(
+      ret = VideoController(
         combinedSub,
         sessionId: sessionId,
         stateBroadcast: stateSubject,
@@ -164,16 +178,36 @@ Widget _defaultLoading(BuildContext ctx, String message) {
   );
 }
 
-Widget _defaultContent(BuildContext context, StreamState state) {
+/// After an error or stop the texture stays registered and shows the end frame
+/// of the session's `PresentationPolicy`, so it keeps being rendered.
+Widget _defaultContent(
+  BuildContext context,
+  StreamState state, {
+  int? endFrameTextureId,
+}) {
   return switch (state) {
     StreamState_Loading() => _defaultLoading(context, 'Initializing stream...'),
-    StreamState_Error(field0: final message) => Center(
+    StreamState_Error(field0: final error) when endFrameTextureId != null =>
+      Stack(
+        alignment: Alignment.center,
+        children: [
+          Texture(textureId: endFrameTextureId),
+          Text(
+            'Error: ${error.message}',
+            style: const TextStyle(color: Colors.red, fontSize: 16),
+          ),
+        ],
+      ),
+    StreamState_Error(field0: final error) => Center(
       child: Text(
-        'Error: $message',
+        'Error: ${error.message}',
         style: const TextStyle(color: Colors.red, fontSize: 16),
       ),
     ),
     StreamState_Playing(:final textureId) => Texture(textureId: textureId),
+    StreamState_Stopped() when endFrameTextureId != null => Texture(
+      textureId: endFrameTextureId,
+    ),
     StreamState_Stopped() => const Center(
       child: Text('Video stopped', style: TextStyle(fontSize: 16)),
     ),
@@ -183,7 +217,10 @@ Widget _defaultContent(BuildContext context, StreamState state) {
 class VideoPlayer extends StatefulWidget {
   final VideoController controller;
   final LoadingBuilder loadingBuilder;
-  final ContentBuilder contentBuilder;
+
+  /// `null` uses the default content, which keeps showing the end frame
+  /// after an error or stop.
+  final ContentBuilder? contentBuilder;
 
   /// whether to dispose the stream when the widget disposes?
   final bool autoDispose;
@@ -192,7 +229,7 @@ class VideoPlayer extends StatefulWidget {
     super.key,
     required this.controller,
     this.loadingBuilder = _defaultLoading,
-    this.contentBuilder = _defaultContent,
+    this.contentBuilder,
     this.autoDispose = true,
   });
 
@@ -208,7 +245,7 @@ class VideoPlayer extends StatefulWidget {
       controller: controller,
       autoDispose: autoDispose,
       loadingBuilder: loadingBuilder ?? _defaultLoading,
-      contentBuilder: contentBuilder ?? _defaultContent,
+      contentBuilder: contentBuilder,
     );
   }
 
@@ -234,7 +271,7 @@ class VideoPlayer extends StatefulWidget {
           controller: controller!,
           autoDispose: autoDispose,
           loadingBuilder: loadingBuilder ?? _defaultLoading,
-          contentBuilder: contentBuilder ?? _defaultContent,
+          contentBuilder: contentBuilder,
         );
       },
     );
@@ -263,7 +300,15 @@ class _VideoPlayerState extends State<VideoPlayer> {
     if (currentState == null) {
       return widget.loadingBuilder.call(context, 'Initializing...');
     }
-    return widget.contentBuilder.call(context, currentState!);
+    final contentBuilder = widget.contentBuilder;
+    if (contentBuilder != null) {
+      return contentBuilder.call(context, currentState!);
+    }
+    return _defaultContent(
+      context,
+      currentState!,
+      endFrameTextureId: widget.controller.textureId,
+    );
   }
 
   @override
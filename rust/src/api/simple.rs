@@ -1,55 +1,44 @@
-use std::thread;
+use std::{sync::Arc, time::Duration};
 
-use log::{debug, error, trace};
+use log::{error, trace};
 
 use crate::{
     core::{
-        input::{playbin::PlaybinSession, wsc_rtp::WscRtpSession},
-        session::{
-            registry::{self, insert_session},
-            VideoSessionCommon,
+        session::registry,
+        types::{
+            FrameTapConfig, ImageAdjustments, OutputSize, PreEventBuffer, PresentationPolicy,
+            RecordingContainer, RecordingSegments, RuntimeConfig, ScaleMode, SessionOptions,
+            SnapshotFormat, SwitchPlaceholder, VideoConfig, VideoTransform,
         },
-        types::VideoConfig,
-        HTTP_CLIENT, IS_INITIALIZED,
     },
-    dart_types::StreamMessage,
+    dart_types::{RecordingInfo, Snapshot, StreamMessage, TapFrame},
     frb_generated::StreamSink,
-    utils::LogErr,
 };
 
+const DEFAULT_TEARDOWN_TIMEOUT_MS: u64 = 5000;
+
 #[flutter_rust_bridge::frb(init)]
 pub fn init_app() {
     crate::core::init_logger();
 }
 
-pub fn flutter_realtime_player_init(ffi_ptr: i64) {
-    let mut is_initialized = IS_INITIALIZED.lock().unwrap();
-    if *is_initialized {
-        return;
-    }
-    irondash_dart_ffi::irondash_init_ffi(ffi_ptr as *mut std::ffi::c_void);
-
-    registry::init().log_err();
-    thread::spawn(crate::core::session::registry::stream_alive_tester_task);
-    debug!("Done initializing flutter gstreamer");
-    *is_initialized = true;
+pub fn flutter_realtime_player_init(ffi_ptr: i64, runtime_config: Option<RuntimeConfig>) {
+    crate::core::init_services(&runtime_config.unwrap_or_default(), || {
+        irondash_dart_ffi::irondash_init_ffi(ffi_ptr as *mut std::ffi::c_void);
+    });
 }
 
-lazy_static::lazy_static! {
-    static ref SESSION_COUNTER: std::sync::Mutex<i64> = std::sync::Mutex::new(0);
-}
 /// updates the counter and returns a session id
 /// note that this doesn't create any resources apart from raising the counter
 pub fn create_new_session() -> i64 {
-    let mut session_counter = SESSION_COUNTER.lock().unwrap();
-    *session_counter += 1;
-    *session_counter
+    registry::next_session_id()
 }
 
 pub async fn create_playable(
     session_id: i64,
     engine_handle: i64,
     config: VideoConfig,
+    options: Option<SessionOptions>,
     combined_sink: StreamSink<StreamMessage>,
 ) -> anyhow::Result<()> {
     trace!(
@@ -57,26 +46,299 @@ pub async fn create_playable(
         engine_handle,
         session_id
     );
-    match config {
-        VideoConfig::WscRtp(wsc_rtp_config) => {
-            trace!("  source_id: {}", wsc_rtp_config.source_id.as_str());
-            let session_common = VideoSessionCommon::new(session_id, engine_handle, combined_sink);
-            let (session, shutdown_rx) =
-                WscRtpSession::new(wsc_rtp_config, session_common, HTTP_CLIENT.clone());
-            let session_clone = session.clone();
-            tokio::spawn(async move { session_clone.execute(shutdown_rx).await });
-            insert_session(session_id, session);
-        }
-        VideoConfig::Playbin(playbin_config) => {
-            trace!("  uri: {}", playbin_config.uri);
-            let session_common = VideoSessionCommon::new(session_id, engine_handle, combined_sink);
-            let (session, shutdown_rx) = PlaybinSession::new(playbin_config, session_common);
-            let session_clone = session.clone();
-            tokio::spawn(async move { session_clone.execute(shutdown_rx).await });
-            insert_session(session_id, session);
-        }
-    }
-    Ok(())
+    registry::create_session(
+        session_id,
+        engine_handle,
+        config,
+        options.unwrap_or_default(),
+        Arc::new(combined_sink),
+    )
+    .await
+}
+
+/// Replaces the session's source while keeping its texture: the current backend is
+/// stopped and one for `config` renders into the same texture id. The texture shows
+/// `placeholder` (by default the last frame) until the new source's first frame arrives.
+/// Meanwhile the session stays in its current state instead of going back to `Loading`.
+pub fn switch_source(
+    session_id: i64,
+    config: VideoConfig,
+    placeholder: Option<SwitchPlaceholder>,
+) -> anyhow::Result<()> {
+    trace!("switch_source was called for session_id: {}", session_id);
+    let result = registry::switch_source(
+        session_id,
+        config,
+        placeholder.unwrap_or(SwitchPlaceholder::HoldLastFrame),
+    );
+    if let Err(e) = &result {
+        error!("switch_source failed: {}", e);
+    }
+    result
+}
+
+/// Scales the session's frames in the decode pipeline, usually to the widget size
+/// in physical pixels. Takes effect without restarting the session.
+pub fn set_output_size(
+    session_id: i64,
+    width: u32,
+    height: u32,
+    mode: ScaleMode,
+) -> anyhow::Result<()> {
+    trace!("set_output_size was called for session_id: {}", session_id);
+    let result = registry::set_output_size_session(
+        session_id,
+        Some(OutputSize {
+            width,
+            height,
+            mode,
+        }),
+    );
+    if let Err(e) = &result {
+        error!("set_output_size failed: {}", e);
+    }
+    result
+}
+
+/// Goes back to rendering frames at the decoded size.
+pub fn reset_output_size(session_id: i64) -> anyhow::Result<()> {
+    trace!(
+        "reset_output_size was called for session_id: {}",
+        session_id
+    );
+    let result = registry::set_output_size_session(session_id, None);
+    if let Err(e) = &result {
+        error!("reset_output_size failed: {}", e);
+    }
+    result
+}
+
+/// Sets the session's rotation, flips, crop and zoom; applied in the decode pipeline
+/// without restarting the session. `OriginVideoSize` reports the transformed size.
+pub fn set_video_transform(session_id: i64, transform: VideoTransform) -> anyhow::Result<()> {
+    trace!(
+        "set_video_transform was called for session_id: {}",
+        session_id
+    );
+    let result = registry::set_video_transform_session(session_id, transform);
+    if let Err(e) = &result {
+        error!("set_video_transform failed: {}", e);
+    }
+    result
+}
+
+/// Zooms into the session's video around (`pan_x`, `pan_y`), fractions of the cropped frame.
+/// Keeps the rest of the transform; `zoom` 1.0 shows the whole frame.
+pub fn set_digital_zoom(session_id: i64, zoom: f64, pan_x: f64, pan_y: f64) -> anyhow::Result<()> {
+    trace!("set_digital_zoom was called for session_id: {}", session_id);
+    let result = registry::set_digital_zoom_session(session_id, zoom, pan_x, pan_y);
+    if let Err(e) = &result {
+        error!("set_digital_zoom failed: {}", e);
+    }
+    result
+}
+
+/// Caps how many frames per second the session converts and renders, e.g. 5 for
+/// background tiles; `None` renders every frame. The stream keeps decoding in full.
+pub fn set_max_frame_rate(session_id: i64, max_frame_rate: Option<u32>) -> anyhow::Result<()> {
+    trace!(
+        "set_max_frame_rate was called for session_id: {}",
+        session_id
+    );
+    let result = registry::set_max_frame_rate_session(session_id, max_frame_rate);
+    if let Err(e) = &result {
+        error!("set_max_frame_rate failed: {}", e);
+    }
+    result
+}
+
+/// Sets deinterlacing, brightness, contrast, saturation, hue and gamma of the session's
+/// video. Applied in the decode pipeline without restarting the session.
+pub fn set_image_adjustments(session_id: i64, adjustments: ImageAdjustments) -> anyhow::Result<()> {
+    trace!(
+        "set_image_adjustments was called for session_id: {}",
+        session_id
+    );
+    let result = registry::set_image_adjustments_session(session_id, adjustments);
+    if let Err(e) = &result {
+        error!("set_image_adjustments failed: {}", e);
+    }
+    result
+}
+
+/// Taps the session's decoded frames, after rotation, crop and zoom, at the rate, size
+/// and pixel format of `config`; returns the tap id for `next_frame` and `unsubscribe_frames`.
+/// The session's own `max_frame_rate` doesn't limit the tap.
+/// Up to `queue_len` frames wait for `next_frame`; further ones are dropped, so a slow
+/// isolate never makes frames pile up.
+pub fn subscribe_frames(session_id: i64, config: Option<FrameTapConfig>) -> anyhow::Result<i64> {
+    trace!("subscribe_frames was called for session_id: {}", session_id);
+    let result = registry::add_pulled_frame_tap_session(session_id, config.unwrap_or_default());
+    if let Err(e) = &result {
+        error!("subscribe_frames failed: {}", e);
+    }
+    result
+}
+
+/// Waits for the tap's next frame; `None` once it was unsubscribed or the session ended.
+pub async fn next_frame(session_id: i64, tap_id: i64) -> anyhow::Result<Option<TapFrame>> {
+    let result = registry::next_frame_session(session_id, tap_id).await;
+    if let Err(e) = &result {
+        error!("next_frame failed: {}", e);
+    }
+    result
+}
+
+pub fn unsubscribe_frames(session_id: i64, tap_id: i64) -> anyhow::Result<()> {
+    trace!(
+        "unsubscribe_frames was called for session_id: {}, tap_id: {}",
+        session_id,
+        tap_id
+    );
+    let result = registry::remove_frame_tap_session(session_id, tap_id);
+    if let Err(e) = &result {
+        error!("unsubscribe_frames failed: {}", e);
+    }
+    result
+}
+
+/// Replaces the session's presentation policy (placeholder, stop/error frame, reconnect overlay).
+/// The initial policy comes from `SessionOptions.presentation`.
+pub fn set_presentation_policy(session_id: i64, policy: PresentationPolicy) -> anyhow::Result<()> {
+    trace!(
+        "set_presentation_policy was called for session_id: {}",
+        session_id
+    );
+    let result = registry::set_presentation_session(session_id, policy);
+    if let Err(e) = &result {
+        error!("set_presentation_policy failed: {}", e);
+    }
+    result
+}
+
+/// Encodes the session's current frame to PNG or JPEG (`quality` 0-100, default 85),
+/// downscaled so its longer side is at most `max_size`. With `full_resolution` the next
+/// decoded frame is captured before any transform or output scaling instead.
+pub async fn capture_snapshot(
+    session_id: i64,
+    format: SnapshotFormat,
+    quality: Option<u8>,
+    max_size: Option<u32>,
+    full_resolution: bool,
+) -> anyhow::Result<Snapshot> {
+    trace!("capture_snapshot was called for session_id: {}", session_id);
+    let result =
+        registry::capture_snapshot(session_id, format, quality, max_size, full_resolution, None)
+            .await;
+    if let Err(e) = &result {
+        error!("capture_snapshot failed: {}", e);
+    }
+    result
+}
+
+/// Like `capture_snapshot`, but writes the image to `path`; the returned `data` is empty.
+pub async fn capture_snapshot_to_file(
+    session_id: i64,
+    path: String,
+    format: SnapshotFormat,
+    quality: Option<u8>,
+    max_size: Option<u32>,
+    full_resolution: bool,
+) -> anyhow::Result<Snapshot> {
+    trace!(
+        "capture_snapshot_to_file was called for session_id: {}",
+        session_id
+    );
+    let result = registry::capture_snapshot(
+        session_id,
+        format,
+        quality,
+        max_size,
+        full_resolution,
+        Some(path),
+    )
+    .await;
+    if let Err(e) = &result {
+        error!("capture_snapshot_to_file failed: {}", e);
+    }
+    result
+}
+
+/// Records the session's stream to `path` as received, without re-encoding. Writing
+/// starts at the next keyframe; the recording stops by itself after `max_duration_ms`
+/// or `max_size_bytes`. With `segments` the files are numbered, e.g. `clip_00000.mp4`.
+/// `RecordingStarted`, `RecordingStopped` and `RecordingError` events report progress.
+pub fn start_recording(
+    session_id: i64,
+    path: String,
+    container: Option<RecordingContainer>,
+    max_duration_ms: Option<u64>,
+    max_size_bytes: Option<u64>,
+    segments: Option<RecordingSegments>,
+) -> anyhow::Result<()> {
+    trace!("start_recording was called for session_id: {}", session_id);
+    let result = registry::start_recording_session(
+        session_id,
+        path,
+        container.unwrap_or_default(),
+        max_duration_ms,
+        max_size_bytes,
+        segments,
+    );
+    if let Err(e) = &result {
+        error!("start_recording failed: {}", e);
+    }
+    result
+}
+
+/// Finalizes the recording and returns the written files.
+pub async fn stop_recording(session_id: i64) -> anyhow::Result<RecordingInfo> {
+    trace!("stop_recording was called for session_id: {}", session_id);
+    let result = registry::stop_recording_session(session_id).await;
+    if let Err(e) = &result {
+        error!("stop_recording failed: {}", e);
+    }
+    result
+}
+
+/// Keeps the last `config.duration_ms` of the encoded stream in memory (`None` disables it),
+/// for `save_pre_event_clip`. The initial buffer comes from `SessionOptions.pre_event_buffer`.
+pub fn set_pre_event_buffer(session_id: i64, config: Option<PreEventBuffer>) -> anyhow::Result<()> {
+    trace!(
+        "set_pre_event_buffer was called for session_id: {}",
+        session_id
+    );
+    let result = registry::set_pre_event_buffer_session(session_id, config);
+    if let Err(e) = &result {
+        error!("set_pre_event_buffer failed: {}", e);
+    }
+    result
+}
+
+/// Saves what happened before and after an incident: the pre-event buffer plus the next
+/// `post_event_ms` of the stream, written to `path` (MP4 by default). Completes with the
+/// written file once it is finalized; `Recording*` events are sent as for `start_recording`.
+pub async fn save_pre_event_clip(
+    session_id: i64,
+    path: String,
+    post_event_ms: u64,
+    container: Option<RecordingContainer>,
+) -> anyhow::Result<RecordingInfo> {
+    trace!(
+        "save_pre_event_clip was called for session_id: {}",
+        session_id
+    );
+    let result = registry::save_pre_event_clip_session(
+        session_id,
+        path,
+        container.unwrap_or_default(),
+        post_event_ms,
+    )
+    .await;
+    if let Err(e) = &result {
+        error!("save_pre_event_clip failed: {}", e);
+    }
+    result
 }
 
 pub async fn seek_to_timestamp(session_id: i64, ts: u64) -> anyhow::Result<()> {
@@ -114,9 +376,26 @@ pub async fn set_speed(session_id: i64, speed: f64) -> anyhow::Result<()> {
     result
 }
 
+/// Moves the textures of `target_session_id` onto the standby session `standby_session_id`
+/// (created with `SessionOptions.standby`) and stops the target's previous source.
+/// The target keeps its session id and texture id; the standby session id is released.
+pub fn promote_standby_session(
+    standby_session_id: i64,
+    target_session_id: i64,
+) -> anyhow::Result<()> {
+    trace!("promote_standby_session was called");
+    let result = registry::promote_standby_session(standby_session_id, target_session_id);
+    if let Err(e) = &result {
+        error!("promote_standby_session failed: {}", e);
+    }
+    result
+}
+
 /// marks the session as required by the ui
-/// if the ui won't call this every 2 seconds
+/// if the ui won't call this within the session's keep-alive timeout
+/// (5 seconds unless set via `SessionOptions.keep_alive`)
 /// this session will be terminate.
+/// a shared decode keeps running as long as any session attached to it is alive.
 pub fn mark_session_alive(session_id: i64) {
     crate::core::session::registry::mark_session_alive(session_id);
 }
@@ -133,3 +412,18 @@ pub fn destroy_stream_session(session_id: i64) {
     trace!("destroy_stream_session was called");
     crate::core::session::registry::destroy_stream_session(session_id)
 }
+
+/// Destroys the session and resolves once its pipeline is stopped and its texture released.
+/// Fails if that didn't happen within `timeout_ms` (default 5000ms).
+pub async fn destroy_stream_session_and_wait(
+    session_id: i64,
+    timeout_ms: Option<u64>,
+) -> anyhow::Result<()> {
+    trace!("destroy_stream_session_and_wait was called");
+    let timeout = Duration::from_millis(timeout_ms.unwrap_or(DEFAULT_TEARDOWN_TIMEOUT_MS));
+    let result = registry::destroy_stream_session_and_wait(session_id, timeout).await;
+    if let Err(e) = &result {
+        error!("destroy_stream_session_and_wait failed: {}", e);
+    }
+    result
+}
@@ -0,0 +1,234 @@
+use std::fmt;
+
+use crate::dart_types::{ErrorBackend, ErrorCategory, StreamError};
+
+impl ErrorCategory {
+    pub fn is_retryable(self) -> bool {
+        matches!(
+            self,
+            ErrorCategory::Network
+                | ErrorCategory::Timeout
+                | ErrorCategory::Decode
+                | ErrorCategory::Server
+                | ErrorCategory::Unavailable
+        )
+    }
+
+    pub fn from_http_status(status: u16) -> Self {
+        match status {
+            401 | 403 => ErrorCategory::AccessDenied,
+            404 => ErrorCategory::SourceNotFound,
+            408 | 504 => ErrorCategory::Timeout,
+            _ => ErrorCategory::Server,
+        }
+    }
+
+    fn from_gst_error(err: &gst::glib::Error) -> Option<Self> {
+        let category = if err.matches(gst::ResourceError::NotFound) {
+            ErrorCategory::SourceNotFound
+        } else if err.matches(gst::ResourceError::NotAuthorized) {
+            ErrorCategory::AccessDenied
+        } else if err.matches(gst::ResourceError::OpenRead)
+            || err.matches(gst::ResourceError::Read)
+            || err.matches(gst::ResourceError::Failed)
+        {
+            ErrorCategory::Network
+        } else if err.matches(gst::ParseError::NoSuchElement)
+            || err.matches(gst::CoreError::MissingPlugin)
+            || err.matches(gst::StreamError::CodecNotFound)
+            || err.matches(gst::StreamError::TypeNotFound)
+            || err.matches(gst::StreamError::WrongType)
+        {
+            ErrorCategory::UnsupportedCodec
+        } else if err.matches(gst::StreamError::Decode)
+            || err.matches(gst::StreamError::Demux)
+            || err.matches(gst::StreamError::Format)
+        {
+            ErrorCategory::Decode
+        } else if err.matches(gst::URIError::BadUri)
+            || err.matches(gst::URIError::UnsupportedProtocol)
+        {
+            ErrorCategory::InvalidUri
+        } else {
+            return None;
+        };
+        Some(category)
+    }
+}
+
+impl StreamError {
+    pub fn new(backend: ErrorBackend, category: ErrorCategory, message: impl Into<String>) -> Self {
+        Self {
+            category,
+            retryable: category.is_retryable(),
+            backend,
+            cause: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn with_cause(mut self, cause: impl fmt::Display) -> Self {
+        self.cause = Some(cause.to_string());
+        self
+    }
+
+    /// Classify an error chain by the typed errors it contains (GStreamer, websocket,
+    /// HTTP, io). `fallback` is used when nothing in the chain is recognized.
+    pub fn from_anyhow(
+        backend: ErrorBackend,
+        fallback: ErrorCategory,
+        message: impl Into<String>,
+        err: &anyhow::Error,
+    ) -> Self {
+        if let Some(stream_err) = err
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<StreamError>())
+        {
+            return stream_err.clone();
+        }
+        let category = err
+            .chain()
+            .find_map(|cause| {
+                if let Some(gst_err) = cause.downcast_ref::<gst::glib::Error>() {
+                    return ErrorCategory::from_gst_error(gst_err);
+                }
+                if let Some(ws_err) = cause.downcast_ref::<tokio_tungstenite::tungstenite::Error>()
+                {
+                    return Some(match ws_err {
+                        tokio_tungstenite::tungstenite::Error::Http(response) => {
+                            ErrorCategory::from_http_status(response.status().as_u16())
+                        }
+                        tokio_tungstenite::tungstenite::Error::Url(_) => ErrorCategory::InvalidUri,
+                        _ => ErrorCategory::Network,
+                    });
+                }
+                if let Some(http_err) = cause.downcast_ref::<reqwest::Error>() {
+                    return Some(match http_err.status() {
+                        Some(status) => ErrorCategory::from_http_status(status.as_u16()),
+                        None if http_err.is_timeout() => ErrorCategory::Timeout,
+                        None => ErrorCategory::Network,
+                    });
+                }
+                if cause.is::<url::ParseError>() {
+                    return Some(ErrorCategory::InvalidUri);
+                }
+                if cause.is::<tokio::time::error::Elapsed>() {
+                    return Some(ErrorCategory::Timeout);
+                }
+                if cause.is::<std::io::Error>() {
+                    return Some(ErrorCategory::Network);
+                }
+                None
+            })
+            .unwrap_or(fallback);
+        Self::new(backend, category, message).with_cause(format!("{:#}", err))
+    }
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.cause {
+            Some(cause) => write!(f, "{}: {}", self.message, cause),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Context;
+
+    use super::*;
+
+    fn classify(err: anyhow::Error) -> StreamError {
+        StreamError::from_anyhow(
+            ErrorBackend::Playbin,
+            ErrorCategory::Internal,
+            "playback failed",
+            &err,
+        )
+    }
+
+    #[test]
+    fn http_status_mapping() {
+        assert_eq!(
+            ErrorCategory::from_http_status(401),
+            ErrorCategory::AccessDenied
+        );
+        assert_eq!(
+            ErrorCategory::from_http_status(403),
+            ErrorCategory::AccessDenied
+        );
+        assert_eq!(
+            ErrorCategory::from_http_status(404),
+            ErrorCategory::SourceNotFound
+        );
+        assert_eq!(ErrorCategory::from_http_status(408), ErrorCategory::Timeout);
+        assert_eq!(ErrorCategory::from_http_status(504), ErrorCategory::Timeout);
+        assert_eq!(ErrorCategory::from_http_status(500), ErrorCategory::Server);
+    }
+
+    #[test]
+    fn new_derives_retryable_from_the_category() {
+        let err = StreamError::new(ErrorBackend::WscRtp, ErrorCategory::Network, "dropped");
+        assert!(err.retryable);
+        let err = StreamError::new(ErrorBackend::WscRtp, ErrorCategory::AccessDenied, "denied");
+        assert!(!err.retryable);
+        assert_eq!(err.cause, None);
+    }
+
+    #[test]
+    fn display_appends_the_cause() {
+        let err = StreamError::new(ErrorBackend::Registry, ErrorCategory::Internal, "failed");
+        assert_eq!(err.to_string(), "failed");
+        assert_eq!(err.with_cause("boom").to_string(), "failed: boom");
+    }
+
+    #[test]
+    fn from_anyhow_classifies_gstreamer_errors() {
+        let gst_err = gst::glib::Error::new(gst::ResourceError::NotFound, "no such file");
+        let err = classify(anyhow::Error::new(gst_err).context("opening source"));
+        assert_eq!(err.category, ErrorCategory::SourceNotFound);
+        assert_eq!(err.backend, ErrorBackend::Playbin);
+        assert_eq!(err.message, "playback failed");
+        assert!(err.cause.unwrap().contains("no such file"));
+
+        let gst_err = gst::glib::Error::new(gst::StreamError::CodecNotFound, "no decoder");
+        let err = classify(gst_err.into());
+        assert_eq!(err.category, ErrorCategory::UnsupportedCodec);
+        assert!(!err.retryable);
+    }
+
+    #[test]
+    fn from_anyhow_classifies_uri_and_io_errors() {
+        let parse_err = url::Url::parse("not a url").unwrap_err();
+        assert_eq!(
+            classify(parse_err.into()).category,
+            ErrorCategory::InvalidUri
+        );
+
+        let io_err = std::io::Error::new(std::io::ErrorKind::ConnectionReset, "reset");
+        let err = classify(anyhow::Error::new(io_err).context("reading"));
+        assert_eq!(err.category, ErrorCategory::Network);
+        assert!(err.retryable);
+    }
+
+    #[test]
+    fn from_anyhow_falls_back_for_unknown_errors() {
+        let err = classify(anyhow::anyhow!("something odd"));
+        assert_eq!(err.category, ErrorCategory::Internal);
+        assert_eq!(err.cause.as_deref(), Some("something odd"));
+    }
+
+    #[test]
+    fn from_anyhow_keeps_a_nested_stream_error() {
+        let inner = StreamError::new(ErrorBackend::WscRtp, ErrorCategory::Server, "rejected");
+        let result: anyhow::Result<()> = Err(inner.into());
+        let err = classify(result.context("starting").unwrap_err());
+        assert_eq!(err.category, ErrorCategory::Server);
+        assert_eq!(err.backend, ErrorBackend::WscRtp);
+        assert_eq!(err.message, "rejected");
+    }
+}
@@ -0,0 +1,134 @@
+use std::{
+    any::Any,
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use anyhow::{Context, Result};
+use futures_util::future::BoxFuture;
+use log::{info, trace};
+use serde::de::DeserializeOwned;
+
+use crate::core::{
+    input::{playbin::PlaybinFactory, wsc_rtp::WscRtpFactory},
+    session::{VideoSession, VideoSessionCommon},
+    types::VideoConfig,
+};
+
+/// Kind of the factory creating `VideoConfig::WscRtp` sessions.
+pub const WSC_RTP_KIND: &str = "wsc_rtp";
+/// Kind of the factory creating `VideoConfig::Playbin` sessions.
+pub const PLAYBIN_KIND: &str = "playbin";
+
+/// A created but not yet running input backend.
+pub struct BackendTask {
+    pub session: Arc<dyn VideoSession>,
+    /// Runs the backend until it was terminated or gave up; spawned on the crate runtime.
+    pub run: BoxFuture<'static, Result<()>>,
+}
+
+/// Creates sessions for one kind of source.
+///
+/// The built-in backends are registered as `WSC_RTP_KIND` and `PLAYBIN_KIND`.
+/// Embedding crates implement this for their own `VideoSession` and register it
+/// with `register_backend`; `create_playable` then accepts
+/// `VideoConfig::Custom { kind, json_params }` with `json_params` deserialized into `Config`.
+/// Errors of custom backends are reported with `ErrorBackend::Custom(kind)`.
+pub trait InputBackendFactory: Send + Sync + 'static {
+    type Config: DeserializeOwned + Send + 'static;
+
+    /// Build the session around `session_common`. Frames, state and events go through
+    /// `session_common` (see `frame_sink` for the shared appsink handling);
+    /// `VideoSession::terminate` must make `run` return.
+    fn create(
+        &self,
+        config: Self::Config,
+        session_common: VideoSessionCommon,
+    ) -> Result<BackendTask>;
+}
+
+/// Config of a backend to create: typed for the built-in `VideoConfig` variants,
+/// JSON for `VideoConfig::Custom`.
+enum BackendParams {
+    Typed(Box<dyn Any + Send>),
+    Json(String),
+}
+
+trait ErasedFactory: Send + Sync {
+    fn create_erased(
+        &self,
+        params: BackendParams,
+        session_common: VideoSessionCommon,
+    ) -> Result<BackendTask>;
+}
+
+impl<F: InputBackendFactory> ErasedFactory for F {
+    fn create_erased(
+        &self,
+        params: BackendParams,
+        session_common: VideoSessionCommon,
+    ) -> Result<BackendTask> {
+        let config = match params {
+            BackendParams::Typed(config) => *config
+                .downcast::<F::Config>()
+                .map_err(|_| anyhow::anyhow!("backend config of an unexpected type"))?,
+            BackendParams::Json(json_params) => serde_json::from_str::<F::Config>(&json_params)
+                .context("parsing custom backend params")?,
+        };
+        self.create(config, session_common)
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref BACKEND_FACTORIES: RwLock<HashMap<String, Arc<dyn ErasedFactory>>> = {
+        let mut factories: HashMap<String, Arc<dyn ErasedFactory>> = HashMap::new();
+        factories.insert(WSC_RTP_KIND.to_string(), Arc::new(WscRtpFactory));
+        factories.insert(PLAYBIN_KIND.to_string(), Arc::new(PlaybinFactory));
+        RwLock::new(factories)
+    };
+}
+
+/// Make sessions of `kind` use `factory`. Registering a kind again replaces the
+/// previous factory, including the built-in ones.
+pub fn register_backend<F: InputBackendFactory>(kind: impl Into<String>, factory: F) {
+    let kind = kind.into();
+    info!("Registering input backend '{}'", kind);
+    BACKEND_FACTORIES
+        .write()
+        .unwrap()
+        .insert(kind, Arc::new(factory));
+}
+
+pub fn unregister_backend(kind: &str) -> bool {
+    BACKEND_FACTORIES.write().unwrap().remove(kind).is_some()
+}
+
+pub fn registered_backends() -> Vec<String> {
+    BACKEND_FACTORIES.read().unwrap().keys().cloned().collect()
+}
+
+/// Creates the backend for `config` with the factory registered for its kind.
+pub(crate) fn create(
+    config: VideoConfig,
+    session_common: VideoSessionCommon,
+) -> Result<BackendTask> {
+    let (kind, params) = match config {
+        VideoConfig::WscRtp(config) => (
+            WSC_RTP_KIND.to_string(),
+            BackendParams::Typed(Box::new(config)),
+        ),
+        VideoConfig::Playbin(config) => (
+            PLAYBIN_KIND.to_string(),
+            BackendParams::Typed(Box::new(config)),
+        ),
+        VideoConfig::Custom { kind, json_params } => (kind, BackendParams::Json(json_params)),
+    };
+    let factory = BACKEND_FACTORIES
+        .read()
+        .unwrap()
+        .get(&kind)
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("No input backend registered for '{}'", kind))?;
+    trace!("  backend: {}", kind);
+    factory.create_erased(params, session_common)
+}
@@ -0,0 +1,199 @@
+use std::sync::{Arc, Weak};
+
+use gst_app::AppSink;
+
+use crate::{
+    core::{
+        input::video_chain::ChainFormat,
+        session::{startup::StartupPhase, VideoSession},
+        texture::{
+            payload::{FrameFormat, RawFrame, SharedPixelData},
+            pool::FramePool,
+        },
+    },
+    dart_types::StreamEvent,
+};
+
+/// Caps the decode branch of every backend must end in: the texture's native pixel format,
+/// so the chain's `videoconvert` is the only conversion a frame goes through.
+pub fn frame_caps() -> gst::Caps {
+    gst::Caps::builder("video/x-raw")
+        .field("format", FrameFormat::native().video_format().to_str())
+        .build()
+}
+
+/// The appsink ending every backend's video chain, named `sink`.
+pub fn build_appsink() -> AppSink {
+    AppSink::builder()
+        .name("sink")
+        .caps(&frame_caps())
+        .sync(false)
+        .build()
+}
+
+/// Installs the appsink callbacks shared by all backends: reports the video format,
+/// marks the first frame, copies the samples and hands them to the session's outputs.
+/// `on_sample` runs for every sample, before the session is looked up.
+pub fn install<S>(appsink: &AppSink, session: Weak<S>, on_sample: impl Fn() + Send + 'static)
+where
+    S: VideoSession + 'static,
+{
+    let mut format = None;
+    let mut first_frame_marked = false;
+    let pool = Arc::new(FramePool::default());
+
+    appsink.set_callbacks(
+        gst_app::AppSinkCallbacks::builder()
+            .new_sample(move |sink| {
+                let sample = sink.pull_sample().map_err(|_| gst::FlowError::Eos)?;
+                on_sample();
+                let Some(session) = session.upgrade() else {
+                    log::warn!("frame sink: session dropped, frame discarded");
+                    return Ok(gst::FlowSuccess::Ok);
+                };
+                let common = session.session_common();
+
+                let caps = sample.caps().ok_or(gst::FlowError::Error)?;
+                let video_info =
+                    gst_video::VideoInfo::from_caps(caps).map_err(|_| gst::FlowError::Error)?;
+                let buffer = sample.buffer().ok_or(gst::FlowError::Error)?;
+
+                // Emit OriginVideoSize whenever size, aspect ratio or frame rate change,
+                // including caps changes mid-stream
+                let current_format = common
+                    .video_format()
+                    .unwrap_or_else(|| ChainFormat::from_video_info(&video_info));
+                if format != Some(current_format) {
+                    format = Some(current_format);
+                    log::debug!("frame sink: video format: {:?}", current_format);
+                    common.send_event_msg(StreamEvent::OriginVideoSize {
+                        width: current_format.source.0 as u64,
+                        height: current_format.source.1 as u64,
+                        transformed_width: current_format.transformed.0 as u64,
+                        transformed_height: current_format.transformed.1 as u64,
+                        pixel_aspect_ratio: current_format.pixel_aspect_ratio(),
+                        display_aspect_ratio: current_format.display_aspect_ratio(),
+                        frame_rate: current_format.frame_rate(),
+                    });
+                }
+
+                if !first_frame_marked {
+                    first_frame_marked = true;
+                    common.mark_startup(StartupPhase::FirstFrame);
+                }
+                let frame = copy_frame(buffer, &video_info, &pool)?;
+                common.push_frame(Arc::new(frame) as SharedPixelData);
+                Ok(gst::FlowSuccess::Ok)
+            })
+            .build(),
+    );
+}
+
+/// Keeps a standby session cheap: while no output wants frames, only keyframes reach
+/// the decoder behind `pad`, so it decodes one picture per GOP instead of every frame.
+/// Once an output wants frames again, delta frames pass from the next keyframe on.
+/// Install it after the recording tap, which must see every packet.
+pub fn throttle_standby_decode<S>(pad: &gst::Pad, session: Weak<S>)
+where
+    S: VideoSession + 'static,
+{
+    let mut skipping = false;
+    pad.add_probe(gst::PadProbeType::BUFFER, move |_, info| {
+        let Some(gst::PadProbeData::Buffer(buffer)) = &info.data else {
+            return gst::PadProbeReturn::Ok;
+        };
+        let is_key = !buffer.flags().contains(gst::BufferFlags::DELTA_UNIT);
+        let wants_frames = session.upgrade().is_some_and(|session| {
+            let common = session.session_common();
+            common.wants_frames() || common.has_frame_taps()
+        });
+        if !wants_frames {
+            skipping = true;
+        } else if skipping && is_key {
+            skipping = false;
+        }
+        if skipping && !is_key {
+            gst::PadProbeReturn::Drop
+        } else {
+            gst::PadProbeReturn::Ok
+        }
+    });
+}
+
+/// Copy a packed RGBA or BGRA buffer into a tightly packed frame backed by a buffer from `pool`.
+pub fn copy_frame(
+    buffer: &gst::BufferRef,
+    video_info: &gst_video::VideoInfo,
+    pool: &Arc<FramePool>,
+) -> Result<RawFrame, gst::FlowError> {
+    let format = FrameFormat::from_video_format(video_info.format()).ok_or_else(|| {
+        log::error!("frame sink: unexpected format {:?}", video_info.format());
+        gst::FlowError::NotNegotiated
+    })?;
+    let video_frame = gst_video::VideoFrameRef::from_buffer_ref_readable(buffer, video_info)
+        .map_err(|_| gst::FlowError::Error)?;
+    let mut data = pool.take(packed_size(video_info));
+    copy_planes(&video_frame, &mut data)?;
+
+    Ok(pool
+        .frame(video_info.width(), video_info.height(), format, data)
+        .with_pts(buffer.pts().map(|pts| pts.nseconds())))
+}
+
+/// Where a plane starts in a packed copy, and its row size there.
+#[derive(Debug, Clone, Copy)]
+pub struct PackedPlane {
+    pub offset: usize,
+    pub stride: usize,
+}
+
+/// Bytes of all planes of a frame without row padding.
+pub fn packed_size(video_info: &gst_video::VideoInfo) -> usize {
+    (0..video_info.n_planes())
+        .map(|plane| {
+            let (row_bytes, rows) = plane_rows(video_info, plane);
+            row_bytes * rows
+        })
+        .sum()
+}
+
+/// Append every plane of `frame` to `data`, dropping the row padding, and return the
+/// layout of the copied planes. Works for any raw format: the visible row size of each
+/// plane comes from its components' width and pixel stride.
+pub fn copy_planes(
+    frame: &gst_video::VideoFrameRef<&gst::BufferRef>,
+    data: &mut Vec<u8>,
+) -> Result<Vec<PackedPlane>, gst::FlowError> {
+    let video_info = frame.info();
+    let mut planes = Vec::with_capacity(video_info.n_planes() as usize);
+    for plane in 0..video_info.n_planes() {
+        let (row_bytes, rows) = plane_rows(video_info, plane);
+        let stride = video_info.stride()[plane as usize] as usize;
+        let plane_data = frame.plane_data(plane).map_err(|_| gst::FlowError::Error)?;
+        planes.push(PackedPlane {
+            offset: data.len(),
+            stride: row_bytes,
+        });
+        if stride == row_bytes {
+            data.extend_from_slice(&plane_data[..row_bytes * rows]);
+        } else {
+            for row in plane_data.chunks(stride).take(rows) {
+                data.extend_from_slice(&row[..row_bytes]);
+            }
+        }
+    }
+    Ok(planes)
+}
+
+/// Visible bytes per row and number of rows of `plane`.
+fn plane_rows(video_info: &gst_video::VideoInfo, plane: u32) -> (usize, usize) {
+    let format_info = video_info.format_info();
+    // any component stored in the plane gives its subsampled size
+    let component = (0..format_info.n_components())
+        .find(|&component| format_info.plane()[component as usize] == plane)
+        .unwrap_or(0);
+    let width = format_info.scale_width(component as u8, video_info.width()) as usize;
+    let height = format_info.scale_height(component as u8, video_info.height()) as usize;
+    let pixel_stride = format_info.pixel_stride()[component as usize] as usize;
+    (width * pixel_stride, height)
+}
@@ -1,7 +1,13 @@
+pub mod factory;
+pub mod frame_sink;
 pub mod playbin;
+pub mod video_chain;
 pub mod wsc_rtp;
 
-use crate::dart_types::StreamState;
+use crate::{
+    core::texture::payload::SharedPixelData,
+    dart_types::{StreamEvent, StreamState},
+};
 
 #[derive(Debug, Clone)]
 pub enum InputCommand {
@@ -9,10 +15,14 @@ pub enum InputCommand {
     Seek { ts: i64 },
 }
 
-#[derive(Debug, Clone)]
+/// What an input backend produces; the session's output loop hands it to the outputs.
+#[derive(Clone)]
 pub enum InputEvent {
-    FrameAvailable,
+    Frame(SharedPixelData),
     State(StreamState),
+    Event(StreamEvent),
+    /// The backend stopped; queued behind its final state, so end frames are presented first.
+    Close,
 }
 
 pub type InputCommandSender = flume::Sender<InputCommand>;
@@ -1,25 +1,32 @@
-use std::sync::{atomic::AtomicU32, Arc};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use gst::prelude::*;
-use gst_app::AppSink;
-use irondash_texture::Texture;
-use log::{debug, error, info, warn};
+use log::{debug, error, info, trace, warn};
 use parking_lot::Mutex;
 
 use crate::{
     core::{
-        session::{VideoSession, VideoSessionCommon},
-        texture::{
-            payload::{self, RawRgbaFrame, SharedPixelData},
-            FlutterTextureSession,
+        input::{
+            factory::{BackendTask, InputBackendFactory},
+            frame_sink,
         },
+        session::{startup::StartupPhase, VideoSession, VideoSessionCommon},
         types::PlaybinConfig,
     },
-    dart_types::{StreamEvent, StreamState},
-    utils::invoke_on_platform_main_thread,
+    dart_types::{ErrorBackend, ErrorCategory, StreamError, StreamEvent, StreamState},
 };
 
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+const STALL_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
 pub struct PlaybinSession {
     session_common: VideoSessionCommon,
     config: PlaybinConfig,
@@ -46,136 +53,216 @@ impl PlaybinSession {
         (session, shutdown_receiver)
     }
 
+    /// Main task: builds and runs the playbin pipeline, rebuilding it with backoff
+    /// when `auto_restart` is enabled, until `max_restart_attempts` restarts in a row
+    /// failed. Frames go to the session's outputs.
     pub async fn execute(
         self: &Arc<Self>,
         mut shutdown_rx: tokio::sync::mpsc::Receiver<()>,
     ) -> anyhow::Result<()> {
-        let payload_holder = Arc::new(payload::PayloadHolder::new());
-        let payload_holder_weak = Arc::downgrade(&payload_holder);
-        let payload_holder_for_texture = Arc::clone(&payload_holder);
-        let engine_handle = self.session_common.engine_handle;
-
-        let (sendable_texture, texture_id) =
-            invoke_on_platform_main_thread(move || -> Result<_> {
-                let texture =
-                    Texture::new_with_provider(engine_handle, payload_holder_for_texture)?;
-                let texture_id = texture.id();
-                info!("Playbin: texture created, id={}", texture_id);
-                Ok((texture.into_sendable_texture(), texture_id))
-            })?;
-
-        let texture_session = Arc::new(crate::core::texture::flutter::TextureSession::new(
-            texture_id,
-            Arc::downgrade(&sendable_texture),
-            payload_holder_weak.clone(),
-        ));
-        let texture_session: Arc<dyn FlutterTextureSession> = texture_session;
-
         self.session_common.send_state_msg(StreamState::Loading);
 
-        // Build appsink for receiving video frames
-        let caps = gst::Caps::builder("video/x-raw")
-            .field("format", "RGBA")
-            .build();
-        let appsink = AppSink::builder().caps(&caps).sync(false).build();
-
-        // Set up appsink callbacks for frame processing
-        let session_weak = Arc::downgrade(self);
-        let session_weak_for_callbacks = session_weak.clone();
-        let size = Arc::new(parking_lot::Mutex::new((0u32, 0u32)));
-        let _frame_count = Arc::new(AtomicU32::new(0));
-
-        appsink.set_callbacks(
-            gst_app::AppSinkCallbacks::builder()
-                .new_sample(move |sink| {
-                    let sample = sink.pull_sample().map_err(|_| gst::FlowError::Eos)?;
-                    let caps = sample.caps().ok_or(gst::FlowError::Error)?;
-                    let video_info =
-                        gst_video::VideoInfo::from_caps(caps).map_err(|_| gst::FlowError::Error)?;
-                    let buffer = sample.buffer().ok_or(gst::FlowError::Error)?;
-
-                    let width = video_info.width();
-                    let height = video_info.height();
-
-                    let mut size_guard = size.lock();
-                    let (cached_width, cached_height) = *size_guard;
-                    // Emit OriginVideoSize only when dimensions change (lock-free comparison)
-                    if cached_width != width || cached_height != height {
-                        *size_guard = (width, height);
-                        log::debug!("Playbin: video dimensions: {}x{}", width, height);
-                        if let Some(session) = session_weak_for_callbacks.upgrade() {
-                            session
-                                .session_common
-                                .send_event_msg(StreamEvent::OriginVideoSize {
-                                    width: width as u64,
-                                    height: height as u64,
-                                });
-                        }
+        let mut backoff = INITIAL_BACKOFF;
+        let mut attempt = 0u32;
+        let mut output: anyhow::Result<()> = Ok(());
+        let mut final_error = None;
+        let mut last_error = None;
+
+        loop {
+            self.session_common.startup.begin_attempt();
+            let last_sample = Arc::new(Mutex::new(Instant::now()));
+            let first_frame = Arc::new(AtomicBool::new(false));
+            match self.setup_pipeline(last_sample.clone(), first_frame.clone()) {
+                Ok(pipeline) => {
+                    let pipeline_arc = Arc::new(pipeline);
+                    *self.active_pipeline.lock() = Some(Arc::clone(&pipeline_arc));
+
+                    let inner_result = self
+                        .run_pipeline_loop(pipeline_arc, last_sample, &mut shutdown_rx)
+                        .await;
+
+                    if let Some(pipeline) = self.active_pipeline.lock().take() {
+                        let _ = pipeline.set_state(gst::State::Null);
+                    }
+
+                    // Only a source that delivered a frame counts as recovered; one that
+                    // builds and then fails right away (404, refused) keeps backing off
+                    if first_frame.load(Ordering::SeqCst) {
+                        backoff = INITIAL_BACKOFF;
+                        attempt = 0;
+                        last_error = None;
                     }
-                    drop(size_guard);
-
-                    let video_frame =
-                        gst_video::VideoFrameRef::from_buffer_ref_readable(buffer, &video_info)
-                            .map_err(|_| gst::FlowError::Error)?;
-
-                    let stride = video_info.stride()[0] as usize;
-                    let expected_stride = (width as usize) * 4; // RGBA
-                    let plane_data = video_frame
-                        .plane_data(0)
-                        .map_err(|_| gst::FlowError::Error)?;
-
-                    let data = if stride == expected_stride {
-                        plane_data.to_vec()
-                    } else {
-                        // Stride mismatch — copy row by row to strip padding
-                        let mut buf = Vec::with_capacity(expected_stride * height as usize);
-                        for y in 0..height as usize {
-                            let row_start = y * stride;
-                            buf.extend_from_slice(
-                                &plane_data[row_start..row_start + expected_stride],
+
+                    match inner_result {
+                        Ok(ExitReason::Shutdown) => {
+                            output = Ok(());
+                            break;
+                        }
+                        Ok(ExitReason::Eos { is_live }) => {
+                            // a finite file ends here; only a live source that ended is lost
+                            if !is_live || !self.config.auto_restart {
+                                break;
+                            }
+                            info!("Playbin: EOS on live source, restarting");
+                        }
+                        Ok(ExitReason::Reconnect) => {}
+                        Err(e) => {
+                            warn!("Playbin: playback failed: {}", e);
+                            let error = StreamError::from_anyhow(
+                                ErrorBackend::Playbin,
+                                ErrorCategory::Internal,
+                                "Playback failed",
+                                &e,
                             );
+                            self.session_common
+                                .send_event_msg(StreamEvent::Error(error.clone()));
+
+                            if !self.config.auto_restart {
+                                debug!("Playbin: auto_restart disabled, stopping");
+                                self.session_common.fail_startup(error.clone());
+                                final_error = Some(error);
+                                output = Err(e);
+                                break;
+                            }
+                            last_error = Some(error);
                         }
-                        buf
-                    };
-
-                    let frame = RawRgbaFrame {
-                        width,
-                        height,
-                        data,
-                    };
-
-                    if let Some(holder) = payload_holder_weak.upgrade() {
-                        holder.set_payload(Arc::new(frame) as SharedPixelData);
-                        texture_session.mark_frame_available();
-                    } else {
-                        warn!("Playbin: payload_holder dropped, frame discarded");
                     }
-                    Ok(gst::FlowSuccess::Ok)
-                })
-                .build(),
-        );
+                }
+                Err(e) => {
+                    error!("Playbin: pipeline setup failed: {}", e);
+                    let error = StreamError::from_anyhow(
+                        ErrorBackend::Playbin,
+                        ErrorCategory::Internal,
+                        "Pipeline setup failed",
+                        &e,
+                    );
+                    self.session_common
+                        .send_event_msg(StreamEvent::Error(error.clone()));
+
+                    if !self.config.auto_restart {
+                        debug!("Playbin: auto_restart disabled, stopping");
+                        self.session_common.fail_startup(error.clone());
+                        final_error = Some(error);
+                        output = Err(e);
+                        break;
+                    }
+                    last_error = Some(error);
+                }
+            }
+
+            // Backoff before retry
+            attempt += 1;
+            if let Some(max_attempts) = self
+                .config
+                .max_restart_attempts
+                .filter(|&max_attempts| attempt > max_attempts)
+            {
+                warn!("Playbin: giving up after {} restart attempts", max_attempts);
+                let error = last_error.take().unwrap_or_else(|| {
+                    StreamError::new(
+                        ErrorBackend::Playbin,
+                        ErrorCategory::Unavailable,
+                        "Source did not recover",
+                    )
+                });
+                self.session_common.fail_startup(error.clone());
+                output = Err(error.clone().into());
+                final_error = Some(error);
+                break;
+            }
+            self.session_common.send_state_msg(StreamState::Loading);
+            self.session_common
+                .send_event_msg(StreamEvent::Reconnecting {
+                    attempt,
+                    delay_ms: backoff.as_millis() as u64,
+                });
+            tokio::select! {
+                _ = tokio::time::sleep(backoff) => {}
+                cmd = shutdown_rx.recv() => {
+                    if cmd.is_some() {
+                        debug!("Playbin: shutdown requested during backoff");
+                        break;
+                    }
+                }
+            }
+            backoff = std::cmp::min(backoff * 2, MAX_BACKOFF);
+        }
+
+        // Send the final state: Error if we gave up because of one, Stopped otherwise
+        self.session_common
+            .send_state_msg(final_error.map_or(StreamState::Stopped, StreamState::Error));
+
+        // Close the outputs regardless of how the loop exited (including error paths);
+        // this is queued behind the final state, so its end frame is presented first.
+        self.session_common.close_outputs();
+
+        output
+    }
+
+    /// Build the playbin3 pipeline with an appsink feeding the session's textures.
+    /// `last_sample` is bumped on every decoded frame for stall detection,
+    /// `first_frame` set once the first one arrived.
+    fn setup_pipeline(
+        self: &Arc<Self>,
+        last_sample: Arc<Mutex<Instant>>,
+        first_frame: Arc<AtomicBool>,
+    ) -> Result<gst::Pipeline> {
+        // Build appsink for receiving video frames
+        let appsink = frame_sink::build_appsink();
+        frame_sink::install(&appsink, Arc::downgrade(self), move || {
+            *last_sample.lock() = Instant::now();
+            first_frame.store(true, Ordering::SeqCst);
+        });
 
         // Build playbin3 pipeline
         let playbin = gst::ElementFactory::make("playbin3")
             .build()
             .context("Failed to create playbin3 element")?;
         playbin.set_property("uri", &self.config.uri);
-        playbin.set_property("video-sink", &appsink);
+        let video_chain = self.session_common.build_video_chain(&appsink)?;
+        playbin.set_property("video-filter", video_chain.filter_bin());
+        playbin.set_property("video-sink", video_chain.bin());
 
         if self.config.mute {
             playbin.set_property("mute", true);
         }
 
-        let pipeline = playbin
-            .downcast::<gst::Pipeline>()
-            .map_err(|_| anyhow::anyhow!("playbin3 is not a pipeline"))?;
-        let pipeline_arc = Arc::new(pipeline);
+        // recordings write the parsed stream in front of the video decoder decodebin3 picks
+        let session = Arc::downgrade(self);
+        playbin
+            .downcast_ref::<gst::Bin>()
+            .ok_or_else(|| anyhow::anyhow!("playbin3 is not a bin"))?
+            .connect_deep_element_added(move |_, _, element| {
+                let is_video_decoder = element.factory().is_some_and(|factory| {
+                    let klass = factory.klass();
+                    klass.contains("Decoder") && klass.contains("Video")
+                });
+                if !is_video_decoder {
+                    return;
+                }
+                if let (Some(session), Some(pad)) = (session.upgrade(), element.static_pad("sink"))
+                {
+                    session.session_common.recording().attach(&pad);
+                    frame_sink::throttle_standby_decode(&pad, Arc::downgrade(&session));
+                }
+            });
 
-        *self.active_pipeline.lock() = Some(Arc::clone(&pipeline_arc));
+        playbin
+            .downcast::<gst::Pipeline>()
+            .map_err(|_| anyhow::anyhow!("playbin3 is not a pipeline"))
+    }
 
+    /// Inner loop - runs while the pipeline is alive.
+    /// Returns ExitReason to indicate why the loop exited.
+    async fn run_pipeline_loop(
+        &self,
+        pipeline: Arc<gst::Pipeline>,
+        last_sample: Arc<Mutex<Instant>>,
+        shutdown_rx: &mut tokio::sync::mpsc::Receiver<()>,
+    ) -> Result<ExitReason> {
         // Set up GStreamer bus monitoring
         let (gst_event_tx, mut gst_event_rx) = tokio::sync::mpsc::channel::<GstBusEvent>(16);
-        let bus = pipeline_arc
+        let bus = pipeline
             .bus()
             .ok_or(anyhow::anyhow!("Failed to get pipeline bus"))?;
         let bus_session_id = self.session_common.session_id;
@@ -183,11 +270,10 @@ impl PlaybinSession {
         bus.set_sync_handler(move |_bus, msg| {
             match msg.view() {
                 gst::MessageView::Error(err) => {
-                    let _ = gst_event_tx.try_send(GstBusEvent::Error(format!(
-                        "GStreamer error [{}]: {}",
-                        bus_session_id,
-                        err.error()
-                    )));
+                    let _ = gst_event_tx.try_send(GstBusEvent::Error {
+                        message: format!("GStreamer error [{}]", bus_session_id),
+                        error: err.error(),
+                    });
                 }
                 gst::MessageView::Eos(_) => {
                     let _ = gst_event_tx.try_send(GstBusEvent::Eos);
@@ -216,84 +302,104 @@ impl PlaybinSession {
             gst::BusSyncReply::Drop
         });
 
-        let state_change = pipeline_arc
+        let state_change = pipeline
             .set_state(gst::State::Playing)
             .context("setting GStreamer pipeline to Playing")?;
         info!("Playbin: set_state(Playing) -> {:?}", state_change);
+        self.session_common.mark_startup(StartupPhase::Connected);
 
-        // Send Playing state with texture_id
-        self.session_common.send_state_msg(StreamState::Playing {
-            texture_id,
-            seekable: true,
-        });
+        // Send Playing state; every consumer gets its own texture id
+        self.session_common.send_playing_msg(true);
+
+        let pipeline_name = pipeline.name().to_string();
+        let stall_timeout = self.config.stall_timeout_ms.map(Duration::from_millis);
+        // Only known once the pipeline reached Playing and answered the latency query.
+        let mut is_live = false;
+        let mut stall_check = tokio::time::interval(STALL_CHECK_INTERVAL);
+        stall_check.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
 
-        // Main event loop
-        let mut loop_result: anyhow::Result<()> = Ok(());
         loop {
             tokio::select! {
                 cmd = shutdown_rx.recv() => {
                     if cmd.is_some() {
                         info!("Playbin: shutdown command received, stopping");
-                        if let Some(pipeline) = self.active_pipeline.lock().take() {
-                            let _ = pipeline.set_state(gst::State::Null);
+                        return Ok(ExitReason::Shutdown);
+                    }
+                }
+                _ = self.session_common.reconnect_requested() => {
+                    info!("Playbin: reconnect requested, rebuilding pipeline");
+                    return Ok(ExitReason::Reconnect);
+                }
+                _ = stall_check.tick() => {
+                    if let Some(timeout) = stall_timeout {
+                        let since_last_sample = last_sample.lock().elapsed();
+                        if since_last_sample > timeout {
+                            return Err(StreamError::new(
+                                ErrorBackend::Playbin,
+                                ErrorCategory::Timeout,
+                                format!("no new sample for {}s", since_last_sample.as_secs()),
+                            )
+                            .into());
                         }
-                        break;
                     }
                 }
                 event = gst_event_rx.recv() => {
                     match event {
-                        Some(GstBusEvent::Error(msg)) => {
-                            error!("Playbin: {}", msg);
-                            self.session_common.send_event_msg(StreamEvent::Error(msg.clone()));
-                            if let Some(pipeline) = self.active_pipeline.lock().take() {
-                                let _ = pipeline.set_state(gst::State::Null);
-                            }
-                            loop_result = Err(anyhow::anyhow!(msg));
-                            break;
+                        Some(GstBusEvent::Error { message, error }) => {
+                            error!("Playbin: {}: {}", message, error);
+                            return Err(anyhow::Error::new(error).context(message));
                         }
                         Some(GstBusEvent::Warning(msg)) => {
                             warn!("Playbin: {}", msg);
                         }
                         Some(GstBusEvent::Eos) => {
                             info!("Playbin: EOS received");
-                            if let Some(pipeline) = self.active_pipeline.lock().take() {
-                                let _ = pipeline.set_state(gst::State::Null);
-                            }
-                            self.session_common.send_state_msg(StreamState::Stopped);
-                            break;
+                            return Ok(ExitReason::Eos { is_live });
                         }
                         Some(GstBusEvent::Buffering(percent)) => {
                             debug!("Playbin: buffering {}%", percent);
                         }
                         Some(GstBusEvent::StateChanged { src, old, new }) => {
                             debug!("Playbin: [{}] state {:?} -> {:?}", src, old, new);
+                            if src == pipeline_name && new == gst::State::Paused {
+                                self.session_common.mark_startup(StartupPhase::Preroll);
+                            }
+                            if src == pipeline_name && new == gst::State::Playing {
+                                let mut query = gst::query::Latency::new();
+                                if pipeline.query(&mut query) {
+                                    is_live = query.result().0;
+                                    debug!("Playbin: source is_live={}", is_live);
+                                }
+                                // Don't count the preroll time against the stall timeout
+                                *last_sample.lock() = Instant::now();
+                            }
                         }
                         None => {
-                            warn!("Playbin: bus event channel closed unexpectedly");
-                            break;
+                            bail!("bus event channel closed unexpectedly");
                         }
                     }
                 }
             }
         }
-
-        // Send Stopped state
-        let _ = self.session_common.send_state_msg(StreamState::Stopped);
-
-        // Texture + payload_holder must always be dropped on the platform main thread,
-        // regardless of how the loop exited (including error paths).
-        crate::utils::invoke_on_platform_main_thread(move || {
-            drop(sendable_texture);
-            drop(payload_holder);
-        });
-
-        loop_result
     }
 }
 
+/// Reason why the pipeline loop exited
+enum ExitReason {
+    /// Intentional shutdown via terminate()
+    Shutdown,
+    /// The source reached end of stream; only a live one is restarted
+    Eos { is_live: bool },
+    /// `VideoSessionCommon::request_reconnect` was called, e.g. by the stall watchdog
+    Reconnect,
+}
+
 #[derive(Debug, Clone)]
 enum GstBusEvent {
-    Error(String),
+    Error {
+        message: String,
+        error: gst::glib::Error,
+    },
     Warning(String),
     Eos,
     Buffering(i32),
@@ -304,6 +410,26 @@ enum GstBusEvent {
     },
 }
 
+/// Creates `VideoConfig::Playbin` sessions, registered as `factory::PLAYBIN_KIND`.
+pub struct PlaybinFactory;
+
+impl InputBackendFactory for PlaybinFactory {
+    type Config = PlaybinConfig;
+
+    fn create(
+        &self,
+        config: PlaybinConfig,
+        session_common: VideoSessionCommon,
+    ) -> Result<BackendTask> {
+        trace!("  uri: {}", config.uri);
+        let (session, shutdown_rx) = PlaybinSession::new(config, session_common);
+        Ok(BackendTask {
+            session: session.clone(),
+            run: Box::pin(async move { session.execute(shutdown_rx).await }),
+        })
+    }
+}
+
 #[async_trait::async_trait]
 impl VideoSession for PlaybinSession {
     fn session_id(&self) -> i64 {
@@ -314,19 +440,16 @@ impl VideoSession for PlaybinSession {
         self.session_common.engine_handle
     }
 
-    fn last_alive_mark(&self) -> std::time::SystemTime {
-        self.session_common.get_last_alive_mark()
-    }
-
-    fn make_alive(&self) {
-        self.session_common.mark_alive();
+    fn session_common(&self) -> &VideoSessionCommon {
+        &self.session_common
     }
 
     fn terminate(&self) {
         if let Some(pipeline) = self.active_pipeline.lock().take() {
             let _ = pipeline.set_state(gst::State::Null);
         }
-        let _ = self.shutdown_sender.blocking_send(());
+        // A full channel means a shutdown is already pending, a closed one that the task ended.
+        let _ = self.shutdown_sender.try_send(());
     }
 
     async fn seek(&self, ts_ms: u64) -> anyhow::Result<()> {
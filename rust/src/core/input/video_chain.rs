@@ -0,0 +1,843 @@
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use gst::prelude::*;
+use gst_app::AppSink;
+use log::debug;
+use parking_lot::Mutex;
+
+use crate::core::{
+    output::frame_tap::FrameTap,
+    types::{
+        DeinterlaceMethod, DeinterlaceMode, ImageAdjustments, OutputSize, Rotation, ScaleMode,
+        VideoTransform,
+    },
+};
+
+/// Per-session settings of the video chain. They outlive pipeline rebuilds,
+/// reconnects and `switch_source`, and are applied to each new chain.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VideoChainSettings {
+    pub output_size: Option<OutputSize>,
+    pub transform: VideoTransform,
+    pub adjustments: ImageAdjustments,
+    /// Frames above this rate are dropped before cropping, scaling and format conversion.
+    pub max_frame_rate: Option<u32>,
+}
+
+/// Decoded and transformed format of the frames going through a chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChainFormat {
+    pub source: (u32, u32),
+    /// After crop, zoom and rotation; output scaling keeps its aspect ratio for `Fit`/`Fill`.
+    pub transformed: (u32, u32),
+    /// Pixel aspect ratio of the transformed frames, as a fraction.
+    pub pixel_aspect_ratio: (i32, i32),
+    /// As a fraction; `(0, 1)` when the source has a variable frame rate.
+    pub frame_rate: (i32, i32),
+}
+
+impl ChainFormat {
+    /// The format of frames nothing was applied to, e.g. without a chain.
+    pub fn from_video_info(video_info: &gst_video::VideoInfo) -> Self {
+        let size = (video_info.width(), video_info.height());
+        Self {
+            source: size,
+            transformed: size,
+            pixel_aspect_ratio: fraction(video_info.par()),
+            frame_rate: fraction(video_info.fps()),
+        }
+    }
+
+    pub fn pixel_aspect_ratio(&self) -> f64 {
+        let (numer, denom) = self.pixel_aspect_ratio;
+        if numer <= 0 || denom <= 0 {
+            1.0
+        } else {
+            numer as f64 / denom as f64
+        }
+    }
+
+    /// Width over height of the transformed frames as they should be displayed;
+    /// differs from the pixel ratio for anamorphic sources.
+    pub fn display_aspect_ratio(&self) -> f64 {
+        let (width, height) = self.transformed;
+        if height == 0 {
+            return 0.0;
+        }
+        width as f64 * self.pixel_aspect_ratio() / height as f64
+    }
+
+    pub fn frame_rate(&self) -> f64 {
+        let (numer, denom) = self.frame_rate;
+        if denom <= 0 {
+            0.0
+        } else {
+            numer as f64 / denom as f64
+        }
+    }
+}
+
+fn fraction(fraction: gst::Fraction) -> (i32, i32) {
+    (fraction.numer(), fraction.denom())
+}
+
+/// The decode-side processing shared by all backends, in two bins:
+/// the filter `deinterlace ! videobalance ! gamma` and the tail
+/// `tee ! videorate ! videocrop ! videoflip ! videoscale ! capsfilter ! videoconvert ! appsink`.
+/// Cropping and scaling happen before the format conversion, so a zoomed or downscaled
+/// session converts fewer pixels. Frame taps branch off at the `tee`, before the session's
+/// frame-rate cap, through `queue ! valve ! videocrop ! videoflip ! fakesink` applying the
+/// same rotation, crop and zoom; each tap then limits its own rate.
+/// Settings can be changed while the pipeline runs; the chain renegotiates without a restart.
+pub struct VideoChain {
+    filter_bin: gst::Bin,
+    deinterlace: gst::Element,
+    balance: gst::Element,
+    gamma: gst::Element,
+    bin: gst::Bin,
+    rate: gst::Element,
+    crop: gst::Element,
+    flip: gst::Element,
+    size_filter: gst::Element,
+    /// Closed while no tap is subscribed, so the tap branch costs nothing.
+    tap_valve: gst::Element,
+    tap_crop: gst::Element,
+    tap_flip: gst::Element,
+    state: Mutex<ChainState>,
+    frame_taps: Mutex<Vec<Arc<FrameTap>>>,
+}
+
+struct ChainState {
+    settings: VideoChainSettings,
+    /// Decoded format as negotiated upstream of the chain.
+    source: Option<ChainFormat>,
+    format: Option<ChainFormat>,
+    flip_direction: Option<&'static str>,
+}
+
+impl VideoChain {
+    /// Wraps `appsink` in the tail bin, which has a `sink` ghost pad.
+    /// The filter bin has `sink` and `src` ghost pads; backends link
+    /// `decoder ! filter_bin ! bin` (playbin takes them as `video-filter` and `video-sink`).
+    pub fn new(appsink: &AppSink, settings: VideoChainSettings) -> Result<Arc<Self>> {
+        let make = |factory: &str| {
+            gst::ElementFactory::make(factory)
+                .build()
+                .with_context(|| format!("Failed to create {} element", factory))
+        };
+
+        let filter_bin = gst::Bin::builder().name("video_filter").build();
+        let deinterlace = make("deinterlace")?;
+        let balance = make("videobalance")?;
+        let gamma = make("gamma")?;
+        let filters = [&deinterlace, &balance, &gamma];
+        filter_bin.add_many(filters)?;
+        gst::Element::link_many(filters)?;
+        for (element, pad_name) in [(&deinterlace, "sink"), (&gamma, "src")] {
+            let pad = element
+                .static_pad(pad_name)
+                .ok_or_else(|| anyhow::anyhow!("{} has no {} pad", element.name(), pad_name))?;
+            let ghost = gst::GhostPad::builder_with_target(&pad)?
+                .name(pad_name)
+                .build();
+            filter_bin.add_pad(&ghost)?;
+        }
+
+        let bin = gst::Bin::builder().name("video_chain").build();
+        let split = make("tee")?;
+        let rate = make("videorate")?;
+        // only drop frames to honor `max-rate`, never duplicate them
+        rate.set_property("drop-only", true);
+        let crop = make("videocrop")?;
+        let flip = make("videoflip")?;
+        let scale = make("videoscale")?;
+        let size_filter = make("capsfilter")?;
+        let convert = make("videoconvert")?;
+
+        let elements = [
+            &split,
+            &rate,
+            &crop,
+            &flip,
+            &scale,
+            &size_filter,
+            &convert,
+            appsink.upcast_ref(),
+        ];
+        bin.add_many(elements)?;
+        gst::Element::link_many(elements)?;
+
+        let tap_queue = make("queue")?;
+        // a slow tap branch drops frames instead of holding up the texture
+        tap_queue.set_property_from_str("leaky", "downstream");
+        tap_queue.set_property("max-size-buffers", 1u32);
+        tap_queue.set_property("max-size-bytes", 0u32);
+        tap_queue.set_property("max-size-time", 0u64);
+        let tap_valve = make("valve")?;
+        tap_valve.set_property("drop", true);
+        let tap_crop = make("videocrop")?;
+        let tap_flip = make("videoflip")?;
+        let tap_sink = make("fakesink")?;
+        tap_sink.set_property("sync", false);
+        // it gets nothing to preroll with while the valve is closed
+        tap_sink.set_property("async", false);
+        let tap_elements = [&tap_queue, &tap_valve, &tap_crop, &tap_flip, &tap_sink];
+        bin.add_many(tap_elements)?;
+        gst::Element::link_many(tap_elements)?;
+        split.link(&tap_queue)?;
+
+        let crop_sink = crop
+            .static_pad("sink")
+            .ok_or_else(|| anyhow::anyhow!("videocrop has no sink pad"))?;
+        let tap_flip_src = tap_flip
+            .static_pad("src")
+            .ok_or_else(|| anyhow::anyhow!("videoflip has no src pad"))?;
+        let split_sink = split
+            .static_pad("sink")
+            .ok_or_else(|| anyhow::anyhow!("tee has no sink pad"))?;
+        let ghost = gst::GhostPad::builder_with_target(&split_sink)?
+            .name("sink")
+            .build();
+        bin.add_pad(&ghost)?;
+
+        let chain = Arc::new(Self {
+            filter_bin,
+            deinterlace,
+            balance,
+            gamma,
+            bin,
+            rate,
+            crop,
+            flip,
+            size_filter,
+            tap_valve,
+            tap_crop,
+            tap_flip,
+            state: Mutex::new(ChainState {
+                settings,
+                source: None,
+                format: None,
+                flip_direction: None,
+            }),
+            frame_taps: Mutex::new(Vec::new()),
+        });
+        chain.apply();
+
+        // track the decoded format; the chain is configured before it negotiates, and the
+        // decoder sends new caps whenever the source changes resolution mid-stream
+        let weak = Arc::downgrade(&chain);
+        crop_sink.add_probe(gst::PadProbeType::EVENT_DOWNSTREAM, move |_, info| {
+            if let Some(gst::PadProbeData::Event(event)) = &info.data {
+                if let gst::EventView::Caps(caps) = event.view() {
+                    if let (Some(chain), Ok(video_info)) =
+                        (weak.upgrade(), gst_video::VideoInfo::from_caps(caps.caps()))
+                    {
+                        chain.set_source(ChainFormat::from_video_info(&video_info));
+                    }
+                }
+            }
+            gst::PadProbeReturn::Ok
+        });
+
+        let weak = Arc::downgrade(&chain);
+        tap_flip_src.add_probe(gst::PadProbeType::BUFFER, move |pad, info| {
+            if let (Some(chain), Some(gst::PadProbeData::Buffer(buffer))) =
+                (weak.upgrade(), &info.data)
+            {
+                let taps = chain.frame_taps.lock();
+                if !taps.is_empty() {
+                    let caps = pad.current_caps();
+                    for tap in taps.iter() {
+                        tap.offer(caps.as_ref(), buffer);
+                    }
+                }
+            }
+            gst::PadProbeReturn::Ok
+        });
+        Ok(chain)
+    }
+
+    pub fn filter_bin(&self) -> &gst::Bin {
+        &self.filter_bin
+    }
+
+    pub fn bin(&self) -> &gst::Bin {
+        &self.bin
+    }
+
+    /// Resolves with the next decoded frame entering the tail, before frame-rate cap,
+    /// crop, scaling and format conversion.
+    pub fn capture_next_frame(&self) -> Result<tokio::sync::oneshot::Receiver<gst::Sample>> {
+        let pad = self
+            .bin
+            .static_pad("sink")
+            .ok_or_else(|| anyhow::anyhow!("video chain has no sink pad"))?;
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        let tx = Mutex::new(Some(tx));
+        pad.add_probe(gst::PadProbeType::BUFFER, move |pad, info| {
+            let Some(gst::PadProbeData::Buffer(buffer)) = &info.data else {
+                return gst::PadProbeReturn::Ok;
+            };
+            if let Some(tx) = tx.lock().take() {
+                let caps = pad.current_caps();
+                let mut sample = gst::Sample::builder().buffer(buffer);
+                if let Some(caps) = &caps {
+                    sample = sample.caps(caps);
+                }
+                let _ = tx.send(sample.build());
+            }
+            gst::PadProbeReturn::Remove
+        });
+        Ok(rx)
+    }
+
+    /// `None` until the decoder negotiated its output.
+    pub fn format(&self) -> Option<ChainFormat> {
+        self.state.lock().format
+    }
+
+    pub fn set_frame_taps(&self, taps: Vec<Arc<FrameTap>>) {
+        self.tap_valve.set_property("drop", taps.is_empty());
+        let previous = std::mem::replace(&mut *self.frame_taps.lock(), taps);
+        // a removed tap stops its pipeline when dropped; not while the probe waits on the lock
+        drop(previous);
+    }
+
+    pub fn update(&self, settings: VideoChainSettings) {
+        self.state.lock().settings = settings;
+        self.apply();
+    }
+
+    fn set_source(&self, source: ChainFormat) {
+        {
+            let mut state = self.state.lock();
+            if state.source == Some(source) {
+                return;
+            }
+            debug!("video chain: source format {:?}", source);
+            state.source = Some(source);
+        }
+        self.apply();
+    }
+
+    fn apply(&self) {
+        let (margins, tap_margins, direction, caps, adjustments, max_frame_rate) = {
+            let mut state = self.state.lock();
+            let transform = &state.settings.transform;
+            let rotated = matches!(
+                transform.rotation,
+                Rotation::Clockwise90 | Rotation::Clockwise270
+            );
+            // taps scale on their own, so they only get the transform's crop and zoom
+            let tap_margins = state
+                .source
+                .map(|source| crop_margins(source.source, transform));
+            let margins = state.source.map(|source| {
+                let margins = crop_margins(source.source, transform);
+                // `Fill` cuts the overflow here, so the frames are exactly the requested box
+                let fill = state.settings.output_size.as_ref().and_then(|output_size| {
+                    let output_size = if rotated {
+                        OutputSize {
+                            width: output_size.height,
+                            height: output_size.width,
+                            ..output_size.clone()
+                        }
+                    } else {
+                        output_size.clone()
+                    };
+                    fill_margins(margins.size(source.source), &output_size)
+                });
+                match fill {
+                    Some(fill) => margins.inset(fill),
+                    None => margins,
+                }
+            });
+            let format = state.source.zip(margins).map(|(source, margins)| {
+                let cropped = (
+                    source.source.0 - margins.left - margins.right,
+                    source.source.1 - margins.top - margins.bottom,
+                );
+                let (par_numer, par_denom) = source.pixel_aspect_ratio;
+                let (transformed, pixel_aspect_ratio) = if rotated {
+                    ((cropped.1, cropped.0), (par_denom, par_numer))
+                } else {
+                    (cropped, source.pixel_aspect_ratio)
+                };
+                ChainFormat {
+                    transformed,
+                    pixel_aspect_ratio,
+                    ..source
+                }
+            });
+
+            let mut caps = gst::Caps::builder("video/x-raw");
+            if let Some(output_size) = &state.settings.output_size {
+                let transformed = format.map(|format| format.transformed);
+                if let Some((width, height)) = scaled_size(transformed, output_size) {
+                    caps = caps
+                        .field("width", width as i32)
+                        .field("height", height as i32);
+                }
+            }
+
+            // each property change makes the element renegotiate, so only touch what changed
+            let direction = video_direction(transform);
+            let direction = (state.flip_direction != Some(direction)).then_some(direction);
+            state.format = format;
+            if direction.is_some() {
+                state.flip_direction = direction;
+            }
+            (
+                margins,
+                tap_margins,
+                direction,
+                caps.build(),
+                state.settings.adjustments.clone(),
+                state.settings.max_frame_rate,
+            )
+        };
+
+        self.apply_adjustments(&adjustments);
+        let max_rate = max_frame_rate.map_or(i32::MAX, |rate| rate.max(1) as i32);
+        if self.rate.property::<i32>("max-rate") != max_rate {
+            debug!("video chain: max frame rate {}", max_rate);
+            self.rate.set_property("max-rate", max_rate);
+        }
+
+        if let Some(margins) = margins {
+            margins.apply_to(&self.crop);
+        }
+        if let Some(margins) = tap_margins {
+            margins.apply_to(&self.tap_crop);
+        }
+        if let Some(direction) = direction {
+            debug!("video chain: video direction {}", direction);
+            for flip in [&self.flip, &self.tap_flip] {
+                flip.set_property_from_str("video-direction", direction);
+            }
+        }
+        if self
+            .size_filter
+            .property::<Option<gst::Caps>>("caps")
+            .as_ref()
+            != Some(&caps)
+        {
+            debug!("video chain: output caps {}", caps);
+            // capsfilter asks upstream to renegotiate when its caps change
+            self.size_filter.set_property("caps", &caps);
+        }
+    }
+
+    fn apply_adjustments(&self, adjustments: &ImageAdjustments) {
+        let mode = match adjustments.deinterlace {
+            DeinterlaceMode::Auto => "auto",
+            DeinterlaceMode::On => "interlaced",
+            DeinterlaceMode::Off => "disabled",
+        };
+        let method = match adjustments.deinterlace_method {
+            DeinterlaceMethod::Linear => "linear",
+            DeinterlaceMethod::GreedyHigh => "greedyh",
+            DeinterlaceMethod::GreedyLow => "greedyl",
+            DeinterlaceMethod::Vfir => "vfir",
+            DeinterlaceMethod::ScalerBob => "scalerbob",
+            DeinterlaceMethod::Yadif => "yadif",
+        };
+        set_enum_if_changed(&self.deinterlace, "mode", mode);
+        set_enum_if_changed(&self.deinterlace, "method", method);
+
+        // the elements switch to passthrough at their default values
+        for (element, property, value) in [
+            (
+                &self.balance,
+                "brightness",
+                adjustments.brightness.clamp(-1.0, 1.0),
+            ),
+            (
+                &self.balance,
+                "contrast",
+                adjustments.contrast.clamp(0.0, 2.0),
+            ),
+            (
+                &self.balance,
+                "saturation",
+                adjustments.saturation.clamp(0.0, 2.0),
+            ),
+            (&self.balance, "hue", adjustments.hue.clamp(-1.0, 1.0)),
+            (&self.gamma, "gamma", adjustments.gamma.clamp(0.01, 10.0)),
+        ] {
+            if element.property::<f64>(property) != value {
+                element.set_property(property, value);
+            }
+        }
+    }
+}
+
+fn set_enum_if_changed(element: &gst::Element, property: &str, nick: &str) {
+    let current = element.property_value(property);
+    let unchanged =
+        gst::glib::EnumValue::from_value(&current).is_some_and(|(_, value)| value.nick() == nick);
+    if !unchanged {
+        debug!("video chain: {} {} = {}", element.name(), property, nick);
+        element.set_property_from_str(property, nick);
+    }
+}
+
+/// Pixels cut from each side of the decoded frame.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct CropMargins {
+    left: u32,
+    right: u32,
+    top: u32,
+    bottom: u32,
+}
+
+impl CropMargins {
+    /// Size of a `size` frame after cutting the margins.
+    pub(crate) fn size(&self, size: (u32, u32)) -> (u32, u32) {
+        (
+            size.0.saturating_sub(self.left + self.right),
+            size.1.saturating_sub(self.top + self.bottom),
+        )
+    }
+
+    /// These margins plus `inner`, which is relative to the cropped frame.
+    fn inset(self, inner: CropMargins) -> CropMargins {
+        CropMargins {
+            left: self.left + inner.left,
+            right: self.right + inner.right,
+            top: self.top + inner.top,
+            bottom: self.bottom + inner.bottom,
+        }
+    }
+
+    /// Set the margins on a `videocrop`; unchanged ones are left alone, since every
+    /// change makes it renegotiate.
+    pub(crate) fn apply_to(&self, crop: &gst::Element) {
+        for (property, value) in [
+            ("left", self.left),
+            ("right", self.right),
+            ("top", self.top),
+            ("bottom", self.bottom),
+        ] {
+            if crop.property::<i32>(property) != value as i32 {
+                crop.set_property(property, value as i32);
+            }
+        }
+    }
+}
+
+/// For `ScaleMode::Fill`: margins cutting a `size` frame around its center to the aspect
+/// ratio of the box, so that scaling it to the box neither stretches nor overflows.
+/// `None` for the other modes, an empty box or a frame that already has the box's ratio.
+pub(crate) fn fill_margins(size: (u32, u32), output_size: &OutputSize) -> Option<CropMargins> {
+    if output_size.mode != ScaleMode::Fill
+        || output_size.width == 0
+        || output_size.height == 0
+        || size.0 == 0
+        || size.1 == 0
+    {
+        return None;
+    }
+    let (width, height) = (size.0 as u64, size.1 as u64);
+    let (box_width, box_height) = (output_size.width as u64, output_size.height as u64);
+    let even = |value: u64, max: u32| ((value as u32) & !1).clamp(2.min(max), max);
+    let margins = if width * box_height > height * box_width {
+        // wider than the box: cut left and right
+        let kept = even(height * box_width / box_height, size.0);
+        let left = ((size.0 - kept) / 2) & !1;
+        CropMargins {
+            left,
+            right: size.0 - kept - left,
+            ..Default::default()
+        }
+    } else {
+        let kept = even(width * box_height / box_width, size.1);
+        let top = ((size.1 - kept) / 2) & !1;
+        CropMargins {
+            top,
+            bottom: size.1 - kept - top,
+            ..Default::default()
+        }
+    };
+    (margins != CropMargins::default()).then_some(margins)
+}
+
+/// Margins for the crop rectangle and the zoomed region inside it, aligned to even pixels.
+fn crop_margins(source: (u32, u32), transform: &VideoTransform) -> CropMargins {
+    let (source_width, source_height) = (source.0 as f64, source.1 as f64);
+    let (mut x, mut y, mut width, mut height) = match &transform.crop {
+        Some(crop) => {
+            let x = crop.x.clamp(0.0, 1.0) * source_width;
+            let y = crop.y.clamp(0.0, 1.0) * source_height;
+            (
+                x,
+                y,
+                (crop.width.clamp(0.0, 1.0) * source_width).min(source_width - x),
+                (crop.height.clamp(0.0, 1.0) * source_height).min(source_height - y),
+            )
+        }
+        None => (0.0, 0.0, source_width, source_height),
+    };
+    if transform.zoom > 1.0 {
+        let zoomed_width = width / transform.zoom;
+        let zoomed_height = height / transform.zoom;
+        let center_x = x + transform.pan_x.clamp(0.0, 1.0) * width;
+        let center_y = y + transform.pan_y.clamp(0.0, 1.0) * height;
+        x = (center_x - zoomed_width / 2.0).clamp(x, x + width - zoomed_width);
+        y = (center_y - zoomed_height / 2.0).clamp(y, y + height - zoomed_height);
+        width = zoomed_width;
+        height = zoomed_height;
+    }
+
+    let even = |value: f64| (value.round() as u32) & !1;
+    let left = even(x).min(source.0.saturating_sub(2));
+    let top = even(y).min(source.1.saturating_sub(2));
+    let width = even(width).clamp(2, (source.0 - left).max(2));
+    let height = even(height).clamp(2, (source.1 - top).max(2));
+    CropMargins {
+        left,
+        right: source.0.saturating_sub(left + width),
+        top,
+        bottom: source.1.saturating_sub(top + height),
+    }
+}
+
+/// `videoflip` direction for flipping first and rotating clockwise after.
+fn video_direction(transform: &VideoTransform) -> &'static str {
+    let quarter_turns = match transform.rotation {
+        Rotation::None => 0,
+        Rotation::Clockwise90 => 1,
+        Rotation::Clockwise180 => 2,
+        Rotation::Clockwise270 => 3,
+    };
+    // a vertical flip is a horizontal flip plus half a turn
+    let (flip, quarter_turns) = match (transform.flip_horizontal, transform.flip_vertical) {
+        (false, false) => (false, quarter_turns),
+        (true, false) => (true, quarter_turns),
+        (false, true) => (true, quarter_turns + 2),
+        (true, true) => (false, quarter_turns + 2),
+    };
+    let directions = if flip {
+        ["horiz", "ur-ll", "vert", "ul-lr"]
+    } else {
+        ["identity", "90r", "180", "90l"]
+    };
+    directions[quarter_turns % 4]
+}
+
+/// Size the scaler outputs for `output_size`, or `None` to keep the transformed size.
+/// `Fit` and `Fill` keep the aspect ratio and never upscale; `Exact` stretches.
+/// `Fill` expects the frames already cut with `fill_margins` and scales them to the box.
+pub(crate) fn scaled_size(
+    transformed_size: Option<(u32, u32)>,
+    output_size: &OutputSize,
+) -> Option<(u32, u32)> {
+    if output_size.width == 0 || output_size.height == 0 {
+        return None;
+    }
+    let (source_width, source_height) = match output_size.mode {
+        ScaleMode::Exact => return Some((output_size.width, output_size.height)),
+        ScaleMode::Fit | ScaleMode::Fill => transformed_size?,
+    };
+    let width_ratio = output_size.width as f64 / source_width as f64;
+    let height_ratio = output_size.height as f64 / source_height as f64;
+    let ratio = if output_size.mode == ScaleMode::Fit {
+        width_ratio.min(height_ratio)
+    } else {
+        width_ratio.max(height_ratio)
+    };
+    if ratio >= 1.0 {
+        return None;
+    }
+    // even dimensions keep subsampled formats happy before the format conversion
+    let even = |value: f64| ((value.round() as u32) & !1).max(2);
+    if output_size.mode == ScaleMode::Fill {
+        return Some((
+            even(output_size.width as f64),
+            even(output_size.height as f64),
+        ));
+    }
+    Some((
+        even(source_width as f64 * ratio),
+        even(source_height as f64 * ratio),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::CropRect;
+
+    fn output_size(width: u32, height: u32, mode: ScaleMode) -> OutputSize {
+        OutputSize {
+            width,
+            height,
+            mode,
+        }
+    }
+
+    fn margins(left: u32, right: u32, top: u32, bottom: u32) -> CropMargins {
+        CropMargins {
+            left,
+            right,
+            top,
+            bottom,
+        }
+    }
+
+    #[test]
+    fn crop_margins_keep_the_frame_by_default() {
+        let margins = crop_margins((1920, 1080), &VideoTransform::default());
+        assert_eq!(margins, CropMargins::default());
+    }
+
+    #[test]
+    fn crop_margins_cut_the_crop_rect() {
+        let transform = VideoTransform {
+            crop: Some(CropRect {
+                x: 0.25,
+                y: 0.25,
+                width: 0.5,
+                height: 0.5,
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            crop_margins((1920, 1080), &transform),
+            margins(480, 480, 270, 270)
+        );
+    }
+
+    #[test]
+    fn crop_margins_zoom_around_the_pan_center() {
+        let centered = VideoTransform {
+            zoom: 2.0,
+            ..Default::default()
+        };
+        assert_eq!(
+            crop_margins((1920, 1080), &centered),
+            margins(480, 480, 270, 270)
+        );
+
+        // a pan beyond the edge keeps the zoomed region inside the frame
+        let top_left = VideoTransform {
+            zoom: 2.0,
+            pan_x: 0.0,
+            pan_y: 0.0,
+            ..Default::default()
+        };
+        assert_eq!(
+            crop_margins((1920, 1080), &top_left),
+            margins(0, 960, 0, 540)
+        );
+    }
+
+    #[test]
+    fn crop_margins_are_even() {
+        let transform = VideoTransform {
+            crop: Some(CropRect {
+                x: 0.1,
+                y: 0.1,
+                width: 0.33,
+                height: 0.33,
+            }),
+            ..Default::default()
+        };
+        let cut = crop_margins((1001, 701), &transform);
+        assert_eq!(cut.left % 2, 0);
+        assert_eq!(cut.top % 2, 0);
+        let (width, height) = cut.size((1001, 701));
+        assert_eq!(width % 2, 0);
+        assert_eq!(height % 2, 0);
+    }
+
+    #[test]
+    fn video_direction_flips_before_rotating() {
+        let direction = |rotation, flip_horizontal, flip_vertical| {
+            video_direction(&VideoTransform {
+                rotation,
+                flip_horizontal,
+                flip_vertical,
+                ..Default::default()
+            })
+        };
+        assert_eq!(direction(Rotation::None, false, false), "identity");
+        assert_eq!(direction(Rotation::Clockwise90, false, false), "90r");
+        assert_eq!(direction(Rotation::Clockwise180, false, false), "180");
+        assert_eq!(direction(Rotation::Clockwise270, false, false), "90l");
+        assert_eq!(direction(Rotation::None, true, false), "horiz");
+        assert_eq!(direction(Rotation::None, false, true), "vert");
+        assert_eq!(direction(Rotation::None, true, true), "180");
+        assert_eq!(direction(Rotation::Clockwise90, true, false), "ur-ll");
+        assert_eq!(direction(Rotation::Clockwise270, true, false), "ul-lr");
+    }
+
+    #[test]
+    fn scaled_size_fits_without_upscaling() {
+        let fit = output_size(960, 960, ScaleMode::Fit);
+        assert_eq!(scaled_size(Some((1920, 1080)), &fit), Some((960, 540)));
+        assert_eq!(scaled_size(Some((640, 360)), &fit), None);
+        assert_eq!(scaled_size(None, &fit), None);
+    }
+
+    #[test]
+    fn scaled_size_exact_stretches() {
+        let exact = output_size(300, 100, ScaleMode::Exact);
+        assert_eq!(scaled_size(None, &exact), Some((300, 100)));
+        assert_eq!(scaled_size(Some((64, 64)), &exact), Some((300, 100)));
+    }
+
+    #[test]
+    fn scaled_size_ignores_an_empty_box() {
+        for mode in [ScaleMode::Fit, ScaleMode::Fill, ScaleMode::Exact] {
+            assert_eq!(
+                scaled_size(Some((1920, 1080)), &output_size(0, 100, mode)),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn fill_is_cut_and_scaled_to_the_box() {
+        let fill = output_size(960, 960, ScaleMode::Fill);
+        let cut = fill_margins((1920, 1080), &fill).expect("wider than the box");
+        assert_eq!(cut, margins(420, 420, 0, 0));
+        let cropped = cut.size((1920, 1080));
+        assert_eq!(cropped, (1080, 1080));
+        assert_eq!(scaled_size(Some(cropped), &fill), Some((960, 960)));
+    }
+
+    #[test]
+    fn fill_cuts_tall_frames_at_top_and_bottom() {
+        let fill = output_size(1600, 900, ScaleMode::Fill);
+        let cut = fill_margins((1080, 1920), &fill).expect("taller than the box");
+        assert_eq!((cut.left, cut.right), (0, 0));
+        assert_eq!(cut.size((1080, 1920)), (1080, 606));
+        assert!(cut.top.abs_diff(cut.bottom) <= 2);
+    }
+
+    #[test]
+    fn fill_margins_only_apply_to_fill() {
+        assert_eq!(
+            fill_margins((1920, 1080), &output_size(960, 540, ScaleMode::Fill)),
+            None
+        );
+        assert_eq!(
+            fill_margins((1920, 1080), &output_size(960, 960, ScaleMode::Fit)),
+            None
+        );
+        assert_eq!(
+            fill_margins((1920, 1080), &output_size(960, 960, ScaleMode::Exact)),
+            None
+        );
+    }
+
+    #[test]
+    fn inset_adds_inner_margins() {
+        let outer = margins(10, 20, 30, 40);
+        let inner = margins(1, 2, 3, 4);
+        assert_eq!(outer.inset(inner), margins(11, 22, 33, 44));
+        assert_eq!(outer.inset(inner).size((100, 100)), (67, 23));
+    }
+}
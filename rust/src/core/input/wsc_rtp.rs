@@ -1,6 +1,6 @@
 use std::{
     net::{SocketAddr, ToSocketAddrs},
-    sync::{Arc, Weak},
+    sync::Arc,
     time::Duration,
 };
 
@@ -12,7 +12,6 @@ use futures_util::{
 };
 use gst::prelude::*;
 use gst_app::AppSrc;
-use irondash_texture::Texture;
 use log::{error, warn};
 use parking_lot::{Mutex, RwLock};
 use tokio::net::{TcpStream, UdpSocket};
@@ -21,15 +20,15 @@ use url::Url;
 
 use crate::{
     core::{
-        session::VideoSessionCommon,
-        texture::{
-            payload::{self, RawRgbaFrame, SharedPixelData},
-            FlutterTextureSession,
+        input::{
+            factory::{BackendTask, InputBackendFactory},
+            frame_sink,
         },
+        session::{startup::StartupPhase, VideoSessionCommon},
         types::WscRtpSessionConfig,
+        HTTP_CLIENT,
     },
-    dart_types::{StreamEvent, StreamState, WscRtpMode},
-    utils::invoke_on_platform_main_thread,
+    dart_types::{ErrorBackend, ErrorCategory, StreamError, StreamEvent, StreamState, WscRtpMode},
 };
 
 use media_server_api_models::{
@@ -111,6 +110,7 @@ impl WscRtpSession {
             .await
             .context(format!("connecting to WSC-RTP ws at {}", wsc_rtp_url))?;
         let (ws_sink, mut ws_stream) = ws.split();
+        self.session_common.mark_startup(StartupPhase::Connected);
 
         let deadline = tokio::time::Instant::now() + SDP_TIMEOUT;
         let mut init_message = None;
@@ -161,6 +161,8 @@ impl WscRtpSession {
             udp_sock_maybe = Some(udp_sock);
         }
 
+        self.session_common.mark_startup(StartupPhase::Handshake);
+
         let (encoding, pt, clock_rate, sprop) = parse_rtp_caps_from_sdp(&initial_sdp)
             .ok_or_else(|| anyhow::anyhow!("Failed to parse RTP caps from SDP"))?;
         let pipeline_str = build_pipeline_str(&encoding, pt, clock_rate, &sprop);
@@ -170,6 +172,24 @@ impl WscRtpSession {
             .context("GStreamer pipeline launch")?
             .downcast::<gst::Pipeline>()
             .map_err(|_| anyhow::anyhow!("Not a pipeline"))?;
+        let decoder = pipeline
+            .by_name("decoder")
+            .ok_or_else(|| anyhow::anyhow!("decoder not found"))?;
+        // the depayloaded, parsed stream is what recordings write
+        let decoder_sink = decoder
+            .static_pad("sink")
+            .ok_or_else(|| anyhow::anyhow!("decoder has no sink pad"))?;
+        self.session_common.recording().attach(&decoder_sink);
+        let video_chain = self
+            .session_common
+            .build_video_chain(&frame_sink::build_appsink())?;
+        pipeline.add_many([video_chain.filter_bin(), video_chain.bin()])?;
+        gst::Element::link_many([
+            &decoder,
+            video_chain.filter_bin().upcast_ref(),
+            video_chain.bin().upcast_ref(),
+        ])
+        .context("linking decoder to the video chain")?;
 
         Ok(ConnectionResources {
             ws_sink,
@@ -206,41 +226,26 @@ impl WscRtpSession {
     /// Main task: retry loop for connections, receives RTP packets, feeds GStreamer,
     /// sends pings, handles commands.
     ///
-    /// Texture creation and `mark_frame_available` are handled externally on the
-    /// platform thread via the `event_tx` → output loop path.
+    /// Decoded frames go to the session's outputs, whose textures were created
+    /// on the platform thread when their consumers attached.
     pub async fn execute(
         self: &Arc<Self>,
         mut shutdown_rx: tokio::sync::mpsc::Receiver<()>,
     ) -> anyhow::Result<()> {
-        let payload_holder = Arc::new(crate::core::texture::payload::PayloadHolder::new());
-        let payload_holder_weak = Arc::downgrade(&payload_holder);
-        let payload_holder_for_texture = Arc::clone(&payload_holder);
-        let engine_handle = self.session_common.engine_handle;
-        let (sendable_texture, texture_id) =
-            invoke_on_platform_main_thread(move || -> Result<_> {
-                let texture =
-                    Texture::new_with_provider(engine_handle, payload_holder_for_texture)?;
-                let texture_id = texture.id();
-                Ok((texture.into_sendable_texture(), texture_id))
-            })?;
-
-        let texture_session = Arc::new(crate::core::texture::flutter::TextureSession::new(
-            texture_id,
-            Arc::downgrade(&sendable_texture),
-            payload_holder_weak.clone(),
-        ));
-        let texture_session: Arc<dyn FlutterTextureSession> = texture_session;
-
         self.session_common.send_state_msg(StreamState::Loading);
 
         let mut backoff = INITIAL_BACKOFF;
+        let mut attempt = 0u32;
         let mut output: anyhow::Result<()> = Ok(());
+        let mut final_error = None;
 
         loop {
-            match self.connect_and_setup_pipeline().await {
+            self.session_common.startup.begin_attempt();
+            let e = match self.connect_and_setup_pipeline().await {
                 Ok(resources) => {
                     // Reset backoff on successful connection
                     backoff = INITIAL_BACKOFF;
+                    attempt = 0;
 
                     // Store session_id and pipeline
                     *self.active_session_id.write() = Some(resources.wsc_session_id.clone());
@@ -254,10 +259,7 @@ impl WscRtpSession {
                         resources.ws_stream,
                         resources.udp_sock,
                         pipeline_arc.clone(),
-                        texture_session.clone(),
-                        payload_holder_weak.clone(),
                         &mut shutdown_rx,
-                        texture_id,
                     )
                     .await;
 
@@ -269,28 +271,44 @@ impl WscRtpSession {
                             output = Ok(());
                             break;
                         }
+                        Ok(ExitReason::Reconnect) => {
+                            log::debug!("WSC-RTP: reconnecting on request");
+                            self.session_common.send_state_msg(StreamState::Loading);
+                            continue;
+                        }
                         Err(e) => {
                             // Connection lost - will retry if auto_restart is enabled
                             warn!("WSC-RTP session disconnected: {}", e);
+                            let error = StreamError::from_anyhow(
+                                ErrorBackend::WscRtp,
+                                ErrorCategory::Network,
+                                "Connection lost",
+                                &e,
+                            );
                             self.session_common
-                                .send_event_msg(StreamEvent::Error(format!(
-                                    "Connection lost: {}",
-                                    e
-                                )));
+                                .send_event_msg(StreamEvent::Error(error.clone()));
 
                             if !self.config.auto_restart {
                                 log::debug!("WSC-RTP: auto_restart disabled, stopping");
+                                self.session_common.fail_startup(error.clone());
+                                final_error = Some(error);
                                 output = Err(e);
                                 break;
                             }
-                            // Continue to retry
+                            e
                         }
                     }
                 }
                 Err(e) => {
                     error!("WSC-RTP connection failed: {}", e);
+                    let error = StreamError::from_anyhow(
+                        ErrorBackend::WscRtp,
+                        ErrorCategory::Network,
+                        "Connection failed",
+                        &e,
+                    );
                     self.session_common
-                        .send_event_msg(StreamEvent::Error(format!("Connection failed: {}", e)));
+                        .send_event_msg(StreamEvent::Error(error.clone()));
 
                     if shutdown_rx.try_recv().is_ok() {
                         log::debug!("WSC-RTP: shutdown requested during connection");
@@ -300,42 +318,42 @@ impl WscRtpSession {
 
                     if !self.config.auto_restart {
                         log::debug!("WSC-RTP: auto_restart disabled, stopping");
+                        self.session_common.fail_startup(error.clone());
+                        final_error = Some(error);
                         output = Err(e);
                         break;
                     }
+                    e
+                }
+            };
 
-                    // Backoff before retry
-                    self.session_common.send_state_msg(StreamState::Loading);
-                    self.session_common
-                        .send_event_msg(StreamEvent::Error(format!(
-                            "WS connection failed retrying in {}s",
-                            backoff.as_secs()
-                        )));
-                    tokio::select! {
-                        _ = tokio::time::sleep(backoff) => {}
-                        cmd = shutdown_rx.recv() => {
-                            if cmd.is_some() {
-                                log::debug!("WSC-RTP: shutdown requested during backoff");
-                                output = Err(e);
-                                break;
-                            }
-                        }
+            // Backoff before retry, after a lost connection as after a failed one
+            attempt += 1;
+            self.session_common.send_state_msg(StreamState::Loading);
+            self.session_common
+                .send_event_msg(StreamEvent::Reconnecting {
+                    attempt,
+                    delay_ms: backoff.as_millis() as u64,
+                });
+            tokio::select! {
+                _ = tokio::time::sleep(backoff) => {}
+                cmd = shutdown_rx.recv() => {
+                    if cmd.is_some() {
+                        log::debug!("WSC-RTP: shutdown requested during backoff");
+                        output = Err(e);
+                        break;
                     }
-                    backoff = std::cmp::min(backoff * 2, MAX_BACKOFF);
                 }
             }
+            backoff = std::cmp::min(backoff * 2, MAX_BACKOFF);
         }
 
-        // Send Stopped state
-        let _ = self
-            .session_common
-            .send_state_msg(crate::dart_types::StreamState::Stopped);
+        // Send the final state: Error if we gave up because of one, Stopped otherwise
+        self.session_common
+            .send_state_msg(final_error.map_or(StreamState::Stopped, StreamState::Error));
 
-        // Texture + payload_holder must be dropped on the platform main thread
-        invoke_on_platform_main_thread(move || {
-            drop(sendable_texture);
-            drop(payload_holder);
-        });
+        // Queued behind the final state, so its end frame is presented first
+        self.session_common.close_outputs();
 
         output
     }
@@ -348,10 +366,7 @@ impl WscRtpSession {
         mut ws_stream: WsStream,
         udp_sock: Option<UdpSocket>,
         pipeline: Arc<gst::Pipeline>,
-        texture_session: Arc<dyn FlutterTextureSession>,
-        payload_holder_weak: Weak<payload::PayloadHolder>,
         shutdown_rx: &mut tokio::sync::mpsc::Receiver<()>,
-        texture_id: i64,
     ) -> Result<ExitReason> {
         let appsrc = pipeline
             .by_name("src")
@@ -366,71 +381,13 @@ impl WscRtpSession {
             .map_err(|_| anyhow::anyhow!("sink is not AppSink"))?;
 
         let session_weak = Arc::downgrade(session);
-        let session_weak_for_callbacks = session_weak.clone();
-        let mut origin_size_sent = false;
-        appsink.set_callbacks(
-            gst_app::AppSinkCallbacks::builder()
-                .new_sample(move |sink| {
-                    let sample = sink.pull_sample().map_err(|_| gst::FlowError::Eos)?;
-                    let caps = sample.caps().ok_or(gst::FlowError::Error)?;
-                    let video_info =
-                        gst_video::VideoInfo::from_caps(caps).map_err(|_| gst::FlowError::Error)?;
-                    let buffer = sample.buffer().ok_or(gst::FlowError::Error)?;
-
-                    let width = video_info.width();
-                    let height = video_info.height();
-
-                    // Emit OriginVideoSize on first decoded frame
-                    if !origin_size_sent {
-                        origin_size_sent = true;
-                        if let Some(session) = session_weak_for_callbacks.upgrade() {
-                            session
-                                .session_common
-                                .send_event_msg(StreamEvent::OriginVideoSize {
-                                    width: width as u64,
-                                    height: height as u64,
-                                });
-                        }
-                    }
-
-                    let video_frame =
-                        gst_video::VideoFrameRef::from_buffer_ref_readable(buffer, &video_info)
-                            .map_err(|_| gst::FlowError::Error)?;
-
-                    let stride = video_info.stride()[0] as usize;
-                    let expected_stride = (width as usize) * 4; // RGBA
-                    let plane_data = video_frame
-                        .plane_data(0)
-                        .map_err(|_| gst::FlowError::Error)?;
-
-                    let data = if stride == expected_stride {
-                        plane_data.to_vec()
-                    } else {
-                        // Stride mismatch — copy row by row to strip padding
-                        let mut buf = Vec::with_capacity(expected_stride * height as usize);
-                        for y in 0..height as usize {
-                            let row_start = y * stride;
-                            buf.extend_from_slice(
-                                &plane_data[row_start..row_start + expected_stride],
-                            );
-                        }
-                        buf
-                    };
-
-                    let frame = RawRgbaFrame {
-                        width,
-                        height,
-                        data,
-                    };
-
-                    if let Some(holder) = payload_holder_weak.upgrade() {
-                        holder.set_payload(Arc::new(frame) as SharedPixelData);
-                        texture_session.mark_frame_available();
-                    }
-                    Ok(gst::FlowSuccess::Ok)
-                })
-                .build(),
-        );
+        frame_sink::install(&appsink, session_weak.clone(), || {});
+        if let Some(decoder_sink) = pipeline
+            .by_name("decoder")
+            .and_then(|decoder| decoder.static_pad("sink"))
+        {
+            frame_sink::throttle_standby_decode(&decoder_sink, session_weak.clone());
+        }
 
         // UDP packet receiver task
         async fn udp_packet_receiver(appsrc: AppSrc, udp_sock: UdpSocket) {
@@ -474,14 +431,11 @@ impl WscRtpSession {
         pipeline
             .set_state(gst::State::Playing)
             .context("setting GStreamer pipeline to Playing")?;
+        // live pipeline: there is no preroll, count the switch to Playing instead
+        session.session_common.mark_startup(StartupPhase::Preroll);
 
-        // Send Playing state with texture_id
-        if let Some(session) = session_weak.upgrade() {
-            session.session_common.send_state_msg(StreamState::Playing {
-                texture_id,
-                seekable: true,
-            });
-        }
+        // Send Playing state; every consumer gets its own texture id
+        session.session_common.send_playing_msg(true);
 
         let mut ping_interval = tokio::time::interval(PING_INTERVAL);
         ping_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
@@ -502,6 +456,15 @@ impl WscRtpSession {
                     }
                 }
 
+                _ = session.session_common.reconnect_requested() => {
+                    log::debug!("WSC-RTP: reconnect requested");
+                    if let Some(udp_rcv_task) = udp_packet_rcv_task {
+                        udp_rcv_task.abort();
+                    }
+                    pipeline.set_state(gst::State::Null)?;
+                    return Ok(ExitReason::Reconnect);
+                }
+
                 _ = ping_interval.tick() => {
                     if let Ok(payload) = serde_json::to_string(&WscRtpClientMessage::Ping) {
                         let _ = ws_sink.send(Message::Text(payload.into())).await;
@@ -601,7 +564,11 @@ impl WscRtpSession {
             }
             WscRtpServerMessage::Error { message } => {
                 self.session_common
-                    .send_event_msg(StreamEvent::Error(message));
+                    .send_event_msg(StreamEvent::Error(StreamError::new(
+                        ErrorBackend::WscRtp,
+                        ErrorCategory::Server,
+                        message,
+                    )));
             }
             WscRtpServerMessage::FallingBackRtpToWs => {
                 log::debug!("WSC-RTP: server falling back to WebSocket for RTP delivery");
@@ -610,16 +577,24 @@ impl WscRtpSession {
         }
     }
 
+    /// Report a failed control request to Dart and turn it into the returned error.
+    fn control_error(&self, error: StreamError) -> anyhow::Error {
+        self.session_common
+            .send_event_msg(StreamEvent::Error(error.clone()));
+        error.into()
+    }
+
     async fn send_control_request(
         &self,
         endpoint: &str,
         body: impl serde::Serialize,
     ) -> Result<()> {
         let session_id = self.active_session_id.read().clone().ok_or_else(|| {
-            let msg = "session is reconnecting, seek is not available yet".to_string();
-            self.session_common
-                .send_event_msg(StreamEvent::Error(msg.clone()));
-            anyhow::anyhow!(msg)
+            self.control_error(StreamError::new(
+                ErrorBackend::WscRtp,
+                ErrorCategory::Unavailable,
+                "session is reconnecting, seek is not available yet",
+            ))
         })?;
 
         let mut url = self.media_server_http_url.clone();
@@ -636,10 +611,15 @@ impl WscRtpSession {
             .await
             .map_err(|e| {
                 error!("WSC-RTP control request to {} failed: {:#}", endpoint, e);
-                let msg = format!("Control request failed: {}", e.without_url());
-                self.session_common
-                    .send_event_msg(StreamEvent::Error(msg.clone()));
-                anyhow::anyhow!(msg)
+                let category = if e.is_timeout() {
+                    ErrorCategory::Timeout
+                } else {
+                    ErrorCategory::Network
+                };
+                self.control_error(
+                    StreamError::new(ErrorBackend::WscRtp, category, "Control request failed")
+                        .with_cause(e.without_url()),
+                )
             })?;
 
         let status = response.status();
@@ -649,10 +629,14 @@ impl WscRtpSession {
                 "WSC-RTP control request to {} returned {}: {}",
                 endpoint, status, body_text
             );
-            let msg = format!("Server returned {status}: {body_text}");
-            self.session_common
-                .send_event_msg(StreamEvent::Error(msg.clone()));
-            anyhow::bail!(msg);
+            return Err(self.control_error(
+                StreamError::new(
+                    ErrorBackend::WscRtp,
+                    ErrorCategory::from_http_status(status.as_u16()),
+                    format!("Server returned {status}"),
+                )
+                .with_cause(body_text),
+            ));
         }
 
         let mode: SessionModeResponse = response.json().await.map_err(|e| {
@@ -660,10 +644,14 @@ impl WscRtpSession {
                 "WSC-RTP: failed to parse control response from {}: {:#}",
                 endpoint, e
             );
-            let msg = "Invalid response from server".to_string();
-            self.session_common
-                .send_event_msg(StreamEvent::Error(msg.clone()));
-            anyhow::anyhow!(msg)
+            self.control_error(
+                StreamError::new(
+                    ErrorBackend::WscRtp,
+                    ErrorCategory::Server,
+                    "Invalid response from server",
+                )
+                .with_cause(e),
+            )
         })?;
         let wsc_mode = if mode.is_live {
             WscRtpMode::Live
@@ -683,6 +671,30 @@ impl WscRtpSession {
 enum ExitReason {
     /// Intentional shutdown via terminate()
     Shutdown,
+    /// `VideoSessionCommon::request_reconnect` was called, e.g. by the stall watchdog
+    Reconnect,
+}
+
+/// Creates `VideoConfig::WscRtp` sessions, registered as `factory::WSC_RTP_KIND`.
+pub struct WscRtpFactory;
+
+impl InputBackendFactory for WscRtpFactory {
+    type Config = WscRtpSessionConfig;
+
+    fn create(
+        &self,
+        config: WscRtpSessionConfig,
+        session_common: VideoSessionCommon,
+    ) -> Result<BackendTask> {
+        log::trace!("  source_id: {}", config.source_id.as_str());
+        Url::parse(&config.base_url).context("parsing media server HTTP URL")?;
+        let (session, shutdown_rx) =
+            WscRtpSession::new(config, session_common, HTTP_CLIENT.clone());
+        Ok(BackendTask {
+            session: session.clone(),
+            run: Box::pin(async move { session.execute(shutdown_rx).await }),
+        })
+    }
 }
 
 #[async_trait]
@@ -705,12 +717,8 @@ impl crate::core::session::VideoSession for WscRtpSession {
         self.session_common.engine_handle
     }
 
-    fn last_alive_mark(&self) -> std::time::SystemTime {
-        self.session_common.get_last_alive_mark()
-    }
-
-    fn make_alive(&self) {
-        self.session_common.mark_alive();
+    fn session_common(&self) -> &VideoSessionCommon {
+        &self.session_common
     }
 
     fn terminate(&self) {
@@ -718,8 +726,12 @@ impl crate::core::session::VideoSession for WscRtpSession {
         if let Some(pipeline) = self.active_pipeline.lock().take() {
             let _ = pipeline.set_state(gst::State::Null);
         }
-        if let Err(e) = self.shutdown_sender.blocking_send(()) {
-            log::error!("Failed to send shutdown signal: {:?}", e);
+        match self.shutdown_sender.try_send(()) {
+            // a shutdown is already pending
+            Ok(()) | Err(tokio::sync::mpsc::error::TrySendError::Full(_)) => {}
+            Err(tokio::sync::mpsc::error::TrySendError::Closed(_)) => {
+                log::debug!("Shutdown signal not sent, session task already finished");
+            }
         }
     }
 }
@@ -827,10 +839,7 @@ fn build_pipeline_str(encoding: &str, pt: u8, clock_rate: u32, sprop: &Option<St
     format!(
         "appsrc name=src caps=\"application/x-rtp,media=video,payload={pt},clock-rate={clock_rate},encoding-name={encoding}{sprop_cap}\" format=time is-live=true \
          ! rtpjitterbuffer \
-         ! {depay_decode} \
-         ! videoconvert \
-         ! video/x-raw,format=RGBA \
-         ! appsink name=sink sync=false emit-signals=true",
+         ! {depay_decode} name=decoder",
     )
 }
 
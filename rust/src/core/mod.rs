@@ -1,11 +1,18 @@
+pub mod error;
 pub mod input;
 pub mod output;
+pub mod runtime;
 pub mod session;
 pub mod texture;
 pub mod types;
 use std::sync::Arc;
 
 use log::debug;
+
+use crate::{
+    core::{session::registry, types::RuntimeConfig},
+    utils::LogErr,
+};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
 lazy_static::lazy_static! {
@@ -14,6 +21,22 @@ lazy_static::lazy_static! {
     pub static ref HTTP_CLIENT: Arc<reqwest::Client> = Arc::new(reqwest::Client::new());
 }
 
+/// Starts GStreamer, the crate runtime and the session supervisor.
+/// Only the first call does anything; `host_init` runs before the rest of that call.
+pub(crate) fn init_services(runtime_config: &RuntimeConfig, host_init: impl FnOnce()) {
+    let mut is_initialized = IS_INITIALIZED.lock().unwrap();
+    if *is_initialized {
+        return;
+    }
+    host_init();
+
+    registry::init().log_err();
+    runtime::init(runtime_config).log_err();
+    runtime::spawn(registry::supervisor_task());
+    debug!("Done initializing flutter gstreamer");
+    *is_initialized = true;
+}
+
 pub(crate) fn init_logger() {
     let is_initialized = IS_INITIALIZED.lock().unwrap();
     if *is_initialized {
@@ -0,0 +1,252 @@
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result};
+use gst::prelude::*;
+use gst_app::AppSrc;
+use log::{debug, trace};
+use parking_lot::Mutex;
+
+use crate::{
+    core::{
+        input::{frame_sink, video_chain},
+        types::{FrameTapConfig, TapPixelFormat},
+    },
+    dart_types::TapFrame,
+};
+
+/// Where a frame tap delivers its frames; called from the tap's own streaming thread.
+pub trait FrameTapSink: Send + Sync {
+    fn send(&self, frame: TapFrame) -> Result<()>;
+}
+
+pub type SharedFrameTapSink = Arc<dyn FrameTapSink>;
+
+impl FrameTapSink for flume::Sender<TapFrame> {
+    fn send(&self, frame: TapFrame) -> Result<()> {
+        // a bounded channel is the consumer's queue; a full one drops the frame
+        self.try_send(frame).map_err(|e| match e {
+            flume::TrySendError::Full(_) => anyhow::anyhow!("receiver queue full"),
+            flume::TrySendError::Disconnected(_) => anyhow::anyhow!("receiver dropped"),
+        })
+    }
+}
+
+/// Hands a session's decoded frames to analytics code at its own rate, size and format.
+///
+/// The video chain offers each frame after rotation, crop and zoom, but before the
+/// session's frame-rate cap and output scaling; accepted frames are queued by reference
+/// in a separate `appsrc ! videoconvert ! videoscale ! appsink` pipeline.
+/// The offer never blocks: while `queue_len` frames wait for the consumer,
+/// further frames are dropped, so a slow consumer can't stall the texture path.
+pub struct FrameTap {
+    pub id: i64,
+    config: FrameTapConfig,
+    min_interval: Option<Duration>,
+    pipeline: gst::Pipeline,
+    appsrc: AppSrc,
+    appsink: gst_app::AppSink,
+    crop: gst::Element,
+    size_filter: gst::Element,
+    /// Set for taps created with `pulled`: the consumer's queue, read by `frames`.
+    pulled: Option<flume::Receiver<TapFrame>>,
+    state: Mutex<TapState>,
+    dropped: Arc<AtomicU64>,
+}
+
+#[derive(Default)]
+struct TapState {
+    caps: Option<gst::Caps>,
+    last_offered: Option<Instant>,
+}
+
+impl FrameTap {
+    pub fn new(id: i64, config: FrameTapConfig, sink: SharedFrameTapSink) -> Result<Arc<Self>> {
+        Ok(Arc::new(Self::build(id, config, sink)?))
+    }
+
+    /// A tap whose frames wait in a queue of `config.queue_len` until the consumer
+    /// pulls them from `frames`, e.g. Dart through `next_frame`; further ones are dropped.
+    pub fn pulled(id: i64, config: FrameTapConfig) -> Result<Arc<Self>> {
+        let (tx, rx) = flume::bounded(config.queue_len.max(1) as usize);
+        let mut tap = Self::build(id, config, Arc::new(tx))?;
+        tap.pulled = Some(rx);
+        Ok(Arc::new(tap))
+    }
+
+    fn build(id: i64, config: FrameTapConfig, sink: SharedFrameTapSink) -> Result<Self> {
+        let pipeline = gst::parse::launch(
+            "appsrc name=src is-live=true format=time max-bytes=0 \
+             ! videocrop name=crop ! videoconvert ! videoscale ! capsfilter name=size \
+             ! appsink name=sink sync=false max-buffers=1",
+        )
+        .context("frame tap pipeline launch")?
+        .downcast::<gst::Pipeline>()
+        .map_err(|_| anyhow::anyhow!("Not a pipeline"))?;
+        let appsrc = pipeline
+            .by_name("src")
+            .ok_or_else(|| anyhow::anyhow!("appsrc not found"))?
+            .downcast::<AppSrc>()
+            .map_err(|_| anyhow::anyhow!("src is not AppSrc"))?;
+        let crop = pipeline
+            .by_name("crop")
+            .ok_or_else(|| anyhow::anyhow!("videocrop not found"))?;
+        let size_filter = pipeline
+            .by_name("size")
+            .ok_or_else(|| anyhow::anyhow!("capsfilter not found"))?;
+        let appsink = pipeline
+            .by_name("sink")
+            .ok_or_else(|| anyhow::anyhow!("appsink not found"))?
+            .downcast::<gst_app::AppSink>()
+            .map_err(|_| anyhow::anyhow!("sink is not AppSink"))?;
+
+        let format = config.format;
+        let dropped = Arc::new(AtomicU64::new(0));
+        let sequence = AtomicU64::new(0);
+        let dropped_for_sink = Arc::clone(&dropped);
+        appsink.set_callbacks(
+            gst_app::AppSinkCallbacks::builder()
+                .new_sample(move |appsink| {
+                    let sample = appsink.pull_sample().map_err(|_| gst::FlowError::Eos)?;
+                    let frame = tap_frame(&sample, format).ok_or(gst::FlowError::Error)?;
+                    let frame = TapFrame {
+                        sequence: sequence.fetch_add(1, Ordering::SeqCst),
+                        dropped: dropped_for_sink.load(Ordering::SeqCst),
+                        ..frame
+                    };
+                    if let Err(e) = sink.send(frame) {
+                        trace!("frame tap {}: frame not delivered: {}", id, e);
+                        dropped_for_sink.fetch_add(1, Ordering::SeqCst);
+                    }
+                    Ok(gst::FlowSuccess::Ok)
+                })
+                .build(),
+        );
+        pipeline.set_state(gst::State::Playing)?;
+
+        Ok(Self {
+            id,
+            min_interval: config
+                .max_frame_rate
+                .map(|rate| Duration::from_secs(1) / rate.max(1)),
+            config,
+            pipeline,
+            appsrc,
+            appsink,
+            crop,
+            size_filter,
+            pulled: None,
+            state: Mutex::new(TapState::default()),
+            dropped,
+        })
+    }
+
+    /// Queue of a `pulled` tap; it disconnects once the tap is dropped.
+    pub fn frames(&self) -> Option<flume::Receiver<TapFrame>> {
+        self.pulled.clone()
+    }
+
+    /// Frames dropped so far because the consumer's queue was full.
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::SeqCst)
+    }
+
+    /// Queue `buffer` for the consumer unless the rate limit or a full queue skips it.
+    /// Called from the session's streaming thread; never blocks.
+    pub(crate) fn offer(&self, caps: Option<&gst::Caps>, buffer: &gst::Buffer) {
+        let now = Instant::now();
+        {
+            let mut state = self.state.lock();
+            if let (Some(min_interval), Some(last)) = (self.min_interval, state.last_offered) {
+                if now.duration_since(last) < min_interval {
+                    return;
+                }
+            }
+            if self.appsrc.current_level_buffers() >= self.config.queue_len.max(1) as u64 {
+                self.dropped.fetch_add(1, Ordering::SeqCst);
+                return;
+            }
+            state.last_offered = Some(now);
+
+            if let Some(caps) = caps {
+                if state.caps.as_ref() != Some(caps) {
+                    state.caps = Some(caps.clone());
+                    self.set_input_caps(caps);
+                }
+            }
+        }
+        if let Err(e) = self.appsrc.push_buffer(buffer.clone()) {
+            debug!("frame tap {}: push failed: {:?}", self.id, e);
+        }
+    }
+
+    fn set_input_caps(&self, caps: &gst::Caps) {
+        let mut output = gst::Caps::builder("video/x-raw").field(
+            "format",
+            match self.config.format {
+                TapPixelFormat::Rgba => "RGBA",
+                TapPixelFormat::Rgb => "RGB",
+                TapPixelFormat::Nv12 => "NV12",
+                TapPixelFormat::Gray8 => "GRAY8",
+            },
+        );
+        let mut margins = video_chain::CropMargins::default();
+        if let (Some(size), Ok(video_info)) =
+            (&self.config.size, gst_video::VideoInfo::from_caps(caps))
+        {
+            let mut source = (video_info.width(), video_info.height());
+            if let Some(fill) = video_chain::fill_margins(source, size) {
+                margins = fill;
+                source = fill.size(source);
+            }
+            if let Some((width, height)) = video_chain::scaled_size(Some(source), size) {
+                output = output
+                    .field("width", width as i32)
+                    .field("height", height as i32);
+            }
+        }
+        let output = output.build();
+        debug!("frame tap {}: {} -> {}", self.id, caps, output);
+        margins.apply_to(&self.crop);
+        self.size_filter.set_property("caps", &output);
+        self.appsrc.set_caps(Some(caps));
+    }
+}
+
+impl Drop for FrameTap {
+    fn drop(&mut self) {
+        let _ = self.pipeline.set_state(gst::State::Null);
+        // release the sink now, so a consumer waiting on a pulled queue sees it disconnect
+        self.appsink
+            .set_callbacks(gst_app::AppSinkCallbacks::builder().build());
+    }
+}
+
+fn tap_frame(sample: &gst::Sample, format: TapPixelFormat) -> Option<TapFrame> {
+    let video_info = gst_video::VideoInfo::from_caps(sample.caps()?).ok()?;
+    let buffer = sample.buffer()?;
+    let video_frame =
+        gst_video::VideoFrameRef::from_buffer_ref_readable(buffer, &video_info).ok()?;
+    let mut data = Vec::with_capacity(frame_sink::packed_size(&video_info));
+    let planes = frame_sink::copy_planes(&video_frame, &mut data).ok()?;
+    Some(TapFrame {
+        data,
+        width: video_info.width(),
+        height: video_info.height(),
+        format,
+        plane_strides: planes.iter().map(|plane| plane.stride as u32).collect(),
+        plane_offsets: planes.iter().map(|plane| plane.offset as u32).collect(),
+        pts_ms: buffer.pts().map(|pts| pts.mseconds()),
+        captured_at_ms: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or(Duration::ZERO)
+            .as_millis() as i64,
+        sequence: 0,
+        dropped: 0,
+    })
+}
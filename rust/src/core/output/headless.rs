@@ -0,0 +1,112 @@
+use std::{
+    collections::VecDeque,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    time::Duration,
+};
+
+use anyhow::{bail, Result};
+use parking_lot::Mutex;
+use tokio::sync::Notify;
+
+use crate::core::{output::FrameOutput, texture::payload::SharedPixelData};
+
+/// In-memory frame output that needs neither a Flutter engine nor the platform main thread,
+/// so whole playback flows can run in `cargo test` or a CLI.
+/// Frames are kept by reference; nothing is copied. Their byte order is `RawFrame::format`,
+/// the texture's native one.
+pub struct HeadlessFrameSink {
+    latest: Mutex<Option<SharedPixelData>>,
+    frame_count: AtomicU64,
+    history: Mutex<VecDeque<SharedPixelData>>,
+    history_len: usize,
+    closed: AtomicBool,
+    frame_notify: Notify,
+}
+
+impl HeadlessFrameSink {
+    /// Keeps the latest frame plus up to `history_len` recent frames (0 disables the history).
+    pub fn new(history_len: usize) -> Self {
+        Self {
+            latest: Mutex::new(None),
+            frame_count: AtomicU64::new(0),
+            history: Mutex::new(VecDeque::with_capacity(history_len)),
+            history_len,
+            closed: AtomicBool::new(false),
+            frame_notify: Notify::new(),
+        }
+    }
+
+    pub fn latest_frame(&self) -> Option<SharedPixelData> {
+        self.latest.lock().clone()
+    }
+
+    /// Frames received since the sink was created.
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count.load(Ordering::SeqCst)
+    }
+
+    /// Recent frames, oldest first.
+    pub fn history(&self) -> Vec<SharedPixelData> {
+        self.history.lock().iter().cloned().collect()
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::SeqCst)
+    }
+
+    /// Resolves with the frame count once at least `count` frames were received.
+    /// Fails on timeout or if the sink was closed before that.
+    pub async fn wait_for_frames(&self, count: u64, timeout: Duration) -> Result<u64> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            // registered before the check, so a frame arriving in between isn't missed
+            let notified = self.frame_notify.notified();
+            let received = self.frame_count();
+            if received >= count {
+                return Ok(received);
+            }
+            if self.is_closed() {
+                bail!(
+                    "headless sink closed after {} of {} frames",
+                    received,
+                    count
+                );
+            }
+            if tokio::time::timeout_at(deadline, notified).await.is_err() {
+                bail!(
+                    "timed out after {:?} waiting for {} frames, got {}",
+                    timeout,
+                    count,
+                    self.frame_count()
+                );
+            }
+        }
+    }
+}
+
+impl FrameOutput for HeadlessFrameSink {
+    fn wants_frames(&self) -> bool {
+        !self.is_closed()
+    }
+
+    fn on_frame(&self, frame: &SharedPixelData) {
+        if self.is_closed() {
+            return;
+        }
+        *self.latest.lock() = Some(frame.clone());
+        if self.history_len > 0 {
+            let mut history = self.history.lock();
+            if history.len() == self.history_len {
+                history.pop_front();
+            }
+            history.push_back(frame.clone());
+        }
+        self.frame_count.fetch_add(1, Ordering::SeqCst);
+        self.frame_notify.notify_waiters();
+    }
+
+    fn close(&self) {
+        self.closed.store(true, Ordering::SeqCst);
+        self.frame_notify.notify_waiters();
+    }
+}
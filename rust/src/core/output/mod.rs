@@ -1 +1,30 @@
 pub mod flutter_pixelbuffer;
+pub mod frame_tap;
+pub mod headless;
+pub mod presentation;
+pub mod recorder;
+pub mod snapshot;
+
+use crate::{
+    core::texture::payload::SharedPixelData,
+    dart_types::{StreamEvent, StreamState},
+};
+
+/// A consumer of what an input backend produces: decoded frames, state and events.
+///
+/// Outputs are attached to a session's `SessionOutputs` and called from its output loop,
+/// so any input backend can feed any combination of outputs
+/// (Flutter texture, snapshot, recorder, headless sink).
+pub trait FrameOutput: Send + Sync {
+    /// Whether decoded frames are needed at all. Backends decode only keyframes
+    /// while no attached output wants frames (e.g. standby sessions).
+    fn wants_frames(&self) -> bool {
+        true
+    }
+    fn on_frame(&self, frame: &SharedPixelData);
+    fn on_state(&self, _state: &StreamState) {}
+    fn on_event(&self, _event: &StreamEvent) {}
+    /// Release the output's resources. Called once when it is detached or the session ended;
+    /// frames still queued in the output loop may arrive afterwards and must be ignored.
+    fn close(&self) {}
+}
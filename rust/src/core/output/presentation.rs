@@ -0,0 +1,201 @@
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use gst::prelude::*;
+
+use crate::core::{
+    input::frame_sink,
+    texture::{
+        payload::{FrameFormat, RawFrame, SharedPixelData},
+        pool::FramePool,
+    },
+    types::{EndFramePolicy, PlaceholderFrame},
+};
+
+const POSTER_TIMEOUT: gst::ClockTime = gst::ClockTime::from_seconds(10);
+/// Size of generated placeholders until the session's frame size is known.
+pub const DEFAULT_PLACEHOLDER_SIZE: (u32, u32) = (640, 480);
+
+/// What an `EndFramePolicy` does to the texture.
+pub enum EndFrame {
+    Keep,
+    Show(SharedPixelData),
+    Clear,
+}
+
+pub fn end_frame(policy: EndFramePolicy, current: Option<SharedPixelData>) -> EndFrame {
+    match (policy, current) {
+        (EndFramePolicy::Clear, _) => EndFrame::Clear,
+        (EndFramePolicy::Dim { brightness_percent }, Some(frame)) => {
+            EndFrame::Show(Arc::new(frame.dimmed(brightness_percent)))
+        }
+        _ => EndFrame::Keep,
+    }
+}
+
+/// The placeholder frame, or `None` for a poster that still has to be decoded.
+/// Black and transparent placeholders are generated at `size`.
+pub fn placeholder_frame(
+    placeholder: &PlaceholderFrame,
+    size: (u32, u32),
+) -> Result<Option<SharedPixelData>> {
+    let (width, height) = size;
+    let frame = match placeholder {
+        PlaceholderFrame::Black => RawFrame::black(width, height),
+        PlaceholderFrame::Transparent => RawFrame::transparent(width, height),
+        PlaceholderFrame::Rgba {
+            width,
+            height,
+            data,
+        } => {
+            anyhow::ensure!(
+                data.len() == (*width as usize) * (*height as usize) * 4,
+                "placeholder is {} bytes, expected {}x{} RGBA",
+                data.len(),
+                width,
+                height
+            );
+            RawFrame::new(*width, *height, FrameFormat::Rgba, data.clone())
+                .converted(FrameFormat::native())
+        }
+        PlaceholderFrame::Poster { .. } => return Ok(None),
+    };
+    Ok(Some(Arc::new(frame)))
+}
+
+/// Decode the first frame of `uri` to the texture's pixel format.
+/// Blocks until prerolled, so run it off the runtime.
+pub fn decode_poster(uri: &str) -> Result<RawFrame> {
+    let pipeline = gst::parse::launch(&format!(
+        "uridecodebin name=src ! videoconvert ! video/x-raw,format={} ! appsink name=sink",
+        FrameFormat::native().video_format().to_str()
+    ))
+    .context("poster pipeline launch")?
+    .downcast::<gst::Pipeline>()
+    .map_err(|_| anyhow::anyhow!("Not a pipeline"))?;
+    pipeline
+        .by_name("src")
+        .ok_or_else(|| anyhow::anyhow!("uridecodebin not found"))?
+        .set_property("uri", uri);
+    let appsink = pipeline
+        .by_name("sink")
+        .ok_or_else(|| anyhow::anyhow!("appsink not found"))?
+        .downcast::<gst_app::AppSink>()
+        .map_err(|_| anyhow::anyhow!("sink is not AppSink"))?;
+
+    pipeline.set_state(gst::State::Paused)?;
+    let sample = appsink.try_pull_preroll(POSTER_TIMEOUT);
+    let _ = pipeline.set_state(gst::State::Null);
+
+    let sample = sample.ok_or_else(|| anyhow::anyhow!("no frame decoded from {}", uri))?;
+    let caps = sample
+        .caps()
+        .ok_or_else(|| anyhow::anyhow!("poster sample without caps"))?;
+    let video_info = gst_video::VideoInfo::from_caps(caps)?;
+    let buffer = sample
+        .buffer()
+        .ok_or_else(|| anyhow::anyhow!("poster sample without buffer"))?;
+    // a one-off frame, nothing to recycle
+    frame_sink::copy_frame(buffer, &video_info, &Arc::new(FramePool::new(0)))
+        .map_err(|e| anyhow::anyhow!("copying poster frame: {:?}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(pixel: [u8; 4]) -> SharedPixelData {
+        Arc::new(RawFrame::new(2, 1, FrameFormat::Rgba, pixel.repeat(2)))
+    }
+
+    #[test]
+    fn end_frame_follows_the_policy() {
+        assert!(matches!(
+            end_frame(
+                EndFramePolicy::HoldLastFrame,
+                Some(frame([200, 100, 50, 255]))
+            ),
+            EndFrame::Keep
+        ));
+        assert!(matches!(
+            end_frame(EndFramePolicy::Clear, Some(frame([200, 100, 50, 255]))),
+            EndFrame::Clear
+        ));
+        // nothing to dim yet
+        assert!(matches!(
+            end_frame(
+                EndFramePolicy::Dim {
+                    brightness_percent: 50
+                },
+                None
+            ),
+            EndFrame::Keep
+        ));
+
+        let policy = EndFramePolicy::Dim {
+            brightness_percent: 50,
+        };
+        match end_frame(policy, Some(frame([200, 100, 50, 255]))) {
+            EndFrame::Show(dimmed) => assert_eq!(dimmed.data, [100, 50, 25, 255].repeat(2)),
+            _ => panic!("expected a dimmed frame"),
+        }
+    }
+
+    #[test]
+    fn generated_placeholders_use_the_requested_size() {
+        let black = placeholder_frame(&PlaceholderFrame::Black, (64, 36))
+            .unwrap()
+            .unwrap();
+        assert_eq!((black.width, black.height), (64, 36));
+        assert_eq!(black.data.len(), 64 * 36 * 4);
+        assert!(black
+            .data
+            .chunks_exact(4)
+            .all(|pixel| pixel == [0, 0, 0, 255]));
+
+        let transparent = placeholder_frame(&PlaceholderFrame::Transparent, (64, 36))
+            .unwrap()
+            .unwrap();
+        assert_eq!((transparent.width, transparent.height), (64, 36));
+        assert!(transparent.data.iter().all(|&byte| byte == 0));
+    }
+
+    #[test]
+    fn rgba_placeholder_is_converted_to_the_native_format() {
+        let placeholder = PlaceholderFrame::Rgba {
+            width: 1,
+            height: 1,
+            data: vec![10, 20, 30, 255],
+        };
+        let frame = placeholder_frame(&placeholder, DEFAULT_PLACEHOLDER_SIZE)
+            .unwrap()
+            .unwrap();
+        assert_eq!((frame.width, frame.height), (1, 1));
+        assert_eq!(frame.format, FrameFormat::native());
+        let expected = match FrameFormat::native() {
+            FrameFormat::Rgba => [10, 20, 30, 255],
+            FrameFormat::Bgra => [30, 20, 10, 255],
+        };
+        assert_eq!(frame.data, expected);
+    }
+
+    #[test]
+    fn rgba_placeholder_of_the_wrong_length_is_rejected() {
+        let placeholder = PlaceholderFrame::Rgba {
+            width: 2,
+            height: 2,
+            data: vec![0; 4],
+        };
+        assert!(placeholder_frame(&placeholder, DEFAULT_PLACEHOLDER_SIZE).is_err());
+    }
+
+    #[test]
+    fn poster_placeholder_is_decoded_later() {
+        let placeholder = PlaceholderFrame::Poster {
+            uri: "file:///tmp/poster.jpg".to_string(),
+        };
+        assert!(placeholder_frame(&placeholder, DEFAULT_PLACEHOLDER_SIZE)
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,685 @@
+use std::{collections::VecDeque, path::Path, sync::Arc, time::Duration};
+
+use anyhow::{Context, Result};
+use gst::prelude::*;
+use gst_app::AppSrc;
+use log::{debug, info, warn};
+use parking_lot::Mutex;
+use tokio::sync::{watch, OnceCell};
+
+use crate::{
+    core::{
+        runtime,
+        types::{PreEventBuffer, RecordingContainer, RecordingSegments},
+    },
+    dart_types::{ErrorBackend, ErrorCategory, RecordingInfo, StreamError, StreamEvent},
+};
+
+const FINISH_TIMEOUT: Duration = Duration::from_secs(10);
+/// Gap between the last frame before a reconnect and the first one after it.
+const RECONNECT_GAP_NS: i64 = 40_000_000;
+const DEFAULT_RING_MAX_BYTES: u64 = 64 * 1024 * 1024;
+/// Encoded data waiting for the muxer and disk beyond this fails the recording.
+const MAX_QUEUED_BYTES: u64 = 32 * 1024 * 1024;
+
+pub type RecordingEventCallback = Arc<dyn Fn(StreamEvent) + Send + Sync>;
+
+/// Forwards a backend's encoded stream to the session's recorders and pre-event ring.
+/// Backends attach the sink pad of their decoder, where the stream is parsed but
+/// not decoded; recorders keep running across reconnects that attach a new pad.
+pub struct RecordingTap {
+    pad: Mutex<Option<gst::glib::WeakRef<gst::Pad>>>,
+    targets: Arc<Mutex<TapTargets>>,
+}
+
+#[derive(Default)]
+struct TapTargets {
+    /// Started with `start_recording`.
+    recorder: Option<Arc<Recorder>>,
+    /// Pre-event clips still writing their post-event part.
+    clips: Vec<Arc<Recorder>>,
+    ring: Option<PacketRing>,
+}
+
+impl TapTargets {
+    fn recorders(&self) -> impl Iterator<Item = &Arc<Recorder>> {
+        self.recorder.iter().chain(&self.clips)
+    }
+}
+
+impl RecordingTap {
+    pub fn new(pre_event_buffer: Option<PreEventBuffer>) -> Self {
+        Self {
+            pad: Mutex::new(None),
+            targets: Arc::new(Mutex::new(TapTargets {
+                ring: pre_event_buffer.map(PacketRing::new),
+                ..Default::default()
+            })),
+        }
+    }
+
+    pub fn attach(&self, pad: &gst::Pad) {
+        debug!("recording tap: attached to {}", pad.name());
+        *self.pad.lock() = Some(pad.downgrade());
+        {
+            let mut targets = self.targets.lock();
+            // the new pipeline's timestamps don't continue the buffered ones
+            if let Some(ring) = targets.ring.as_mut() {
+                ring.clear();
+            }
+            for recorder in targets.recorders() {
+                recorder.discontinuity();
+            }
+        }
+        let targets = Arc::clone(&self.targets);
+        pad.add_probe(gst::PadProbeType::BUFFER, move |pad, info| {
+            if let Some(gst::PadProbeData::Buffer(buffer)) = &info.data {
+                let caps = pad.current_caps();
+                let mut targets = targets.lock();
+                if let Some(ring) = targets.ring.as_mut() {
+                    ring.push(caps.as_ref(), buffer);
+                }
+                targets.clips.retain(|clip| !clip.is_finished());
+                for recorder in targets.recorders() {
+                    recorder.offer(caps.as_ref(), buffer);
+                }
+            }
+            gst::PadProbeReturn::Ok
+        });
+    }
+
+    /// Caps of the encoded stream; `None` until the backend negotiated it.
+    pub fn caps(&self) -> Option<gst::Caps> {
+        self.pad.lock().as_ref()?.upgrade()?.current_caps()
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.targets
+            .lock()
+            .recorder
+            .as_ref()
+            .is_some_and(|recorder| !recorder.is_finished())
+    }
+
+    /// Start feeding `recorder`. Fails while another recording is still running.
+    pub fn start(&self, recorder: Arc<Recorder>) -> Result<()> {
+        let mut targets = self.targets.lock();
+        if targets
+            .recorder
+            .as_ref()
+            .is_some_and(|current| !current.is_finished())
+        {
+            anyhow::bail!("a recording is already running");
+        }
+        targets.recorder = Some(recorder);
+        Ok(())
+    }
+
+    /// The running or last recording, removed from the tap.
+    pub fn take(&self) -> Option<Arc<Recorder>> {
+        self.targets.lock().recorder.take()
+    }
+
+    /// Every recording and clip, removed from the tap.
+    pub fn take_all(&self) -> Vec<Arc<Recorder>> {
+        let mut targets = self.targets.lock();
+        let clips = std::mem::take(&mut targets.clips);
+        targets.recorder.take().into_iter().chain(clips).collect()
+    }
+
+    pub fn pre_event_buffer(&self) -> Option<PreEventBuffer> {
+        Some(self.targets.lock().ring.as_ref()?.config.clone())
+    }
+
+    /// `None` stops buffering and drops the buffered packets.
+    pub fn set_pre_event_buffer(&self, config: Option<PreEventBuffer>) {
+        self.targets.lock().ring = config.map(PacketRing::new);
+    }
+
+    /// Caps of the buffered packets; `None` while the ring is disabled or empty.
+    pub fn ring_caps(&self) -> Option<gst::Caps> {
+        let targets = self.targets.lock();
+        let ring = targets.ring.as_ref()?;
+        (!ring.gops.is_empty()).then(|| ring.caps.clone()).flatten()
+    }
+
+    /// Write the buffered packets to `clip` and keep feeding it live packets, without a gap:
+    /// nothing is pushed to the tap in between.
+    pub fn start_clip(&self, clip: Arc<Recorder>) -> Result<()> {
+        let mut targets = self.targets.lock();
+        let ring = targets
+            .ring
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("no pre-event buffer configured"))?;
+        // the whole ring is queued at once, on top of what may queue up later
+        clip.appsrc
+            .set_max_bytes(MAX_QUEUED_BYTES.saturating_add(ring.bytes));
+        for buffer in ring.packets() {
+            clip.offer(ring.caps.as_ref(), buffer);
+        }
+        targets.clips.push(clip);
+        Ok(())
+    }
+}
+
+/// Encoded packets of the last GOPs. Every GOP starts with a keyframe, so a clip
+/// written from the ring starts cleanly.
+struct PacketRing {
+    config: PreEventBuffer,
+    caps: Option<gst::Caps>,
+    gops: VecDeque<Vec<gst::Buffer>>,
+    bytes: u64,
+}
+
+impl PacketRing {
+    fn new(config: PreEventBuffer) -> Self {
+        Self {
+            config,
+            caps: None,
+            gops: VecDeque::new(),
+            bytes: 0,
+        }
+    }
+
+    fn clear(&mut self) {
+        self.gops.clear();
+        self.bytes = 0;
+    }
+
+    fn push(&mut self, caps: Option<&gst::Caps>, buffer: &gst::Buffer) {
+        if let Some(caps) = caps {
+            if self.caps.as_ref() != Some(caps) {
+                // packets of another stream format can't go into the same file
+                self.clear();
+                self.caps = Some(caps.clone());
+            }
+        }
+        if !buffer.flags().contains(gst::BufferFlags::DELTA_UNIT) {
+            self.gops.push_back(Vec::new());
+        }
+        // until the first keyframe there is nothing to align to
+        let Some(gop) = self.gops.back_mut() else {
+            return;
+        };
+        gop.push(buffer.clone());
+        self.bytes += buffer.size() as u64;
+
+        let max_bytes = self.config.max_bytes.unwrap_or(DEFAULT_RING_MAX_BYTES);
+        let duration_ns = self.config.duration_ms.saturating_mul(1_000_000);
+        // drop the oldest GOP while the others still cover the duration
+        while self.gops.len() > 1
+            && (self.bytes > max_bytes || self.span_after_oldest_ns() >= duration_ns)
+        {
+            if let Some(gop) = self.gops.pop_front() {
+                self.bytes -= gop.iter().map(|buffer| buffer.size() as u64).sum::<u64>();
+            }
+        }
+    }
+
+    /// Time covered by all but the oldest GOP.
+    fn span_after_oldest_ns(&self) -> u64 {
+        let first = self
+            .gops
+            .get(1)
+            .and_then(|gop| gop.first())
+            .and_then(|buffer| buffer.dts_or_pts());
+        let last = self
+            .gops
+            .back()
+            .and_then(|gop| gop.last())
+            .and_then(|buffer| buffer.dts_or_pts());
+        match (first, last) {
+            (Some(first), Some(last)) => last.saturating_sub(first).nseconds(),
+            _ => 0,
+        }
+    }
+
+    fn packets(&self) -> impl Iterator<Item = &gst::Buffer> {
+        self.gops.iter().flatten()
+    }
+}
+
+/// What `Recorder::new` writes.
+#[derive(Debug, Clone)]
+pub struct RecordingOptions {
+    pub path: String,
+    pub container: RecordingContainer,
+    /// The recording stops by itself once it is this long or large.
+    pub max_duration_ms: Option<u64>,
+    pub max_size_bytes: Option<u64>,
+    pub segments: Option<RecordingSegments>,
+}
+
+/// Writes an encoded stream to MP4 or MKV without re-encoding.
+///
+/// Buffers are offered from the backend's streaming thread and pushed by reference into a
+/// separate `appsrc ! parser ! splitmuxsink` pipeline, so the recording is finalized
+/// (EOS, index written) independently of the playback pipeline. Writing starts at the first
+/// keyframe, and again after each reconnect; timestamps are rebased to start at zero
+/// and continue across reconnects.
+pub struct Recorder {
+    options: RecordingOptions,
+    pipeline: gst::Pipeline,
+    appsrc: AppSrc,
+    on_event: RecordingEventCallback,
+    state: Mutex<RecorderState>,
+    files: Arc<Mutex<Vec<String>>>,
+    done: watch::Receiver<bool>,
+    finished: OnceCell<RecordingInfo>,
+}
+
+struct RecorderState {
+    caps: gst::Caps,
+    waiting_for_keyframe: bool,
+    /// Subtracted from incoming timestamps; recomputed after a discontinuity.
+    offset_ns: Option<i64>,
+    last_output_ns: Option<i64>,
+    bytes: u64,
+    started: bool,
+    stopping: bool,
+}
+
+impl Recorder {
+    pub fn new(
+        caps: gst::Caps,
+        options: RecordingOptions,
+        on_event: RecordingEventCallback,
+    ) -> Result<Arc<Self>> {
+        let media_type = caps
+            .structure(0)
+            .map(|structure| structure.name().to_string())
+            .unwrap_or_default();
+        let parser = match media_type.as_str() {
+            "video/x-h264" => "h264parse",
+            "video/x-h265" => "h265parse",
+            "image/jpeg" => "jpegparse",
+            "video/x-vp8" | "video/x-vp9" => "identity",
+            other => anyhow::bail!("recording {} streams is not supported", other),
+        };
+        let pipeline = gst::parse::launch(&format!(
+            "appsrc name=src is-live=true format=time max-bytes={MAX_QUEUED_BYTES} \
+             ! {parser} ! splitmuxsink name=mux"
+        ))
+        .context("recording pipeline launch")?
+        .downcast::<gst::Pipeline>()
+        .map_err(|_| anyhow::anyhow!("Not a pipeline"))?;
+        let appsrc = pipeline
+            .by_name("src")
+            .ok_or_else(|| anyhow::anyhow!("appsrc not found"))?
+            .downcast::<AppSrc>()
+            .map_err(|_| anyhow::anyhow!("src is not AppSrc"))?;
+        let mux = pipeline
+            .by_name("mux")
+            .ok_or_else(|| anyhow::anyhow!("splitmuxsink not found"))?;
+
+        mux.set_property(
+            "muxer-factory",
+            match options.container {
+                RecordingContainer::Mp4 => "mp4mux",
+                RecordingContainer::Mkv => "matroskamux",
+            },
+        );
+        match &options.segments {
+            Some(segments) => {
+                mux.set_property("location", segment_location(&options.path));
+                mux.set_property(
+                    "max-size-time",
+                    segments.duration_ms.saturating_mul(1_000_000),
+                );
+                if let Some(max_files) = segments.max_files {
+                    mux.set_property("max-files", max_files);
+                }
+            }
+            None => mux.set_property("location", &options.path),
+        }
+        appsrc.set_caps(Some(&caps));
+
+        let (done_tx, done) = watch::channel(false);
+        let recorder = Arc::new(Self {
+            options,
+            pipeline,
+            appsrc,
+            on_event,
+            state: Mutex::new(RecorderState {
+                caps,
+                waiting_for_keyframe: true,
+                offset_ns: None,
+                last_output_ns: None,
+                bytes: 0,
+                started: false,
+                stopping: false,
+            }),
+            files: Arc::new(Mutex::new(Vec::new())),
+            done,
+            finished: OnceCell::new(),
+        });
+
+        let bus = recorder
+            .pipeline
+            .bus()
+            .ok_or_else(|| anyhow::anyhow!("Failed to get recording pipeline bus"))?;
+        let weak = Arc::downgrade(&recorder);
+        let files = Arc::clone(&recorder.files);
+        bus.set_sync_handler(move |_, msg| {
+            match msg.view() {
+                gst::MessageView::Element(element) => {
+                    let location = element
+                        .structure()
+                        .filter(|s| s.name() == "splitmuxsink-fragment-opened")
+                        .and_then(|s| s.get::<String>("location").ok());
+                    if let Some(location) = location {
+                        let mut files = files.lock();
+                        if !files.contains(&location) {
+                            files.push(location);
+                        }
+                    }
+                }
+                gst::MessageView::Eos(_) => {
+                    let _ = done_tx.send(true);
+                }
+                gst::MessageView::Error(err) => {
+                    let _ = done_tx.send(true);
+                    if let Some(recorder) = weak.upgrade() {
+                        let error = StreamError::from_anyhow(
+                            ErrorBackend::Recorder,
+                            ErrorCategory::Internal,
+                            "Recording failed",
+                            &anyhow::Error::from(err.error()),
+                        );
+                        warn!("recorder: {}", error);
+                        (recorder.on_event)(StreamEvent::RecordingError(error));
+                        runtime::spawn(async move {
+                            recorder.finish().await;
+                        });
+                    }
+                }
+                _ => {}
+            }
+            gst::BusSyncReply::Drop
+        });
+
+        recorder
+            .pipeline
+            .set_state(gst::State::Playing)
+            .context("starting the recording pipeline")?;
+        Ok(recorder)
+    }
+
+    /// Whether the recording was finalized (stopped, limit reached or failed).
+    pub fn is_finished(&self) -> bool {
+        self.finished.initialized()
+    }
+
+    /// Resolves once the recording pipeline ended: finished, limit reached or failed.
+    pub async fn wait_finished(&self) {
+        let mut done = self.done.clone();
+        let _ = done.wait_for(|done| *done).await;
+    }
+
+    /// The next buffers come from a new pipeline: wait for a keyframe and
+    /// continue the timestamps where the recording left off.
+    fn discontinuity(&self) {
+        let mut state = self.state.lock();
+        state.waiting_for_keyframe = true;
+        state.offset_ns = None;
+    }
+
+    /// Called from the backend's streaming thread; never blocks.
+    fn offer(self: &Arc<Self>, caps: Option<&gst::Caps>, buffer: &gst::Buffer) {
+        let mut state = self.state.lock();
+        if state.stopping {
+            return;
+        }
+        if self.appsrc.current_level_bytes() + buffer.size() as u64 > self.appsrc.max_bytes() {
+            // the muxer or the disk stalled; stop instead of buffering without limit
+            state.stopping = true;
+            drop(state);
+            let error = StreamError::new(
+                ErrorBackend::Recorder,
+                ErrorCategory::Internal,
+                format!(
+                    "recording can't keep up, more than {} bytes queued",
+                    self.appsrc.max_bytes()
+                ),
+            );
+            warn!("recorder: {}", error);
+            (self.on_event)(StreamEvent::RecordingError(error));
+            let recorder = Arc::clone(self);
+            runtime::spawn(async move {
+                recorder.finish().await;
+            });
+            return;
+        }
+        if let Some(caps) = caps {
+            if *caps != state.caps {
+                debug!("recorder: caps changed to {}", caps);
+                state.caps = caps.clone();
+                self.appsrc.set_caps(Some(caps));
+            }
+        }
+        if state.waiting_for_keyframe {
+            if buffer.flags().contains(gst::BufferFlags::DELTA_UNIT) {
+                return;
+            }
+            state.waiting_for_keyframe = false;
+        }
+        let Some(timestamp) = buffer.dts_or_pts() else {
+            return;
+        };
+        let timestamp = timestamp.nseconds() as i64;
+        let offset = match state.offset_ns {
+            Some(offset) => offset,
+            None => {
+                let offset = match state.last_output_ns {
+                    Some(last) => timestamp - (last + RECONNECT_GAP_NS),
+                    None => timestamp,
+                };
+                state.offset_ns = Some(offset);
+                offset
+            }
+        };
+        let shift = |time: gst::ClockTime| {
+            gst::ClockTime::from_nseconds((time.nseconds() as i64 - offset).max(0) as u64)
+        };
+
+        let mut output = buffer.copy();
+        {
+            let output = output.make_mut();
+            output.set_pts(buffer.pts().map(shift));
+            output.set_dts(buffer.dts().map(shift));
+        }
+        let output_ns = (timestamp - offset).max(0);
+        state.last_output_ns = Some(output_ns);
+        state.bytes += buffer.size() as u64;
+        let first = !std::mem::replace(&mut state.started, true);
+        let limit_reached = self
+            .options
+            .max_duration_ms
+            .is_some_and(|max| output_ns as u64 >= max.saturating_mul(1_000_000))
+            || self
+                .options
+                .max_size_bytes
+                .is_some_and(|max| state.bytes >= max);
+        state.stopping = limit_reached;
+        drop(state);
+
+        if first {
+            info!("recorder: started writing {}", self.options.path);
+            (self.on_event)(StreamEvent::RecordingStarted {
+                path: self.options.path.clone(),
+            });
+        }
+        if let Err(e) = self.appsrc.push_buffer(output) {
+            debug!("recorder: push failed: {:?}", e);
+        }
+        if limit_reached {
+            info!("recorder: limit reached for {}", self.options.path);
+            let recorder = Arc::clone(self);
+            runtime::spawn(async move {
+                recorder.finish().await;
+            });
+        }
+    }
+
+    /// Finalize the files and report them with `RecordingStopped`.
+    /// Later calls return the same info without stopping again.
+    pub async fn finish(&self) -> RecordingInfo {
+        self.finished
+            .get_or_init(|| async {
+                self.state.lock().stopping = true;
+                let _ = self.appsrc.end_of_stream();
+                let mut done = self.done.clone();
+                if tokio::time::timeout(FINISH_TIMEOUT, done.wait_for(|done| *done))
+                    .await
+                    .is_err()
+                {
+                    warn!("recorder: {} not finalized in time", self.options.path);
+                }
+                let _ = self.pipeline.set_state(gst::State::Null);
+
+                let info = self.info();
+                info!("recorder: stopped, {:?}", info);
+                (self.on_event)(StreamEvent::RecordingStopped(info.clone()));
+                info
+            })
+            .await
+            .clone()
+    }
+
+    fn info(&self) -> RecordingInfo {
+        let mut files = self.files.lock().clone();
+        if files.is_empty() && self.options.segments.is_none() {
+            files.push(self.options.path.clone());
+        }
+        // ring recordings reuse names, list the remaining files only
+        let sizes: Vec<_> = files
+            .into_iter()
+            .filter_map(|file| {
+                let size = std::fs::metadata(&file).ok()?.len();
+                Some((file, size))
+            })
+            .collect();
+        let duration_ns = self.state.lock().last_output_ns.unwrap_or(0);
+        RecordingInfo {
+            size_bytes: sizes.iter().map(|(_, size)| size).sum(),
+            files: sizes.into_iter().map(|(file, _)| file).collect(),
+            duration_ms: duration_ns as u64 / 1_000_000,
+        }
+    }
+}
+
+impl Drop for Recorder {
+    fn drop(&mut self) {
+        let _ = self.pipeline.set_state(gst::State::Null);
+    }
+}
+
+/// `splitmuxsink` location for numbered segments: `clip.mp4` becomes `clip_00000.mp4`.
+/// A path that already has a printf-style pattern is used as is.
+fn segment_location(path: &str) -> String {
+    if path.contains('%') {
+        return path.to_string();
+    }
+    let path = Path::new(path);
+    let stem = path.with_extension("");
+    match path.extension() {
+        Some(extension) => format!("{}_%05d.{}", stem.display(), extension.to_string_lossy()),
+        None => format!("{}_%05d", stem.display()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packet(pts_ms: u64, size: usize, keyframe: bool) -> gst::Buffer {
+        let mut buffer = gst::Buffer::with_size(size).unwrap();
+        {
+            let buffer = buffer.get_mut().unwrap();
+            buffer.set_pts(gst::ClockTime::from_mseconds(pts_ms));
+            if !keyframe {
+                buffer.set_flags(gst::BufferFlags::DELTA_UNIT);
+            }
+        }
+        buffer
+    }
+
+    fn ring(duration_ms: u64, max_bytes: Option<u64>) -> PacketRing {
+        gst::init().unwrap();
+        PacketRing::new(PreEventBuffer {
+            duration_ms,
+            max_bytes,
+        })
+    }
+
+    fn pts_ms(ring: &PacketRing) -> Vec<u64> {
+        ring.packets()
+            .filter_map(|buffer| buffer.pts())
+            .map(|pts| pts.mseconds())
+            .collect()
+    }
+
+    #[test]
+    fn ring_drops_packets_before_the_first_keyframe() {
+        let mut ring = ring(1000, None);
+        ring.push(None, &packet(0, 10, false));
+        ring.push(None, &packet(40, 10, false));
+        assert_eq!(ring.packets().count(), 0);
+        assert_eq!(ring.bytes, 0);
+
+        ring.push(None, &packet(80, 10, true));
+        ring.push(None, &packet(120, 10, false));
+        assert_eq!(pts_ms(&ring), vec![80, 120]);
+        assert_eq!(ring.bytes, 20);
+    }
+
+    #[test]
+    fn ring_keeps_whole_gops_covering_the_duration() {
+        let mut ring = ring(1000, None);
+        for gop_start in [0, 1000, 2000] {
+            ring.push(None, &packet(gop_start, 10, true));
+            ring.push(None, &packet(gop_start + 500, 10, false));
+        }
+        assert_eq!(pts_ms(&ring), vec![1000, 1500, 2000, 2500]);
+        assert_eq!(ring.bytes, 40);
+    }
+
+    #[test]
+    fn ring_drops_old_gops_beyond_max_bytes() {
+        let mut ring = ring(60_000, Some(100));
+        ring.push(None, &packet(0, 60, true));
+        ring.push(None, &packet(1000, 60, true));
+        assert_eq!(pts_ms(&ring), vec![1000]);
+        assert_eq!(ring.bytes, 60);
+    }
+
+    #[test]
+    fn ring_never_drops_the_newest_gop() {
+        let mut ring = ring(0, Some(10));
+        ring.push(None, &packet(0, 60, true));
+        ring.push(None, &packet(40, 60, false));
+        assert_eq!(pts_ms(&ring), vec![0, 40]);
+    }
+
+    #[test]
+    fn ring_restarts_when_the_caps_change() {
+        let mut ring = ring(60_000, None);
+        let h264 = gst::Caps::builder("video/x-h264").build();
+        let h265 = gst::Caps::builder("video/x-h265").build();
+        ring.push(Some(&h264), &packet(0, 10, true));
+        ring.push(Some(&h264), &packet(40, 10, false));
+        ring.push(Some(&h265), &packet(80, 10, false));
+        assert_eq!(ring.packets().count(), 0);
+
+        ring.push(Some(&h265), &packet(120, 10, true));
+        assert_eq!(pts_ms(&ring), vec![120]);
+        assert_eq!(ring.caps, Some(h265));
+    }
+
+    #[test]
+    fn segment_location_numbers_before_the_extension() {
+        assert_eq!(segment_location("/tmp/clip.mp4"), "/tmp/clip_%05d.mp4");
+        assert_eq!(segment_location("/tmp/clip"), "/tmp/clip_%05d");
+        assert_eq!(segment_location("/tmp/clip_%03d.mkv"), "/tmp/clip_%03d.mkv");
+    }
+}
@@ -0,0 +1,168 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use gst::prelude::*;
+
+use crate::{
+    core::{texture::payload::SharedPixelData, types::SnapshotFormat},
+    dart_types::Snapshot,
+};
+
+const ENCODE_TIMEOUT: gst::ClockTime = gst::ClockTime::from_seconds(5);
+const DEFAULT_JPEG_QUALITY: u8 = 85;
+
+/// A frame to encode, with the timestamps reported in the `Snapshot`.
+pub struct SnapshotFrame {
+    pub sample: gst::Sample,
+    pub pts_ns: Option<u64>,
+    pub decoded_at: SystemTime,
+}
+
+/// Lets GStreamer read a shared frame without copying it.
+struct FrameBytes(SharedPixelData);
+
+impl AsRef<[u8]> for FrameBytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0.data
+    }
+}
+
+impl SnapshotFrame {
+    pub fn from_frame(frame: SharedPixelData) -> Self {
+        let caps =
+            gst_video::VideoInfo::builder(frame.format.video_format(), frame.width, frame.height)
+                .build()
+                .ok()
+                .and_then(|info| info.to_caps().ok());
+        let pts_ns = frame.pts_ns;
+        let decoded_at = frame.decoded_at;
+        let mut sample = gst::Sample::builder().buffer(&gst::Buffer::from_slice(FrameBytes(frame)));
+        if let Some(caps) = &caps {
+            sample = sample.caps(caps);
+        }
+        Self {
+            sample: sample.build(),
+            pts_ns,
+            decoded_at,
+        }
+    }
+
+    pub fn from_sample(sample: gst::Sample) -> Self {
+        let pts_ns = sample
+            .buffer()
+            .and_then(|buffer| buffer.pts())
+            .map(|pts| pts.nseconds());
+        Self {
+            sample,
+            pts_ns,
+            decoded_at: SystemTime::now(),
+        }
+    }
+}
+
+/// Encode the frame to PNG or JPEG, downscaled so its longer side is at most `max_size`.
+/// Blocks until encoded, so run it off the runtime.
+pub fn encode(
+    frame: SnapshotFrame,
+    format: SnapshotFormat,
+    quality: Option<u8>,
+    max_size: Option<u32>,
+) -> Result<Snapshot> {
+    let caps = frame
+        .sample
+        .caps()
+        .ok_or_else(|| anyhow::anyhow!("snapshot frame without caps"))?;
+    let video_info = gst_video::VideoInfo::from_caps(caps)?;
+    let (width, height) = fit_size(video_info.width(), video_info.height(), max_size);
+
+    let encoder = match format {
+        SnapshotFormat::Png => "pngenc".to_string(),
+        SnapshotFormat::Jpeg => format!(
+            "jpegenc quality={}",
+            quality.unwrap_or(DEFAULT_JPEG_QUALITY).min(100)
+        ),
+    };
+    let pipeline = gst::parse::launch(&format!(
+        "appsrc name=src format=time ! videoconvert ! videoscale \
+         ! video/x-raw,width={width},height={height} ! {encoder} ! appsink name=sink sync=false"
+    ))
+    .context("snapshot pipeline launch")?
+    .downcast::<gst::Pipeline>()
+    .map_err(|_| anyhow::anyhow!("Not a pipeline"))?;
+    let appsrc = pipeline
+        .by_name("src")
+        .ok_or_else(|| anyhow::anyhow!("appsrc not found"))?
+        .downcast::<gst_app::AppSrc>()
+        .map_err(|_| anyhow::anyhow!("src is not AppSrc"))?;
+    let appsink = pipeline
+        .by_name("sink")
+        .ok_or_else(|| anyhow::anyhow!("appsink not found"))?
+        .downcast::<gst_app::AppSink>()
+        .map_err(|_| anyhow::anyhow!("sink is not AppSink"))?;
+
+    appsrc.set_caps(Some(caps));
+    pipeline.set_state(gst::State::Playing)?;
+    let encoded = appsrc
+        .push_sample(&frame.sample)
+        .map_err(|e| anyhow::anyhow!("pushing snapshot frame: {:?}", e))
+        .and_then(|_| {
+            let _ = appsrc.end_of_stream();
+            appsink
+                .try_pull_sample(ENCODE_TIMEOUT)
+                .ok_or_else(|| anyhow::anyhow!("timed out encoding snapshot"))
+        });
+    let _ = pipeline.set_state(gst::State::Null);
+
+    let encoded = encoded?;
+    let buffer = encoded
+        .buffer()
+        .ok_or_else(|| anyhow::anyhow!("encoded snapshot without buffer"))?;
+    let data = buffer
+        .map_readable()
+        .map_err(|_| anyhow::anyhow!("mapping encoded snapshot"))?
+        .to_vec();
+    let captured_at_ms = frame
+        .decoded_at
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or(Duration::ZERO)
+        .as_millis() as i64;
+    Ok(Snapshot {
+        data,
+        width,
+        height,
+        pts_ms: frame.pts_ns.map(|pts| pts / 1_000_000),
+        captured_at_ms,
+    })
+}
+
+fn fit_size(width: u32, height: u32, max_size: Option<u32>) -> (u32, u32) {
+    match max_size {
+        Some(max_size) if max_size > 0 && width.max(height) > max_size => {
+            let ratio = max_size as f64 / width.max(height) as f64;
+            (
+                ((width as f64 * ratio).round() as u32).max(1),
+                ((height as f64 * ratio).round() as u32).max(1),
+            )
+        }
+        _ => (width, height),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fit_size_keeps_small_frames() {
+        assert_eq!(fit_size(640, 480, None), (640, 480));
+        assert_eq!(fit_size(640, 480, Some(0)), (640, 480));
+        assert_eq!(fit_size(640, 480, Some(640)), (640, 480));
+    }
+
+    #[test]
+    fn fit_size_scales_the_longer_side_down() {
+        assert_eq!(fit_size(1920, 1080, Some(960)), (960, 540));
+        assert_eq!(fit_size(1080, 1920, Some(480)), (270, 480));
+        assert_eq!(fit_size(4000, 2, Some(100)), (100, 1));
+    }
+}
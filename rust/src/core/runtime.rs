@@ -0,0 +1,60 @@
+use std::{future::Future, sync::OnceLock};
+
+use anyhow::Context;
+use log::{info, warn};
+use tokio::{runtime::Runtime, task::JoinHandle};
+
+use crate::core::types::RuntimeConfig;
+
+const DEFAULT_THREAD_NAME: &str = "flutter-realtime-player";
+
+static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+
+fn build_runtime(config: &RuntimeConfig) -> anyhow::Result<Runtime> {
+    let mut builder = tokio::runtime::Builder::new_multi_thread();
+    builder.enable_all().thread_name(
+        config
+            .thread_name
+            .clone()
+            .unwrap_or_else(|| DEFAULT_THREAD_NAME.to_string()),
+    );
+    if let Some(worker_threads) = config.worker_threads {
+        builder.worker_threads(worker_threads.max(1) as usize);
+    }
+    builder.build().context("building tokio runtime")
+}
+
+/// Build the crate runtime with the given config.
+/// Has no effect if the runtime already exists (e.g. a session was created before init).
+pub fn init(config: &RuntimeConfig) -> anyhow::Result<()> {
+    if RUNTIME.get().is_some() {
+        warn!("tokio runtime already initialized, ignoring {:?}", config);
+        return Ok(());
+    }
+    let runtime = build_runtime(config)?;
+    if RUNTIME.set(runtime).is_err() {
+        warn!(
+            "tokio runtime initialized concurrently, ignoring {:?}",
+            config
+        );
+    } else {
+        info!("tokio runtime initialized with {:?}", config);
+    }
+    Ok(())
+}
+
+/// The crate runtime, built with the default config on first use.
+pub fn runtime() -> &'static Runtime {
+    RUNTIME.get_or_init(|| {
+        build_runtime(&RuntimeConfig::default()).expect("building default tokio runtime")
+    })
+}
+
+/// Spawn a task on the crate runtime, from any thread.
+pub fn spawn<F>(future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    runtime().spawn(future)
+}
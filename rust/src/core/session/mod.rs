@@ -1,24 +1,47 @@
+pub mod outputs;
 pub mod registry;
+pub mod sink;
+pub mod startup;
+pub mod watchdog;
 
-use std::time::SystemTime;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
 
 use async_trait::async_trait;
 use parking_lot::Mutex;
+use tokio::sync::{watch, Notify};
 
 use crate::{
-    core::types::DartCombinedStream,
-    dart_types::{StreamEvent, StreamMessage, StreamState},
+    core::{
+        input::{
+            video_chain::{ChainFormat, VideoChain, VideoChainSettings},
+            InputEvent,
+        },
+        output::{frame_tap::FrameTap, recorder::RecordingTap},
+        runtime,
+        session::{
+            outputs::{OutputChannel, SessionOutputs},
+            startup::{StartupPhase, StartupTracker},
+            watchdog::FrameWatchdog,
+        },
+        texture::payload::SharedPixelData,
+        types::{SessionOptions, VideoConfig},
+    },
+    dart_types::{ErrorBackend, ErrorCategory, StreamError, StreamEvent, StreamState},
 };
 
 #[async_trait]
 pub trait VideoSession: Send + Sync {
     fn session_id(&self) -> i64;
     fn engine_handle(&self) -> i64;
-    fn last_alive_mark(&self) -> SystemTime;
-    fn make_alive(&self);
-    /// this should not block at all.
+    fn session_common(&self) -> &VideoSessionCommon;
+    /// this should not block at all and must be callable from any thread,
+    /// including the tokio runtime and the platform main thread.
     /// either set a flag or abort a task.
-    /// it is expected that a few moments later (or immediately) any actual flutter textures will be destroyed.
+    /// it is expected that a few moments later (or immediately) the pipeline is stopped,
+    /// after which `VideoSessionCommon::wait_torn_down` resolves.
     fn terminate(&self);
     async fn seek(&self, ts: u64) -> anyhow::Result<()>;
     async fn go_to_live_stream(&self) -> anyhow::Result<()>;
@@ -26,41 +49,265 @@ pub trait VideoSession: Send + Sync {
 }
 
 pub struct VideoSessionCommon {
+    /// Id of the consumer that created the session; other consumers may attach later.
     pub session_id: i64,
     pub engine_handle: i64,
-    pub last_alive_mark: Mutex<SystemTime>,
-    pub combined_sink: DartCombinedStream,
+    /// Set when created with `SessionOptions.share_decode`:
+    /// `create_playable` calls with an equal config attach to this session.
+    pub shared_config: Option<VideoConfig>,
+    /// Kept to start a replacement backend with the same options on `switch_source`.
+    pub options: SessionOptions,
+    /// Carries frames, state and events to the outputs; these are swapped when a
+    /// standby session is promoted or the source switched.
+    output_channel: OutputChannel,
+    /// Last `OriginVideoSize`, replayed to outputs swapped in later.
+    origin_size: Mutex<Option<StreamEvent>>,
+    pub frame_watchdog: Arc<FrameWatchdog>,
+    pub startup: StartupTracker,
+    reconnect_requested: AtomicBool,
+    reconnect_notify: Notify,
+    /// Set by `switch_source`: `Loading` isn't forwarded until the new source plays or
+    /// fails, so consumers keep showing the previous source's texture meanwhile.
+    switching_source: AtomicBool,
+    torn_down: watch::Sender<bool>,
+    /// Scaling (and other decode-side processing) requested for this session.
+    video_settings: Mutex<VideoChainSettings>,
+    /// Chain of the running pipeline; replaced whenever the backend rebuilds it.
+    video_chain: Mutex<Option<Arc<VideoChain>>>,
+    /// Subscribed frame taps; like the settings they carry over to rebuilt chains.
+    frame_taps: Mutex<Vec<Arc<FrameTap>>>,
+    /// Encoded stream of the running pipeline, for recordings and pre-event clips.
+    recording: RecordingTap,
 }
 
 impl VideoSessionCommon {
-    pub fn new(session_id: i64, engine_handle: i64, combined_sink: DartCombinedStream) -> Self {
+    pub fn new(
+        session_id: i64,
+        engine_handle: i64,
+        config: &VideoConfig,
+        options: &SessionOptions,
+    ) -> Self {
         Self {
             session_id,
             engine_handle,
-            last_alive_mark: Mutex::new(SystemTime::now()),
-            combined_sink,
+            shared_config: options.share_decode.then(|| config.clone()),
+            options: options.clone(),
+            output_channel: OutputChannel::new(),
+            origin_size: Mutex::new(None),
+            frame_watchdog: Arc::new(FrameWatchdog::new(options.stall_watchdog.clone())),
+            startup: StartupTracker::new(),
+            reconnect_requested: AtomicBool::new(false),
+            reconnect_notify: Notify::new(),
+            switching_source: AtomicBool::new(false),
+            torn_down: watch::Sender::new(false),
+            video_settings: Mutex::new(VideoChainSettings {
+                max_frame_rate: options.max_frame_rate,
+                ..Default::default()
+            }),
+            video_chain: Mutex::new(None),
+            frame_taps: Mutex::new(Vec::new()),
+            recording: RecordingTap::new(options.pre_event_buffer.clone()),
+        }
+    }
+
+    /// Wrap `appsink` in a video chain using this session's settings.
+    /// Backends link the returned chain's bin after their decoder.
+    pub fn build_video_chain(&self, appsink: &gst_app::AppSink) -> anyhow::Result<Arc<VideoChain>> {
+        let settings = self.video_settings.lock();
+        let chain = VideoChain::new(appsink, settings.clone())?;
+        chain.set_frame_taps(self.frame_taps());
+        *self.video_chain.lock() = Some(Arc::clone(&chain));
+        Ok(chain)
+    }
+
+    /// Chain of the running pipeline; `None` for backends without one.
+    pub fn video_chain(&self) -> Option<Arc<VideoChain>> {
+        self.video_chain.lock().clone()
+    }
+
+    /// Format seen by the running video chain; `None` for backends without one.
+    pub fn video_format(&self) -> Option<ChainFormat> {
+        self.video_chain.lock().as_ref()?.format()
+    }
+
+    pub fn video_settings(&self) -> VideoChainSettings {
+        self.video_settings.lock().clone()
+    }
+
+    /// Change the video chain settings; the running pipeline picks them up without a restart.
+    pub fn update_video_settings(&self, update: impl FnOnce(&mut VideoChainSettings)) {
+        let mut settings = self.video_settings.lock();
+        update(&mut settings);
+        if let Some(chain) = self.video_chain.lock().as_ref() {
+            chain.update(settings.clone());
+        }
+        if let Some(output_size) = &settings.output_size {
+            self.outputs()
+                .set_placeholder_size((output_size.width, output_size.height));
         }
     }
 
-    pub fn get_last_alive_mark(&self) -> SystemTime {
-        *self.last_alive_mark.lock()
+    pub fn recording(&self) -> &RecordingTap {
+        &self.recording
     }
 
-    pub fn mark_alive(&self) {
-        *self.last_alive_mark.lock() = SystemTime::now();
+    pub fn has_frame_taps(&self) -> bool {
+        !self.frame_taps.lock().is_empty()
+    }
+
+    pub fn frame_taps(&self) -> Vec<Arc<FrameTap>> {
+        self.frame_taps.lock().clone()
+    }
+
+    /// Add, remove or carry over frame taps; the running chain feeds the new set right away.
+    pub fn update_frame_taps(&self, update: impl FnOnce(&mut Vec<Arc<FrameTap>>)) {
+        let mut taps = self.frame_taps.lock();
+        update(&mut taps);
+        if let Some(chain) = self.video_chain.lock().as_ref() {
+            chain.set_frame_taps(taps.clone());
+        }
+    }
+
+    pub fn outputs(&self) -> Arc<SessionOutputs> {
+        self.output_channel.outputs()
+    }
+
+    /// Route this session's frames, state and events to `outputs` from now on
+    /// and return the previous ones. The current state is replayed to the new consumers.
+    pub fn swap_outputs(&self, outputs: Arc<SessionOutputs>) -> Arc<SessionOutputs> {
+        let previous = self.output_channel.replace_outputs(Arc::clone(&outputs));
+        if let Some(state) = previous.last_state() {
+            outputs.send_state(state);
+        }
+        if let Some(origin_size) = self.origin_size.lock().clone() {
+            outputs.send_event(origin_size);
+        }
+        // a promoted standby session shows its last keyframe until the next one is decoded
+        if let Some(frame) = previous.latest_frame() {
+            outputs.push_frame(frame);
+        }
+        previous
     }
 
     pub fn send_event_msg(&self, msg: StreamEvent) {
-        let combined_msg = StreamMessage::Event(msg);
-        if let Err(e) = self.combined_sink.add(combined_msg) {
-            log::error!("Failed to send event message: {}", e);
+        if let StreamEvent::OriginVideoSize {
+            transformed_width,
+            transformed_height,
+            ..
+        } = msg
+        {
+            *self.origin_size.lock() = Some(msg.clone());
+            // a requested output size already sized the placeholder
+            if self.video_settings.lock().output_size.is_none() {
+                self.outputs()
+                    .set_placeholder_size((transformed_width as u32, transformed_height as u32));
+            }
+        }
+        self.output_channel.send(InputEvent::Event(msg));
+    }
+
+    /// Whether decoded frames are rendered anywhere. Standby sessions have no texture,
+    /// so backends only decode their keyframes (see `frame_sink::throttle_standby_decode`).
+    pub fn wants_frames(&self) -> bool {
+        self.outputs().wants_frames()
+    }
+
+    /// Hands a decoded frame to the outputs.
+    pub fn push_frame(&self, frame: SharedPixelData) {
+        self.frame_watchdog.frame_arrived();
+        self.output_channel.send(InputEvent::Frame(frame));
+    }
+
+    /// Close the outputs once the backend stopped, after the state sent before.
+    /// Textures are released when their consumers are destroyed.
+    pub fn close_outputs(&self) {
+        self.output_channel.send(InputEvent::Close);
+        // recordings end with their session; finalize them so the files stay readable
+        for recorder in self.recording.take_all() {
+            runtime::spawn(async move {
+                recorder.finish().await;
+            });
+        }
+    }
+
+    /// Ask the running backend to drop its current connection/pipeline and rebuild it.
+    pub fn request_reconnect(&self) {
+        self.reconnect_requested.store(true, Ordering::SeqCst);
+        self.reconnect_notify.notify_one();
+    }
+
+    /// Resolves once `request_reconnect` was called; meant for the backend's select loop.
+    pub async fn reconnect_requested(&self) {
+        loop {
+            self.reconnect_notify.notified().await;
+            if self.reconnect_requested.swap(false, Ordering::SeqCst) {
+                return;
+            }
         }
     }
 
+    /// Keep the consumers' current state while this backend replaces another one.
+    pub fn begin_source_switch(&self) {
+        self.switching_source.store(true, Ordering::SeqCst);
+    }
+
+    pub fn mark_startup(&self, phase: StartupPhase) {
+        if let Some(report) = self.startup.mark(phase) {
+            log::info!("Session {} startup: {:?}", self.session_id, report);
+            self.send_event_msg(StreamEvent::StartupReport(report));
+        }
+    }
+
+    /// Settles the startup with an error, unless the first frame already arrived.
+    pub fn fail_startup(&self, error: StreamError) {
+        if self.startup.fail(error.clone()) {
+            log::warn!("Session {} startup failed: {}", self.session_id, error);
+            self.send_event_msg(StreamEvent::StartupFailed(error));
+        }
+    }
+
+    /// Called once the backend task finished: the pipeline is in NULL.
+    /// Textures are released separately, when their consumers are destroyed.
+    /// A startup that is still pending at this point is settled as aborted.
+    pub fn mark_torn_down(&self) {
+        self.fail_startup(StreamError::new(
+            ErrorBackend::Registry,
+            ErrorCategory::Cancelled,
+            "session stopped before the first frame",
+        ));
+        self.torn_down.send_replace(true);
+    }
+
+    pub fn is_torn_down(&self) -> bool {
+        *self.torn_down.borrow()
+    }
+
+    pub async fn wait_torn_down(&self) {
+        let mut torn_down = self.torn_down.subscribe();
+        // the sender lives in `self`, so this can't fail while we borrow it
+        let _ = torn_down.wait_for(|done| *done).await;
+    }
+
     pub fn send_state_msg(&self, msg: StreamState) {
-        let combined_msg = StreamMessage::State(msg);
-        if let Err(e) = self.combined_sink.add(combined_msg) {
-            log::error!("Failed to send state message: {}", e);
+        if matches!(msg, StreamState::Playing { .. }) {
+            self.frame_watchdog.arm();
+        } else {
+            self.frame_watchdog.disarm();
         }
+        if self.switching_source.load(Ordering::SeqCst) {
+            if matches!(msg, StreamState::Loading) {
+                return;
+            }
+            self.switching_source.store(false, Ordering::SeqCst);
+        }
+        self.output_channel.send(InputEvent::State(msg));
+    }
+
+    /// `Playing` carries each consumer's own texture id, filled in by `SessionOutputs`.
+    pub fn send_playing_msg(&self, seekable: bool) {
+        self.send_state_msg(StreamState::Playing {
+            texture_id: -1,
+            seekable,
+        });
     }
 }
@@ -0,0 +1,625 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, SystemTime},
+};
+
+use anyhow::{bail, Result};
+use log::{debug, error, trace};
+use parking_lot::{Mutex, RwLock};
+use tokio::sync::oneshot;
+
+use crate::{
+    core::{
+        input::{InputEvent, InputEventReceiver, InputEventSender},
+        output::{
+            flutter_pixelbuffer::FlutterPixelBuffer,
+            headless::HeadlessFrameSink,
+            presentation::{self, EndFrame},
+            FrameOutput,
+        },
+        runtime,
+        session::sink::SharedMessageSink,
+        texture::payload::{PayloadHolder, RawFrame, SharedPixelData},
+        types::{EndFramePolicy, KeepAlivePolicy, PlaceholderFrame, PresentationPolicy},
+    },
+    dart_types::{StreamEvent, StreamMessage, StreamState},
+};
+
+const DEFAULT_KEEP_ALIVE_TIMEOUT: Duration = Duration::from_millis(5000);
+/// Frames queued for the output loop beyond this are dropped instead of piling up.
+const MAX_PENDING_FRAMES: usize = 4;
+
+pub fn keep_alive_timeout(policy: Option<&KeepAlivePolicy>) -> Option<Duration> {
+    match policy {
+        None => Some(DEFAULT_KEEP_ALIVE_TIMEOUT),
+        Some(KeepAlivePolicy::Timeout { timeout_ms }) => Some(Duration::from_millis(*timeout_ms)),
+        Some(KeepAlivePolicy::Disabled) => None,
+    }
+}
+
+/// Where a consumer's frames are rendered.
+enum ConsumerFrames {
+    Texture(FlutterPixelBuffer),
+    Headless(Arc<HeadlessFrameSink>),
+}
+
+/// A Dart-side user of a session (a widget): it has its own session id, stream sink,
+/// keep-alive and texture, while the decode behind it may be shared with other consumers.
+pub struct SessionConsumer {
+    pub consumer_id: i64,
+    pub engine_handle: i64,
+    sink: SharedMessageSink,
+    last_alive_mark: Mutex<SystemTime>,
+    /// `None` means the consumer is never reaped by the supervisor.
+    pub keep_alive_timeout: Option<Duration>,
+    /// `None` for standby consumers, which only receive state and events.
+    frames: Option<ConsumerFrames>,
+}
+
+impl SessionConsumer {
+    /// `None` for standby and headless consumers.
+    pub fn texture_id(&self) -> Option<i64> {
+        match &self.frames {
+            Some(ConsumerFrames::Texture(pixel_buffer)) => Some(pixel_buffer.texture_id()),
+            _ => None,
+        }
+    }
+
+    pub fn headless(&self) -> Option<Arc<HeadlessFrameSink>> {
+        match &self.frames {
+            Some(ConsumerFrames::Headless(sink)) => Some(Arc::clone(sink)),
+            _ => None,
+        }
+    }
+
+    /// Re-render the texture after the holder's frame was replaced outside the frame flow.
+    fn redraw(&self) {
+        if let Some(ConsumerFrames::Texture(pixel_buffer)) = &self.frames {
+            pixel_buffer.mark_frame_available();
+        }
+    }
+
+    /// Close the consumer's frame output; for a texture the receiver resolves once
+    /// it was dropped on the platform main thread.
+    fn release(&self) -> Option<oneshot::Receiver<()>> {
+        match self.frames.as_ref()? {
+            ConsumerFrames::Texture(pixel_buffer) => Some(pixel_buffer.release()),
+            ConsumerFrames::Headless(sink) => {
+                sink.close();
+                None
+            }
+        }
+    }
+
+    fn frame_output(&self) -> Option<&dyn FrameOutput> {
+        match self.frames.as_ref()? {
+            ConsumerFrames::Texture(pixel_buffer) => Some(pixel_buffer),
+            ConsumerFrames::Headless(sink) => Some(sink.as_ref()),
+        }
+    }
+
+    pub fn get_last_alive_mark(&self) -> SystemTime {
+        *self.last_alive_mark.lock()
+    }
+
+    pub fn mark_alive(&self) {
+        *self.last_alive_mark.lock() = SystemTime::now();
+    }
+
+    pub fn is_expired(&self, now: SystemTime) -> bool {
+        let Some(timeout) = self.keep_alive_timeout else {
+            return false;
+        };
+        now.duration_since(self.get_last_alive_mark())
+            .map(|dur| dur > timeout)
+            .unwrap_or(false)
+    }
+
+    fn send(&self, msg: StreamMessage) {
+        if let Err(e) = self.sink.send(msg) {
+            log::error!(
+                "Failed to send message to consumer {}: {}",
+                self.consumer_id,
+                e
+            );
+        }
+    }
+}
+
+impl FrameOutput for SessionConsumer {
+    fn wants_frames(&self) -> bool {
+        self.frame_output()
+            .is_some_and(|output| output.wants_frames())
+    }
+
+    fn on_frame(&self, frame: &SharedPixelData) {
+        if let Some(output) = self.frame_output() {
+            output.on_frame(frame);
+        }
+    }
+
+    fn on_state(&self, state: &StreamState) {
+        let state = match state {
+            // every consumer has its own texture
+            StreamState::Playing { seekable, .. } => StreamState::Playing {
+                texture_id: self.texture_id().unwrap_or(-1),
+                seekable: *seekable,
+            },
+            other => other.clone(),
+        };
+        self.send(StreamMessage::State(state));
+    }
+
+    fn on_event(&self, event: &StreamEvent) {
+        self.send(StreamMessage::Event(event.clone()));
+    }
+
+    fn close(&self) {
+        if let Some(output) = self.frame_output() {
+            output.close();
+        }
+    }
+}
+
+/// What `SessionOutputs::detach` left behind.
+pub struct Detached {
+    /// Number of consumers still attached.
+    pub remaining: usize,
+    /// Resolves once the detached consumer's texture was dropped on the platform main
+    /// thread; `None` for consumers without a texture.
+    pub texture_released: Option<oneshot::Receiver<()>>,
+}
+
+/// Everything a decode feeds: the shared `PayloadHolder`, the consumers whose
+/// textures render it and any additional `FrameOutput`s.
+/// Frames are shared by all textures without extra copies.
+pub struct SessionOutputs {
+    payload_holder: Arc<PayloadHolder>,
+    consumers: RwLock<Vec<Arc<SessionConsumer>>>,
+    outputs: RwLock<Vec<(u64, Arc<dyn FrameOutput>)>>,
+    next_output_id: AtomicU64,
+    /// Set once the last consumer detached; the backend is terminating.
+    closed: AtomicBool,
+    /// Replayed to consumers attaching after the session started.
+    last_state: Mutex<Option<StreamState>>,
+    presentation: Mutex<PresentationPolicy>,
+    /// Last frame from the backend; dimmed or overlaid frames are derived from it.
+    last_decoded: Mutex<Option<SharedPixelData>>,
+    /// Generated placeholders have this size, so the texture doesn't jump at the first frame.
+    placeholder_size: Mutex<(u32, u32)>,
+}
+
+impl Default for SessionOutputs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SessionOutputs {
+    pub fn new() -> Self {
+        Self {
+            payload_holder: Arc::new(PayloadHolder::new()),
+            consumers: RwLock::new(Vec::new()),
+            outputs: RwLock::new(Vec::new()),
+            next_output_id: AtomicU64::new(1),
+            closed: AtomicBool::new(false),
+            last_state: Mutex::new(None),
+            presentation: Mutex::new(PresentationPolicy::default()),
+            last_decoded: Mutex::new(None),
+            placeholder_size: Mutex::new(presentation::DEFAULT_PLACEHOLDER_SIZE),
+        }
+    }
+
+    /// Apply `policy` to the textures; a poster is decoded in the background.
+    pub fn set_presentation(self: &Arc<Self>, policy: PresentationPolicy) -> Result<()> {
+        let (width, height) = *self.placeholder_size.lock();
+        let placeholder = presentation::placeholder_frame(&policy.placeholder, (width, height))?;
+        let poster_uri = match &policy.placeholder {
+            PlaceholderFrame::Poster { uri } => Some(uri.clone()),
+            _ => None,
+        };
+        self.payload_holder.set_placeholder(
+            placeholder.unwrap_or_else(|| Arc::new(RawFrame::black(width, height))),
+        );
+        *self.presentation.lock() = policy;
+        self.redraw();
+        if let Some(uri) = poster_uri {
+            self.load_poster(uri);
+        }
+        Ok(())
+    }
+
+    /// Render generated placeholders at `size`: the requested output size, or the frame
+    /// size once known. Posters and app-supplied frames keep their own size.
+    pub fn set_placeholder_size(&self, size: (u32, u32)) {
+        if size.0 == 0 || size.1 == 0 {
+            return;
+        }
+        if std::mem::replace(&mut *self.placeholder_size.lock(), size) == size {
+            return;
+        }
+        let placeholder = self.presentation.lock().placeholder.clone();
+        if !matches!(
+            placeholder,
+            PlaceholderFrame::Black | PlaceholderFrame::Transparent
+        ) {
+            return;
+        }
+        if let Ok(Some(frame)) = presentation::placeholder_frame(&placeholder, size) {
+            self.payload_holder.set_placeholder(frame);
+            self.redraw();
+        }
+    }
+
+    fn load_poster(self: &Arc<Self>, uri: String) {
+        let outputs = Arc::downgrade(self);
+        runtime::runtime().spawn_blocking(move || {
+            let poster = match presentation::decode_poster(&uri) {
+                Ok(poster) => poster,
+                Err(e) => {
+                    error!("Failed to load poster {}: {}", uri, e);
+                    return;
+                }
+            };
+            let Some(outputs) = outputs.upgrade() else {
+                return;
+            };
+            // the policy may have changed while decoding
+            if outputs.presentation.lock().placeholder != (PlaceholderFrame::Poster { uri }) {
+                return;
+            }
+            outputs.payload_holder.set_placeholder(Arc::new(poster));
+            outputs.redraw();
+        });
+    }
+
+    fn redraw(&self) {
+        for consumer in self.consumers.read().iter() {
+            consumer.redraw();
+        }
+    }
+
+    /// Show `frame` on the textures only; other outputs keep what the backend decoded.
+    fn present(&self, frame: SharedPixelData) {
+        self.payload_holder.set_payload(frame);
+        self.redraw();
+    }
+
+    fn present_end_frame(&self, policy: EndFramePolicy) {
+        match presentation::end_frame(policy, self.last_decoded.lock().clone()) {
+            EndFrame::Keep => {}
+            EndFrame::Show(frame) => self.present(frame),
+            EndFrame::Clear => {
+                self.payload_holder.clear();
+                self.redraw();
+            }
+        }
+    }
+
+    pub fn payload_holder(&self) -> &Arc<PayloadHolder> {
+        &self.payload_holder
+    }
+
+    /// Create a texture for the consumer on the platform main thread and start
+    /// delivering state and events to its sink.
+    /// Fails once the last consumer detached, since the decode is going away.
+    pub fn attach(
+        &self,
+        consumer_id: i64,
+        engine_handle: i64,
+        sink: SharedMessageSink,
+        keep_alive: Option<&KeepAlivePolicy>,
+    ) -> Result<Arc<SessionConsumer>> {
+        let pixel_buffer =
+            FlutterPixelBuffer::create(engine_handle, Arc::clone(&self.payload_holder))?;
+        self.insert(SessionConsumer {
+            consumer_id,
+            engine_handle,
+            sink,
+            last_alive_mark: Mutex::new(SystemTime::now()),
+            keep_alive_timeout: keep_alive_timeout(keep_alive),
+            frames: Some(ConsumerFrames::Texture(pixel_buffer)),
+        })
+    }
+
+    /// Attach a consumer without a texture: it receives state and events only,
+    /// until its session is promoted onto another session's textures.
+    pub fn attach_standby(
+        &self,
+        consumer_id: i64,
+        engine_handle: i64,
+        sink: SharedMessageSink,
+        keep_alive: Option<&KeepAlivePolicy>,
+    ) -> Result<Arc<SessionConsumer>> {
+        self.insert(SessionConsumer {
+            consumer_id,
+            engine_handle,
+            sink,
+            last_alive_mark: Mutex::new(SystemTime::now()),
+            keep_alive_timeout: keep_alive_timeout(keep_alive),
+            frames: None,
+        })
+    }
+
+    /// Attach a consumer whose frames go to an in-memory `HeadlessFrameSink`
+    /// instead of a texture; no Flutter engine or platform main thread is involved.
+    pub fn attach_headless(
+        &self,
+        consumer_id: i64,
+        engine_handle: i64,
+        sink: SharedMessageSink,
+        keep_alive: Option<&KeepAlivePolicy>,
+        history_len: usize,
+    ) -> Result<Arc<SessionConsumer>> {
+        self.insert(SessionConsumer {
+            consumer_id,
+            engine_handle,
+            sink,
+            last_alive_mark: Mutex::new(SystemTime::now()),
+            keep_alive_timeout: keep_alive_timeout(keep_alive),
+            frames: Some(ConsumerFrames::Headless(Arc::new(HeadlessFrameSink::new(
+                history_len,
+            )))),
+        })
+    }
+
+    fn insert(&self, consumer: SessionConsumer) -> Result<Arc<SessionConsumer>> {
+        let consumer = Arc::new(consumer);
+        {
+            let mut consumers = self.consumers.write();
+            if !self.closed.load(Ordering::SeqCst) {
+                if let Some(state) = self.last_state.lock().as_ref() {
+                    consumer.on_state(state);
+                }
+                consumers.push(Arc::clone(&consumer));
+                return Ok(consumer);
+            }
+        }
+        consumer.close();
+        bail!(
+            "session is shutting down, consumer {} not attached",
+            consumer.consumer_id
+        )
+    }
+
+    /// Remove the consumer and drop its texture on the platform main thread.
+    pub fn detach(&self, consumer_id: i64) -> Detached {
+        let (removed, remaining) = {
+            let mut consumers = self.consumers.write();
+            let removed = consumers
+                .iter()
+                .position(|consumer| consumer.consumer_id == consumer_id)
+                .map(|index| consumers.remove(index));
+            if consumers.is_empty() {
+                self.closed.store(true, Ordering::SeqCst);
+            }
+            (removed, consumers.len())
+        };
+        let texture_released = match removed {
+            Some(consumer) => {
+                debug!("Consumer {} detached, {} left", consumer_id, remaining);
+                consumer.release()
+            }
+            None => {
+                error!("Consumer {} not attached, can't detach", consumer_id);
+                None
+            }
+        };
+        Detached {
+            remaining,
+            texture_released,
+        }
+    }
+
+    pub fn consumer(&self, consumer_id: i64) -> Option<Arc<SessionConsumer>> {
+        self.consumers
+            .read()
+            .iter()
+            .find(|consumer| consumer.consumer_id == consumer_id)
+            .cloned()
+    }
+
+    pub fn consumers(&self) -> Vec<Arc<SessionConsumer>> {
+        self.consumers.read().clone()
+    }
+
+    /// Attach an additional output; it gets the current state right away.
+    /// Returns an id for `remove_output`.
+    pub fn add_output(&self, output: Arc<dyn FrameOutput>) -> u64 {
+        let output_id = self.next_output_id.fetch_add(1, Ordering::SeqCst);
+        // hold the consumers lock like `insert`, so no state change slips in between
+        let _consumers = self.consumers.read();
+        if let Some(state) = self.last_state.lock().as_ref() {
+            output.on_state(state);
+        }
+        self.outputs.write().push((output_id, output));
+        output_id
+    }
+
+    /// Detach and close an output added with `add_output`.
+    pub fn remove_output(&self, output_id: u64) -> Option<Arc<dyn FrameOutput>> {
+        let output = {
+            let mut outputs = self.outputs.write();
+            let index = outputs.iter().position(|(id, _)| *id == output_id)?;
+            outputs.remove(index).1
+        };
+        output.close();
+        Some(output)
+    }
+
+    /// Whether any consumer or output renders frames; backends decode only keyframes otherwise.
+    pub fn wants_frames(&self) -> bool {
+        self.consumers
+            .read()
+            .iter()
+            .any(|consumer| consumer.wants_frames())
+            || self
+                .outputs
+                .read()
+                .iter()
+                .any(|(_, output)| output.wants_frames())
+    }
+
+    /// Last frame from the backend, without presentation effects like dimming.
+    pub fn latest_frame(&self) -> Option<SharedPixelData> {
+        self.last_decoded.lock().clone()
+    }
+
+    pub fn last_state(&self) -> Option<StreamState> {
+        self.last_state.lock().clone()
+    }
+
+    /// Replace the displayed frame with a black one of the same size. It isn't decoded,
+    /// so only the textures show it; the previous frame is dropped as `latest_frame`.
+    pub fn show_black_frame(&self) {
+        let (width, height) = self
+            .payload_holder
+            .current_frame()
+            .map_or((640, 480), |frame| (frame.width, frame.height));
+        *self.last_decoded.lock() = None;
+        self.present(Arc::new(RawFrame::black(width, height)));
+    }
+
+    pub fn dispatch(&self, event: InputEvent) {
+        match event {
+            InputEvent::Frame(frame) => self.push_frame(frame),
+            InputEvent::State(state) => self.send_state(state),
+            InputEvent::Event(event) => self.send_event(event),
+            InputEvent::Close => self.close(),
+        }
+    }
+
+    pub fn push_frame(&self, frame: SharedPixelData) {
+        *self.last_decoded.lock() = Some(Arc::clone(&frame));
+        self.payload_holder.set_payload(Arc::clone(&frame));
+        for consumer in self.consumers.read().iter() {
+            consumer.on_frame(&frame);
+        }
+        for (_, output) in self.outputs.read().iter() {
+            if output.wants_frames() {
+                output.on_frame(&frame);
+            }
+        }
+    }
+
+    pub fn send_state(&self, state: StreamState) {
+        let end_policy = match &state {
+            StreamState::Stopped => Some(self.presentation.lock().on_stop),
+            StreamState::Error(_) => Some(self.presentation.lock().on_error),
+            _ => None,
+        };
+        if let Some(policy) = end_policy {
+            self.present_end_frame(policy);
+        }
+        // hold the read lock so that `attach` can't slip in between and miss the state
+        let consumers = self.consumers.read();
+        for consumer in consumers.iter() {
+            consumer.on_state(&state);
+        }
+        for (_, output) in self.outputs.read().iter() {
+            output.on_state(&state);
+        }
+        *self.last_state.lock() = Some(state);
+    }
+
+    pub fn send_event(&self, event: StreamEvent) {
+        if matches!(event, StreamEvent::Reconnecting { .. }) {
+            let overlay = self.presentation.lock().reconnect_overlay_argb;
+            let last_decoded = self.last_decoded.lock().clone();
+            if let (Some(argb), Some(frame)) = (overlay, last_decoded) {
+                self.present(Arc::new(frame.with_overlay(argb)));
+            }
+        }
+        for consumer in self.consumers.read().iter() {
+            consumer.on_event(&event);
+        }
+        for (_, output) in self.outputs.read().iter() {
+            output.on_event(&event);
+        }
+    }
+
+    /// Close every output once the backend stopped. Consumers stay attached until they are
+    /// destroyed, and so do their textures, which keep showing the end frame.
+    pub fn close(&self) {
+        for consumer in self.consumers.read().iter() {
+            if let Some(sink) = consumer.headless() {
+                sink.close();
+            }
+        }
+        let outputs = std::mem::take(&mut *self.outputs.write());
+        for (_, output) in outputs {
+            output.close();
+        }
+    }
+}
+
+/// Sending half of the channel from an input backend to its session's current outputs.
+/// The receiving half is drained by `run_output_loop` on the crate runtime,
+/// which ends once the channel is dropped together with its session.
+pub struct OutputChannel {
+    event_tx: InputEventSender,
+    pending_frames: Arc<AtomicUsize>,
+    target: Arc<RwLock<Arc<SessionOutputs>>>,
+}
+
+impl Default for OutputChannel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OutputChannel {
+    pub fn new() -> Self {
+        let (event_tx, event_rx) = flume::unbounded();
+        let pending_frames = Arc::new(AtomicUsize::new(0));
+        let target = Arc::new(RwLock::new(Arc::new(SessionOutputs::new())));
+        runtime::spawn(run_output_loop(
+            event_rx,
+            Arc::clone(&pending_frames),
+            Arc::clone(&target),
+        ));
+        Self {
+            event_tx,
+            pending_frames,
+            target,
+        }
+    }
+
+    pub fn outputs(&self) -> Arc<SessionOutputs> {
+        Arc::clone(&self.target.read())
+    }
+
+    /// Route everything still queued and sent later to `outputs`; returns the previous ones.
+    pub fn replace_outputs(&self, outputs: Arc<SessionOutputs>) -> Arc<SessionOutputs> {
+        std::mem::replace(&mut *self.target.write(), outputs)
+    }
+
+    pub fn send(&self, event: InputEvent) {
+        if matches!(event, InputEvent::Frame(_))
+            && self.pending_frames.fetch_add(1, Ordering::SeqCst) >= MAX_PENDING_FRAMES
+        {
+            self.pending_frames.fetch_sub(1, Ordering::SeqCst);
+            trace!("Outputs are lagging behind, dropping frame");
+            return;
+        }
+        // the receiver only goes away together with the runtime
+        let _ = self.event_tx.send(event);
+    }
+}
+
+async fn run_output_loop(
+    event_rx: InputEventReceiver,
+    pending_frames: Arc<AtomicUsize>,
+    target: Arc<RwLock<Arc<SessionOutputs>>>,
+) {
+    while let Ok(event) = event_rx.recv_async().await {
+        if matches!(event, InputEvent::Frame(_)) {
+            pending_frames.fetch_sub(1, Ordering::SeqCst);
+        }
+        let outputs = Arc::clone(&target.read());
+        outputs.dispatch(event);
+    }
+}
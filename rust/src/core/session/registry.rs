@@ -1,12 +1,37 @@
 use std::{
     collections::HashMap,
     sync::{Arc, RwLock},
-    time::SystemTime,
+    time::{Duration, SystemTime},
 };
 
+use anyhow::Context;
 use log::{debug, error, info};
 
-use crate::core::session::VideoSession;
+use crate::{
+    core::{
+        input::factory::{self, BackendTask},
+        output::{
+            frame_tap::{FrameTap, SharedFrameTapSink},
+            headless::HeadlessFrameSink,
+            recorder::{Recorder, RecordingEventCallback, RecordingOptions},
+            snapshot::{self, SnapshotFrame},
+        },
+        runtime,
+        session::outputs::{Detached, SessionConsumer},
+        session::{
+            outputs::SessionOutputs, sink::SharedMessageSink, watchdog, VideoSession,
+            VideoSessionCommon,
+        },
+        types::{
+            FrameOutputMode, FrameTapConfig, ImageAdjustments, OutputSize, PreEventBuffer,
+            PresentationPolicy, RecordingContainer, RecordingSegments, SessionOptions,
+            SnapshotFormat, SwitchPlaceholder, VideoConfig, VideoTransform,
+        },
+    },
+    dart_types::{
+        ErrorBackend, ErrorCategory, RecordingInfo, Snapshot, StartupReport, StreamError, TapFrame,
+    },
+};
 
 pub fn init() -> anyhow::Result<()> {
     gst::init().map_err(|e| anyhow::anyhow!("Failed to initialize GStreamer: {:?}", e))?;
@@ -14,6 +39,9 @@ pub fn init() -> anyhow::Result<()> {
     Ok(())
 }
 
+const SUPERVISOR_INTERVAL: Duration = Duration::from_secs(1);
+const SNAPSHOT_FRAME_TIMEOUT: Duration = Duration::from_secs(5);
+
 lazy_static::lazy_static! {
     static ref SESSION_CACHE: RwLock<HashMap<i64, Arc<dyn VideoSession>>> =
         RwLock::new(HashMap::new());
@@ -33,13 +61,38 @@ pub fn insert_session(session_id: i64, session: Arc<dyn VideoSession>) {
     SESSION_CACHE.write().unwrap().insert(session_id, session);
 }
 
+/// A running session created with `share_decode` and an equal config, if any.
+pub fn find_shared_session(config: &VideoConfig) -> Option<Arc<dyn VideoSession>> {
+    let session_cache = SESSION_CACHE.read().unwrap();
+    session_cache
+        .values()
+        .find(|session| {
+            let common = session.session_common();
+            common.shared_config.as_ref() == Some(config) && !common.is_torn_down()
+        })
+        .cloned()
+}
+
+fn session_not_found(session_id: i64) -> anyhow::Error {
+    StreamError::new(
+        ErrorBackend::Registry,
+        ErrorCategory::SessionNotFound,
+        format!("Session {} not found", session_id),
+    )
+    .into()
+}
+
 fn remove_session(session_id: i64) -> Option<Arc<dyn VideoSession>> {
     let mut session_cache = SESSION_CACHE.write().unwrap();
     session_cache.remove(&session_id)
 }
 
-pub async fn stream_alive_tester_task() {
+/// Supervisor running on the crate runtime: reaps consumers whose keep-alive expired.
+pub async fn supervisor_task() {
+    let mut interval = tokio::time::interval(SUPERVISOR_INTERVAL);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
     loop {
+        interval.tick().await;
         let mut closed_sessions = Vec::new();
 
         let holders = get_all_sessions()
@@ -48,10 +101,11 @@ pub async fn stream_alive_tester_task() {
             .collect::<Vec<_>>();
         let now = SystemTime::now();
         for (session_id, holder) in holders {
-            let expired = now
-                .duration_since(holder.last_alive_mark())
-                .map(|dur| dur.as_millis() > 5000)
-                .unwrap_or(false);
+            let expired = holder
+                .session_common()
+                .outputs()
+                .consumer(session_id)
+                .map_or(true, |consumer| consumer.is_expired(now));
             if expired {
                 closed_sessions.push(session_id);
             }
@@ -66,14 +120,15 @@ pub async fn stream_alive_tester_task() {
                 destroy_stream_session(session_id);
             }
         }
-        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
     }
 }
 
 pub fn mark_session_alive(session_id: i64) {
     log::trace!("mark_session_alive {}", session_id);
-    if let Some(session) = get_session(session_id) {
-        session.make_alive();
+    if let Some(consumer) = get_session(session_id)
+        .and_then(|session| session.session_common().outputs().consumer(session_id))
+    {
+        consumer.mark_alive();
     }
 }
 
@@ -86,7 +141,8 @@ pub fn destroy_engine_streams(engine_handle: i64) {
     let to_remove = holders
         .into_iter()
         .filter_map(|(session_id, holder)| {
-            if holder.engine_handle() == engine_handle {
+            let consumer = holder.session_common().outputs().consumer(session_id)?;
+            if consumer.engine_handle == engine_handle {
                 Some(session_id)
             } else {
                 None
@@ -107,17 +163,613 @@ pub fn destroy_stream_session(session_id: i64) {
     debug!("Active sessions at destroy: {:?}", active_sessions);
     let session = remove_session(session_id);
     if let Some(holder) = session {
-        log::debug!(
-            "Session {} removed from cache, destroying in a new thread",
+        log::debug!("Session {} removed from cache", session_id);
+        // nobody waits for the texture here, it is released in the background
+        release_consumer(session_id, holder.as_ref());
+    } else {
+        info!(
+            "No stream session found for session id: {}, can't remove",
             session_id
         );
-        holder.terminate();
+    }
+}
+
+/// Detaches the consumer from its session and terminates the session if it was the last one.
+fn release_consumer(session_id: i64, holder: &dyn VideoSession) -> Detached {
+    let detached = holder.session_common().outputs().detach(session_id);
+    if detached.remaining > 0 {
+        debug!(
+            "Session {} still has {} consumer(s), keeping it running",
+            holder.session_id(),
+            detached.remaining
+        );
     } else {
+        holder.terminate();
+    }
+    detached
+}
+
+/// Like `destroy_stream_session`, but resolves only once the consumer's texture was
+/// dropped on the platform main thread and, if it was the last consumer,
+/// the session's pipeline is in NULL.
+pub async fn destroy_stream_session_and_wait(
+    session_id: i64,
+    timeout: Duration,
+) -> anyhow::Result<()> {
+    log::debug!("Destroying stream session and waiting: {}", session_id);
+    let Some(holder) = remove_session(session_id) else {
         info!(
             "No stream session found for session id: {}, can't remove",
             session_id
         );
+        return Ok(());
+    };
+    let detached = release_consumer(session_id, holder.as_ref());
+    let released = async {
+        if let Some(texture_released) = detached.texture_released {
+            // only fails if the platform main thread dropped the task with the texture
+            let _ = texture_released.await;
+        }
+        if detached.remaining == 0 {
+            holder.session_common().wait_torn_down().await;
+        }
+    };
+    tokio::time::timeout(timeout, released).await.map_err(|_| {
+        StreamError::new(
+            ErrorBackend::Registry,
+            ErrorCategory::Timeout,
+            format!(
+                "Session {} was not torn down within {}ms",
+                session_id,
+                timeout.as_millis()
+            ),
+        )
+        .into()
+    })
+}
+
+/// Waits for the session's first decoded frame.
+/// On a startup error or timeout the consumer is destroyed and the `StreamError` returned.
+pub async fn await_first_frame(
+    session_id: i64,
+    timeout: Duration,
+) -> anyhow::Result<StartupReport> {
+    let session = get_session(session_id).ok_or_else(|| session_not_found(session_id))?;
+    let common = session.session_common();
+    let error = match tokio::time::timeout(timeout, common.startup.wait()).await {
+        Ok(Ok(report)) => return Ok(report),
+        Ok(Err(error)) => error,
+        Err(_) => {
+            let error = StreamError::new(
+                ErrorBackend::Registry,
+                ErrorCategory::Timeout,
+                format!("no frame within {}ms", timeout.as_millis()),
+            );
+            // other consumers of a shared session may still be willing to wait
+            if common.outputs().consumers().len() <= 1 {
+                common.fail_startup(error.clone());
+            }
+            error
+        }
+    };
+    destroy_stream_session(session_id);
+    Err(anyhow::Error::new(error))
+}
+
+/// Points every consumer of `outputs` at `session`, e.g. after its source was switched.
+pub fn rebind_consumers(outputs: &SessionOutputs, session: Arc<dyn VideoSession>) {
+    let mut session_cache = SESSION_CACHE.write().unwrap();
+    for consumer in outputs.consumers() {
+        session_cache.insert(consumer.consumer_id, Arc::clone(&session));
+    }
+}
+
+/// Creates the backend for `config` without running it yet.
+pub fn create_backend(
+    config: VideoConfig,
+    session_common: VideoSessionCommon,
+) -> anyhow::Result<BackendTask> {
+    factory::create(config, session_common)
+}
+
+/// Spawns the backend on the crate runtime, plus its watchdog if enabled.
+pub fn spawn_backend(task: BackendTask) -> Arc<dyn VideoSession> {
+    let BackendTask { session, run } = task;
+    let session_clone = Arc::clone(&session);
+    runtime::spawn(async move {
+        let result = run.await;
+        session_clone.session_common().mark_torn_down();
+        result
+    });
+    if session.session_common().frame_watchdog.is_enabled() {
+        runtime::spawn(watchdog::watch_session(Arc::downgrade(&session)));
+    }
+    session
+}
+
+lazy_static::lazy_static! {
+    static ref SESSION_COUNTER: std::sync::Mutex<i64> = std::sync::Mutex::new(0);
+    static ref FRAME_TAP_COUNTER: std::sync::Mutex<i64> = std::sync::Mutex::new(0);
+}
+
+/// Raises the session counter and returns the new value as a session id.
+pub fn next_session_id() -> i64 {
+    let mut session_counter = SESSION_COUNTER.lock().unwrap();
+    *session_counter += 1;
+    *session_counter
+}
+
+/// Creates the session `session_id` delivering its state and events to `sink`,
+/// joining an existing decode if `options.share_decode` allows it.
+pub async fn create_session(
+    session_id: i64,
+    engine_handle: i64,
+    config: VideoConfig,
+    options: SessionOptions,
+    sink: SharedMessageSink,
+) -> anyhow::Result<()> {
+    let await_first_frame_timeout = options
+        .await_first_frame_timeout_ms
+        .map(Duration::from_millis);
+    if options.share_decode && !options.standby {
+        if let Some(session) = find_shared_session(&config) {
+            match attach_consumer(
+                &session.session_common().outputs(),
+                session_id,
+                engine_handle,
+                Arc::clone(&sink),
+                &options,
+            ) {
+                Ok(_) => {
+                    debug!(
+                        "Session {} shares the decode of session {}",
+                        session_id,
+                        session.session_id()
+                    );
+                    insert_session(session_id, session);
+                    if let Some(timeout) = await_first_frame_timeout {
+                        await_first_frame(session_id, timeout).await?;
+                    }
+                    return Ok(());
+                }
+                Err(e) => debug!("Not sharing decode, starting a new session: {}", e),
+            }
+        }
+    }
+    let session_common = VideoSessionCommon::new(session_id, engine_handle, &config, &options);
+    session_common
+        .outputs()
+        .set_presentation(options.presentation.clone())?;
+    attach_consumer(
+        &session_common.outputs(),
+        session_id,
+        engine_handle,
+        sink,
+        &options,
+    )?;
+    let session = spawn_backend(create_backend(config, session_common)?);
+    insert_session(session_id, session);
+    if let Some(timeout) = await_first_frame_timeout {
+        await_first_frame(session_id, timeout).await?;
+    }
+    Ok(())
+}
+
+fn attach_consumer(
+    outputs: &SessionOutputs,
+    session_id: i64,
+    engine_handle: i64,
+    sink: SharedMessageSink,
+    options: &SessionOptions,
+) -> anyhow::Result<Arc<SessionConsumer>> {
+    let keep_alive = options.keep_alive.as_ref();
+    if options.standby {
+        return outputs.attach_standby(session_id, engine_handle, sink, keep_alive);
+    }
+    match options.frame_output {
+        FrameOutputMode::FlutterTexture => {
+            outputs.attach(session_id, engine_handle, sink, keep_alive)
+        }
+        FrameOutputMode::Headless { history_len } => outputs.attach_headless(
+            session_id,
+            engine_handle,
+            sink,
+            keep_alive,
+            history_len as usize,
+        ),
+    }
+}
+
+/// The in-memory frame sink of a session created with `FrameOutputMode::Headless`.
+pub fn headless_output(session_id: i64) -> Option<Arc<HeadlessFrameSink>> {
+    get_session(session_id)?
+        .session_common()
+        .outputs()
+        .consumer(session_id)?
+        .headless()
+}
+
+/// Stops the session's backend and starts one for `config` behind the same outputs,
+/// so its consumer keeps its texture id.
+pub fn switch_source(
+    session_id: i64,
+    config: VideoConfig,
+    placeholder: SwitchPlaceholder,
+) -> anyhow::Result<()> {
+    let current = get_session(session_id).ok_or_else(|| session_not_found(session_id))?;
+    let current_common = current.session_common();
+    let outputs = current_common.outputs();
+    if outputs.consumers().len() > 1 {
+        anyhow::bail!(
+            "Session {} shares its decode with other sessions, can't switch its source",
+            session_id
+        );
+    }
+
+    let session_common = VideoSessionCommon::new(
+        current.session_id(),
+        current.engine_handle(),
+        &config,
+        &current_common.options,
+    );
+    session_common.update_video_settings(|settings| *settings = current_common.video_settings());
+    session_common.update_frame_taps(|taps| *taps = current_common.frame_taps());
+    session_common
+        .recording()
+        .set_pre_event_buffer(current_common.recording().pre_event_buffer());
+    session_common.begin_source_switch();
+    let task = create_backend(config, session_common)?;
+    task.session
+        .session_common()
+        .swap_outputs(Arc::clone(&outputs));
+    // the old backend renders into outputs nobody watches until it stopped
+    current_common.swap_outputs(Arc::new(SessionOutputs::new()));
+    current.terminate();
+
+    if placeholder == SwitchPlaceholder::Black {
+        outputs.show_black_frame();
+    }
+    let session = spawn_backend(task);
+    rebind_consumers(&outputs, session);
+    info!("Session {} switched its source", session_id);
+    Ok(())
+}
+
+/// Swaps the outputs of the standby session and the target session, so the standby
+/// backend renders into the target's textures, then stops the target's previous backend.
+pub fn promote_standby_session(
+    standby_session_id: i64,
+    target_session_id: i64,
+) -> anyhow::Result<()> {
+    let standby =
+        get_session(standby_session_id).ok_or_else(|| session_not_found(standby_session_id))?;
+    let target =
+        get_session(target_session_id).ok_or_else(|| session_not_found(target_session_id))?;
+    if Arc::ptr_eq(&standby, &target) {
+        anyhow::bail!(
+            "Session {} can't be promoted onto itself",
+            standby_session_id
+        );
+    }
+    if standby.session_common().wants_frames() {
+        anyhow::bail!("Session {} is not a standby session", standby_session_id);
     }
+
+    let target_outputs = target.session_common().outputs();
+    let standby_outputs = standby
+        .session_common()
+        .swap_outputs(Arc::clone(&target_outputs));
+    target
+        .session_common()
+        .swap_outputs(Arc::clone(&standby_outputs));
+    rebind_consumers(&target_outputs, Arc::clone(&standby));
+    for consumer in standby_outputs.consumers() {
+        remove_session(consumer.consumer_id);
+    }
+    // the standby consumers are gone, so the previous backend has nobody left to feed
+    for consumer in standby_outputs.consumers() {
+        standby_outputs.detach(consumer.consumer_id);
+    }
+    target.terminate();
+    info!(
+        "Session {} promoted onto session {}",
+        standby_session_id, target_session_id
+    );
+    Ok(())
+}
+
+/// Scale the session's frames to `output_size` (`None` restores the decoded size).
+/// For a shared decode this applies to every consumer of it.
+pub fn set_output_size_session(
+    session_id: i64,
+    output_size: Option<OutputSize>,
+) -> anyhow::Result<()> {
+    let session = get_session(session_id).ok_or_else(|| session_not_found(session_id))?;
+    session
+        .session_common()
+        .update_video_settings(|settings| settings.output_size = output_size);
+    Ok(())
+}
+
+/// Rotate, flip, crop and zoom the session's video in its decode pipeline.
+pub fn set_video_transform_session(
+    session_id: i64,
+    transform: VideoTransform,
+) -> anyhow::Result<()> {
+    let session = get_session(session_id).ok_or_else(|| session_not_found(session_id))?;
+    session
+        .session_common()
+        .update_video_settings(|settings| settings.transform = transform);
+    Ok(())
+}
+
+/// Only change the zoom part of the session's transform, e.g. while pinching.
+pub fn set_digital_zoom_session(
+    session_id: i64,
+    zoom: f64,
+    pan_x: f64,
+    pan_y: f64,
+) -> anyhow::Result<()> {
+    let session = get_session(session_id).ok_or_else(|| session_not_found(session_id))?;
+    session.session_common().update_video_settings(|settings| {
+        settings.transform.zoom = zoom;
+        settings.transform.pan_x = pan_x;
+        settings.transform.pan_y = pan_y;
+    });
+    Ok(())
+}
+
+/// `None` renders every decoded frame again.
+pub fn set_max_frame_rate_session(
+    session_id: i64,
+    max_frame_rate: Option<u32>,
+) -> anyhow::Result<()> {
+    let session = get_session(session_id).ok_or_else(|| session_not_found(session_id))?;
+    session
+        .session_common()
+        .update_video_settings(|settings| settings.max_frame_rate = max_frame_rate);
+    Ok(())
+}
+
+/// Deinterlacing and color adjustments of the session's video, applied live.
+pub fn set_image_adjustments_session(
+    session_id: i64,
+    adjustments: ImageAdjustments,
+) -> anyhow::Result<()> {
+    let session = get_session(session_id).ok_or_else(|| session_not_found(session_id))?;
+    session
+        .session_common()
+        .update_video_settings(|settings| settings.adjustments = adjustments);
+    Ok(())
+}
+
+/// Change how the session's textures present the phases without decoded frames.
+pub fn set_presentation_session(session_id: i64, policy: PresentationPolicy) -> anyhow::Result<()> {
+    let session = get_session(session_id).ok_or_else(|| session_not_found(session_id))?;
+    session.session_common().outputs().set_presentation(policy)
+}
+
+/// Encode the session's latest frame, or with `full_resolution` the next decoded frame
+/// before any transform or scaling. If `path` is set the image is written there
+/// and the returned `data` is empty.
+pub async fn capture_snapshot(
+    session_id: i64,
+    format: SnapshotFormat,
+    quality: Option<u8>,
+    max_size: Option<u32>,
+    full_resolution: bool,
+    path: Option<String>,
+) -> anyhow::Result<Snapshot> {
+    let session = get_session(session_id).ok_or_else(|| session_not_found(session_id))?;
+    let common = session.session_common();
+    let frame = if full_resolution {
+        let chain = common.video_chain().ok_or_else(|| {
+            anyhow::anyhow!("Session {} has no video chain to capture from", session_id)
+        })?;
+        let sample = tokio::time::timeout(SNAPSHOT_FRAME_TIMEOUT, chain.capture_next_frame()?)
+            .await
+            .context("timed out waiting for the next frame")?
+            .context("pipeline stopped before the next frame")?;
+        SnapshotFrame::from_sample(sample)
+    } else {
+        let frame = common
+            .outputs()
+            .latest_frame()
+            .ok_or_else(|| anyhow::anyhow!("Session {} has no frame yet", session_id))?;
+        SnapshotFrame::from_frame(frame)
+    };
+    runtime::runtime()
+        .spawn_blocking(move || {
+            let mut snapshot = snapshot::encode(frame, format, quality, max_size)?;
+            if let Some(path) = path {
+                std::fs::write(&path, &snapshot.data)
+                    .with_context(|| format!("writing snapshot to {}", path))?;
+                snapshot.data.clear();
+            }
+            Ok(snapshot)
+        })
+        .await?
+}
+
+/// Deliver the session's frames to `sink` until `remove_frame_tap_session`; returns the tap id.
+/// The tap follows the session across reconnects and `switch_source`.
+pub fn add_frame_tap_session(
+    session_id: i64,
+    config: FrameTapConfig,
+    sink: SharedFrameTapSink,
+) -> anyhow::Result<i64> {
+    insert_frame_tap(session_id, |tap_id| FrameTap::new(tap_id, config, sink))
+}
+
+/// Like `add_frame_tap_session`, but the frames wait for `next_frame_session`.
+pub fn add_pulled_frame_tap_session(
+    session_id: i64,
+    config: FrameTapConfig,
+) -> anyhow::Result<i64> {
+    insert_frame_tap(session_id, |tap_id| FrameTap::pulled(tap_id, config))
+}
+
+fn insert_frame_tap(
+    session_id: i64,
+    create: impl FnOnce(i64) -> anyhow::Result<Arc<FrameTap>>,
+) -> anyhow::Result<i64> {
+    let session = get_session(session_id).ok_or_else(|| session_not_found(session_id))?;
+    let tap_id = {
+        let mut counter = FRAME_TAP_COUNTER.lock().unwrap();
+        *counter += 1;
+        *counter
+    };
+    let tap = create(tap_id)?;
+    session
+        .session_common()
+        .update_frame_taps(|taps| taps.push(tap));
+    debug!("frame tap {} added to session {}", tap_id, session_id);
+    Ok(tap_id)
+}
+
+pub fn remove_frame_tap_session(session_id: i64, tap_id: i64) -> anyhow::Result<()> {
+    let session = get_session(session_id).ok_or_else(|| session_not_found(session_id))?;
+    let mut removed = false;
+    session.session_common().update_frame_taps(|taps| {
+        let count = taps.len();
+        taps.retain(|tap| tap.id != tap_id);
+        removed = taps.len() != count;
+    });
+    anyhow::ensure!(
+        removed,
+        "Session {} has no frame tap {}",
+        session_id,
+        tap_id
+    );
+    Ok(())
+}
+
+/// Waits for the next frame of a tap added with `add_pulled_frame_tap_session`;
+/// `None` once the tap was removed or its session ended.
+pub async fn next_frame_session(session_id: i64, tap_id: i64) -> anyhow::Result<Option<TapFrame>> {
+    let session = get_session(session_id).ok_or_else(|| session_not_found(session_id))?;
+    let frames = session
+        .session_common()
+        .frame_taps()
+        .iter()
+        .find(|tap| tap.id == tap_id)
+        .ok_or_else(|| anyhow::anyhow!("Session {} has no frame tap {}", session_id, tap_id))?
+        .frames()
+        .ok_or_else(|| anyhow::anyhow!("Frame tap {} delivers to a sink", tap_id))?;
+    // neither the session nor the tap is held while waiting, so both can go away
+    drop(session);
+    Ok(frames.recv_async().await.ok())
+}
+
+/// Record the session's encoded stream to `path` without re-encoding, starting at the
+/// next keyframe. Progress is reported with the `Recording*` events.
+pub fn start_recording_session(
+    session_id: i64,
+    path: String,
+    container: RecordingContainer,
+    max_duration_ms: Option<u64>,
+    max_size_bytes: Option<u64>,
+    segments: Option<RecordingSegments>,
+) -> anyhow::Result<()> {
+    let session = get_session(session_id).ok_or_else(|| session_not_found(session_id))?;
+    let recording = session.session_common().recording();
+    anyhow::ensure!(
+        !recording.is_recording(),
+        "Session {} is already recording",
+        session_id
+    );
+    let caps = recording.caps().ok_or_else(|| {
+        StreamError::new(
+            ErrorBackend::Recorder,
+            ErrorCategory::Unavailable,
+            format!("Session {} has no stream to record yet", session_id),
+        )
+    })?;
+    let recorder = Recorder::new(
+        caps,
+        RecordingOptions {
+            path,
+            container,
+            max_duration_ms,
+            max_size_bytes,
+            segments,
+        },
+        recording_events(session_id),
+    )?;
+    recording.start(recorder)
+}
+
+/// Routes a recorder's events to whichever backend currently serves `session_id`.
+fn recording_events(session_id: i64) -> RecordingEventCallback {
+    Arc::new(move |event| {
+        if let Some(session) = get_session(session_id) {
+            session.session_common().send_event_msg(event);
+        }
+    })
+}
+
+/// Change the session's pre-event buffer; `None` disables it and drops what was buffered.
+pub fn set_pre_event_buffer_session(
+    session_id: i64,
+    config: Option<PreEventBuffer>,
+) -> anyhow::Result<()> {
+    let session = get_session(session_id).ok_or_else(|| session_not_found(session_id))?;
+    session
+        .session_common()
+        .recording()
+        .set_pre_event_buffer(config);
+    Ok(())
+}
+
+/// Write the pre-event buffer plus the next `post_event_ms` of the stream to `path`.
+/// Resolves once the file is finalized; independent of `start_recording`.
+pub async fn save_pre_event_clip_session(
+    session_id: i64,
+    path: String,
+    container: RecordingContainer,
+    post_event_ms: u64,
+) -> anyhow::Result<RecordingInfo> {
+    let clip = {
+        let session = get_session(session_id).ok_or_else(|| session_not_found(session_id))?;
+        let recording = session.session_common().recording();
+        let caps = recording.ring_caps().ok_or_else(|| {
+            StreamError::new(
+                ErrorBackend::Recorder,
+                ErrorCategory::Unavailable,
+                format!("Session {} has nothing in its pre-event buffer", session_id),
+            )
+        })?;
+        let clip = Recorder::new(
+            caps,
+            RecordingOptions {
+                path,
+                container,
+                max_duration_ms: None,
+                max_size_bytes: None,
+                segments: None,
+            },
+            recording_events(session_id),
+        )?;
+        recording.start_clip(Arc::clone(&clip))?;
+        clip
+    };
+    // the clip is finished early if the session ends meanwhile
+    tokio::select! {
+        _ = tokio::time::sleep(Duration::from_millis(post_event_ms)) => {}
+        _ = clip.wait_finished() => {}
+    }
+    Ok(clip.finish().await)
+}
+
+/// Finalize the session's recording and return its files.
+pub async fn stop_recording_session(session_id: i64) -> anyhow::Result<RecordingInfo> {
+    let session = get_session(session_id).ok_or_else(|| session_not_found(session_id))?;
+    let recorder = session
+        .session_common()
+        .recording()
+        .take()
+        .ok_or_else(|| anyhow::anyhow!("Session {} is not recording", session_id))?;
+    Ok(recorder.finish().await)
 }
 
 pub async fn seek_session(session_id: i64, ts: u64) -> anyhow::Result<()> {
@@ -135,7 +787,7 @@ pub async fn seek_session(session_id: i64, ts: u64) -> anyhow::Result<()> {
         }
         None => {
             error!("Session {} not found for seek operation", session_id);
-            anyhow::bail!("Session {} not found", session_id);
+            Err(session_not_found(session_id))
         }
     }
 }
@@ -148,7 +800,7 @@ pub async fn wsc_rtp_live_session(session_id: i64) -> anyhow::Result<()> {
         Ok(())
     } else {
         error!("Session {} not found for go_live operation", session_id);
-        anyhow::bail!("Session {} not found", session_id);
+        Err(session_not_found(session_id))
     }
 }
 
@@ -167,6 +819,6 @@ pub async fn set_speed_session(session_id: i64, speed: f64) -> anyhow::Result<()
         Ok(())
     } else {
         error!("Session {} not found for set_speed operation", session_id);
-        anyhow::bail!("Session {} not found", session_id);
+        Err(session_not_found(session_id))
     }
 }
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::{core::types::DartCombinedStream, dart_types::StreamMessage};
+
+/// Where a consumer's state and events are delivered.
+/// The FRB `StreamSink` is one implementation; Rust hosts and tests use channels.
+pub trait MessageSink: Send + Sync {
+    fn send(&self, msg: StreamMessage) -> Result<()>;
+}
+
+pub type SharedMessageSink = Arc<dyn MessageSink>;
+
+impl MessageSink for DartCombinedStream {
+    fn send(&self, msg: StreamMessage) -> Result<()> {
+        self.add(msg)
+            .map_err(|e| anyhow::anyhow!("Dart stream closed: {}", e))
+    }
+}
+
+impl MessageSink for flume::Sender<StreamMessage> {
+    fn send(&self, msg: StreamMessage) -> Result<()> {
+        flume::Sender::send(self, msg).map_err(|_| anyhow::anyhow!("receiver dropped"))
+    }
+}
+
+impl MessageSink for tokio::sync::mpsc::UnboundedSender<StreamMessage> {
+    fn send(&self, msg: StreamMessage) -> Result<()> {
+        tokio::sync::mpsc::UnboundedSender::send(self, msg)
+            .map_err(|_| anyhow::anyhow!("receiver dropped"))
+    }
+}
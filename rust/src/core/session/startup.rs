@@ -0,0 +1,116 @@
+use std::time::Instant;
+
+use parking_lot::Mutex;
+use tokio::sync::watch;
+
+use crate::dart_types::{StartupReport, StreamError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartupPhase {
+    Connected,
+    Handshake,
+    Preroll,
+    FirstFrame,
+}
+
+#[derive(Default)]
+struct PhaseMarks {
+    connected: Option<Instant>,
+    handshake: Option<Instant>,
+    preroll: Option<Instant>,
+    attempts: u32,
+}
+
+pub type StartupOutcome = Result<StartupReport, StreamError>;
+
+/// Records when each startup phase of a session completed, until the first frame
+/// (or a final startup error) settles the outcome.
+pub struct StartupTracker {
+    started_at: Instant,
+    marks: Mutex<PhaseMarks>,
+    outcome: watch::Sender<Option<StartupOutcome>>,
+}
+
+impl StartupTracker {
+    pub fn new() -> Self {
+        Self {
+            started_at: Instant::now(),
+            marks: Mutex::new(PhaseMarks::default()),
+            outcome: watch::Sender::new(None),
+        }
+    }
+
+    pub fn is_settled(&self) -> bool {
+        self.outcome.borrow().is_some()
+    }
+
+    /// A new connection attempt discards the phase marks of the previous one.
+    pub fn begin_attempt(&self) {
+        if self.is_settled() {
+            return;
+        }
+        let mut marks = self.marks.lock();
+        let attempts = marks.attempts + 1;
+        *marks = PhaseMarks {
+            attempts,
+            ..Default::default()
+        };
+    }
+
+    /// Returns the report if this mark settled the startup.
+    pub fn mark(&self, phase: StartupPhase) -> Option<StartupReport> {
+        if self.is_settled() {
+            return None;
+        }
+        let now = Instant::now();
+        let mut marks = self.marks.lock();
+        match phase {
+            StartupPhase::Connected => {
+                marks.connected.get_or_insert(now);
+            }
+            StartupPhase::Handshake => {
+                marks.handshake.get_or_insert(now);
+            }
+            StartupPhase::Preroll => {
+                marks.preroll.get_or_insert(now);
+            }
+            StartupPhase::FirstFrame => {
+                let since_start =
+                    |at: Instant| at.duration_since(self.started_at).as_millis() as u64;
+                let report = StartupReport {
+                    connect_ms: marks.connected.map(since_start),
+                    handshake_ms: marks.handshake.map(since_start),
+                    preroll_ms: marks.preroll.map(since_start),
+                    first_frame_ms: since_start(now),
+                    attempts: marks.attempts.max(1),
+                };
+                return self.settle(Ok(report.clone())).then_some(report);
+            }
+        }
+        None
+    }
+
+    /// Returns true if this error settled the startup.
+    pub fn fail(&self, error: StreamError) -> bool {
+        self.settle(Err(error))
+    }
+
+    fn settle(&self, result: StartupOutcome) -> bool {
+        self.outcome.send_if_modified(|outcome| {
+            if outcome.is_some() {
+                return false;
+            }
+            *outcome = Some(result);
+            true
+        })
+    }
+
+    pub async fn wait(&self) -> StartupOutcome {
+        let mut outcome = self.outcome.subscribe();
+        let settled = outcome
+            .wait_for(|outcome| outcome.is_some())
+            .await
+            .expect("the sender lives in self");
+        (*settled).clone().expect("waited for a settled outcome")
+    }
+}
@@ -0,0 +1,227 @@
+use std::{
+    sync::Weak,
+    time::{Duration, Instant},
+};
+
+use parking_lot::Mutex;
+
+use crate::{
+    core::{session::VideoSession, types::StallWatchdogConfig},
+    dart_types::StreamEvent,
+};
+
+const WATCHDOG_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Outcome of a single watchdog check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WatchdogAction {
+    Stalled { since_last_frame: Duration },
+    Resumed { stalled_for: Duration },
+    Reconnect,
+}
+
+#[derive(Default)]
+struct WatchdogState {
+    /// Set while the session is `Playing`; stalls are only counted while armed.
+    armed_at: Option<Instant>,
+    last_frame: Option<Instant>,
+    stalled_since: Option<Instant>,
+    reconnect_requested: bool,
+}
+
+/// Tracks when a session last delivered a frame to its texture.
+///
+/// Backends call `frame_arrived` from the frame path, `VideoSessionCommon`
+/// arms/disarms it on state changes, and `watch_session` polls it.
+pub struct FrameWatchdog {
+    config: Option<StallWatchdogConfig>,
+    state: Mutex<WatchdogState>,
+}
+
+impl FrameWatchdog {
+    pub fn new(config: Option<StallWatchdogConfig>) -> Self {
+        Self {
+            config,
+            state: Mutex::new(WatchdogState::default()),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.config.is_some()
+    }
+
+    pub fn frame_arrived(&self) {
+        self.state.lock().last_frame = Some(Instant::now());
+    }
+
+    pub fn arm(&self) {
+        let mut state = self.state.lock();
+        if state.armed_at.is_none() {
+            state.armed_at = Some(Instant::now());
+            state.reconnect_requested = false;
+        }
+    }
+
+    /// Stop counting, e.g. while the backend is reconnecting.
+    /// A pending stall is kept so that `Resumed` is still reported later.
+    pub fn disarm(&self) {
+        self.state.lock().armed_at = None;
+    }
+
+    pub fn poll(&self, now: Instant) -> Option<WatchdogAction> {
+        let config = self.config.as_ref()?;
+        let mut state = self.state.lock();
+
+        if let Some(stalled_since) = state.stalled_since {
+            if state.last_frame.is_some_and(|last| last > stalled_since) {
+                state.stalled_since = None;
+                return Some(WatchdogAction::Resumed {
+                    stalled_for: now.saturating_duration_since(stalled_since),
+                });
+            }
+        }
+
+        let armed_at = state.armed_at?;
+        let reference = state.last_frame.map_or(armed_at, |last| last.max(armed_at));
+        let since_last_frame = now.saturating_duration_since(reference);
+
+        if state.stalled_since.is_none() {
+            if since_last_frame >= Duration::from_millis(config.stall_threshold_ms) {
+                state.stalled_since = Some(reference);
+                return Some(WatchdogAction::Stalled { since_last_frame });
+            }
+            return None;
+        }
+
+        let reconnect_after = config.reconnect_after_ms.map(Duration::from_millis)?;
+        if !state.reconnect_requested && since_last_frame >= reconnect_after {
+            state.reconnect_requested = true;
+            return Some(WatchdogAction::Reconnect);
+        }
+        None
+    }
+}
+
+/// Periodically polls the session's `FrameWatchdog` until the session is dropped.
+pub async fn watch_session(session: Weak<dyn VideoSession>) {
+    let mut interval = tokio::time::interval(WATCHDOG_INTERVAL);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    loop {
+        interval.tick().await;
+        let Some(session) = session.upgrade() else {
+            return;
+        };
+        let common = session.session_common();
+        match common.frame_watchdog.poll(Instant::now()) {
+            Some(WatchdogAction::Stalled { since_last_frame }) => {
+                log::warn!(
+                    "Session {}: no frame for {}ms",
+                    common.session_id,
+                    since_last_frame.as_millis()
+                );
+                common.send_event_msg(StreamEvent::Stalled {
+                    since_last_frame_ms: since_last_frame.as_millis() as u64,
+                });
+            }
+            Some(WatchdogAction::Resumed { stalled_for }) => {
+                log::info!(
+                    "Session {}: frames resumed after {}ms",
+                    common.session_id,
+                    stalled_for.as_millis()
+                );
+                common.send_event_msg(StreamEvent::Resumed {
+                    stalled_for_ms: stalled_for.as_millis() as u64,
+                });
+            }
+            Some(WatchdogAction::Reconnect) => {
+                log::warn!(
+                    "Session {}: stall persisted, requesting reconnect",
+                    common.session_id
+                );
+                common.request_reconnect();
+            }
+            None => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn watchdog(stall_threshold_ms: u64, reconnect_after_ms: Option<u64>) -> FrameWatchdog {
+        FrameWatchdog::new(Some(StallWatchdogConfig {
+            stall_threshold_ms,
+            reconnect_after_ms,
+        }))
+    }
+
+    #[test]
+    fn disabled_watchdog_never_fires() {
+        let watchdog = FrameWatchdog::new(None);
+        watchdog.arm();
+        assert!(!watchdog.is_enabled());
+        assert_eq!(
+            watchdog.poll(Instant::now() + Duration::from_secs(60)),
+            None
+        );
+    }
+
+    #[test]
+    fn stalls_are_only_counted_while_armed() {
+        let watchdog = watchdog(1000, None);
+        let later = Instant::now() + Duration::from_secs(10);
+        assert_eq!(watchdog.poll(later), None);
+
+        watchdog.arm();
+        watchdog.disarm();
+        assert_eq!(watchdog.poll(later), None);
+    }
+
+    #[test]
+    fn reports_a_stall_once_then_reconnects_once() {
+        let watchdog = watchdog(1000, Some(3000));
+        watchdog.arm();
+        let start = Instant::now();
+
+        assert_eq!(watchdog.poll(start + Duration::from_millis(500)), None);
+        assert!(matches!(
+            watchdog.poll(start + Duration::from_millis(1500)),
+            Some(WatchdogAction::Stalled { since_last_frame })
+                if since_last_frame >= Duration::from_millis(1500)
+        ));
+        assert_eq!(watchdog.poll(start + Duration::from_millis(2000)), None);
+        assert_eq!(
+            watchdog.poll(start + Duration::from_millis(3500)),
+            Some(WatchdogAction::Reconnect)
+        );
+        assert_eq!(watchdog.poll(start + Duration::from_millis(4000)), None);
+    }
+
+    #[test]
+    fn a_frame_after_a_stall_resumes() {
+        let watchdog = watchdog(1000, None);
+        watchdog.arm();
+        let start = Instant::now();
+        assert!(matches!(
+            watchdog.poll(start + Duration::from_millis(1500)),
+            Some(WatchdogAction::Stalled { .. })
+        ));
+
+        watchdog.frame_arrived();
+        assert!(matches!(
+            watchdog.poll(Instant::now()),
+            Some(WatchdogAction::Resumed { .. })
+        ));
+        assert_eq!(watchdog.poll(Instant::now()), None);
+    }
+
+    #[test]
+    fn frames_keep_the_watchdog_quiet() {
+        let watchdog = watchdog(1000, Some(2000));
+        watchdog.arm();
+        watchdog.frame_arrived();
+        let last_frame = Instant::now();
+        assert_eq!(watchdog.poll(last_frame + Duration::from_millis(900)), None);
+    }
+}
@@ -1,17 +1,94 @@
-use std::sync::{Arc, Mutex};
+use std::{
+    sync::{Arc, Mutex, Weak},
+    time::SystemTime,
+};
 
-use irondash_texture::{BoxedPixelData, PayloadProvider, PixelData, PixelDataProvider};
+use irondash_texture::{
+    BoxedPixelData, PayloadProvider, PixelData, PixelDataProvider, PixelFormat,
+};
 use log::{debug, error};
 
-/// Unified RGBA frame used by all decoder backends (FFmpeg, GStreamer, etc.).
+use crate::core::texture::pool::FramePool;
+
+/// Byte order of a frame's 4-byte pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameFormat {
+    Rgba,
+    Bgra,
+}
+
+impl FrameFormat {
+    /// The order the platform texture takes as is (BGRA on macOS and iOS, RGBA elsewhere),
+    /// so decoders convert straight to it and frames are uploaded without another pass.
+    pub fn native() -> Self {
+        match PixelData::FORMAT {
+            PixelFormat::BGRA => Self::Bgra,
+            PixelFormat::RGBA => Self::Rgba,
+        }
+    }
+
+    pub fn video_format(self) -> gst_video::VideoFormat {
+        match self {
+            Self::Rgba => gst_video::VideoFormat::Rgba,
+            Self::Bgra => gst_video::VideoFormat::Bgra,
+        }
+    }
+
+    pub fn from_video_format(format: gst_video::VideoFormat) -> Option<Self> {
+        match format {
+            gst_video::VideoFormat::Rgba => Some(Self::Rgba),
+            gst_video::VideoFormat::Bgra => Some(Self::Bgra),
+            _ => None,
+        }
+    }
+
+    /// Positions of red, green and blue within a pixel.
+    fn rgb_indices(self) -> [usize; 3] {
+        match self {
+            Self::Rgba => [0, 1, 2],
+            Self::Bgra => [2, 1, 0],
+        }
+    }
+}
+
+/// Unified packed frame used by all decoder backends (FFmpeg, GStreamer, etc.),
+/// tightly packed in `format`; frames headed for a texture use `FrameFormat::native`.
 #[derive(Clone)]
-pub struct RawRgbaFrame {
+pub struct RawFrame {
     pub width: u32,
     pub height: u32,
+    pub format: FrameFormat,
     pub data: Vec<u8>,
+    /// Presentation timestamp of the decoded buffer, in stream time.
+    pub pts_ns: Option<u64>,
+    pub decoded_at: SystemTime,
+    /// Gets `data` back once the frame is dropped.
+    pool: Option<Weak<FramePool>>,
 }
 
-impl RawRgbaFrame {
+impl RawFrame {
+    pub fn new(width: u32, height: u32, format: FrameFormat, data: Vec<u8>) -> Self {
+        Self {
+            width,
+            height,
+            format,
+            data,
+            pts_ns: None,
+            decoded_at: SystemTime::now(),
+            pool: None,
+        }
+    }
+
+    pub fn with_pts(mut self, pts_ns: Option<u64>) -> Self {
+        self.pts_ns = pts_ns;
+        self
+    }
+
+    pub(crate) fn with_pool(mut self, pool: Weak<FramePool>) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
     /// Create a black (zeroed) frame with the given dimensions.
     pub fn black(width: u32, height: u32) -> Self {
         let mut data = vec![0u8; (width * height * 4) as usize];
@@ -19,15 +96,67 @@ impl RawRgbaFrame {
         for chunk in data.chunks_mut(4) {
             chunk[3] = 255;
         }
-        Self {
+        Self::new(width, height, FrameFormat::native(), data)
+    }
+
+    pub fn transparent(width: u32, height: u32) -> Self {
+        Self::new(
             width,
             height,
-            data,
+            FrameFormat::native(),
+            vec![0u8; (width * height * 4) as usize],
+        )
+    }
+
+    /// The frame with its pixels reordered to `format`.
+    pub fn converted(mut self, format: FrameFormat) -> Self {
+        if self.format != format {
+            // both orders keep green and alpha in place
+            for pixel in self.data.chunks_exact_mut(4) {
+                pixel.swap(0, 2);
+            }
+            self.format = format;
+        }
+        self
+    }
+
+    /// A copy with the color channels scaled to `brightness_percent`.
+    pub fn dimmed(&self, brightness_percent: u8) -> Self {
+        let factor = brightness_percent.min(100) as u32;
+        let mut data = self.data.clone();
+        for pixel in data.chunks_exact_mut(4) {
+            for channel in &mut pixel[..3] {
+                *channel = (*channel as u32 * factor / 100) as u8;
+            }
+        }
+        Self::new(self.width, self.height, self.format, data)
+    }
+
+    /// A copy with the ARGB color `argb` blended over every pixel.
+    pub fn with_overlay(&self, argb: u32) -> Self {
+        let alpha = argb >> 24;
+        let color = [(argb >> 16) & 0xff, (argb >> 8) & 0xff, argb & 0xff];
+        let indices = self.format.rgb_indices();
+        let mut data = self.data.clone();
+        for pixel in data.chunks_exact_mut(4) {
+            for (index, overlay) in indices.into_iter().zip(color) {
+                let channel = &mut pixel[index];
+                *channel = ((*channel as u32 * (255 - alpha) + overlay * alpha) / 255) as u8;
+            }
+        }
+        Self::new(self.width, self.height, self.format, data)
+    }
+}
+
+impl Drop for RawFrame {
+    fn drop(&mut self) {
+        if let Some(pool) = self.pool.as_ref().and_then(Weak::upgrade) {
+            pool.recycle(std::mem::take(&mut self.data));
         }
     }
 }
 
-impl PixelDataProvider for RawRgbaFrame {
+impl PixelDataProvider for RawFrame {
     fn get(&self) -> PixelData<'_> {
         PixelData {
             width: self.width as _,
@@ -38,11 +167,22 @@ impl PixelDataProvider for RawRgbaFrame {
 }
 
 /// Shared pixel data — cheaply cloneable via Arc.
-pub type SharedPixelData = Arc<RawRgbaFrame>;
+pub type SharedPixelData = Arc<RawFrame>;
+
+/// Hands a shared frame to the texture without copying its pixels.
+struct SharedFrameData(SharedPixelData);
+
+impl PixelDataProvider for SharedFrameData {
+    fn get(&self) -> PixelData<'_> {
+        self.0.get()
+    }
+}
 
 pub struct PayloadHolder {
     current_frame: Mutex<Option<SharedPixelData>>,
     previous_frame: Mutex<Option<SharedPixelData>>,
+    /// Shown while there is no frame, see `PresentationPolicy::placeholder`.
+    placeholder: Mutex<SharedPixelData>,
 }
 
 impl PayloadHolder {
@@ -50,6 +190,24 @@ impl PayloadHolder {
         Self {
             current_frame: Mutex::new(None),
             previous_frame: Mutex::new(None),
+            placeholder: Mutex::new(Arc::new(RawFrame::black(640, 480))),
+        }
+    }
+
+    pub fn set_placeholder(&self, placeholder: SharedPixelData) {
+        match self.placeholder.lock() {
+            Ok(mut lock) => *lock = placeholder,
+            Err(e) => error!("placeholder mutex poisoned in set_placeholder: {}", e),
+        }
+    }
+
+    /// Drop the held frames so the placeholder is shown again.
+    pub fn clear(&self) {
+        if let Ok(mut curr_frame) = self.current_frame.lock() {
+            *curr_frame = None;
+        }
+        if let Ok(mut prev_frame) = self.previous_frame.lock() {
+            *prev_frame = None;
         }
     }
 
@@ -72,6 +230,10 @@ impl PayloadHolder {
         *curr_frame = Some(payload);
     }
 
+    pub fn current_frame(&self) -> Option<SharedPixelData> {
+        self.current_frame.lock().ok().and_then(|f| f.clone())
+    }
+
     pub fn previous_frame(&self) -> Option<SharedPixelData> {
         self.previous_frame.lock().ok().and_then(|f| f.clone())
     }
@@ -80,14 +242,17 @@ impl PayloadHolder {
 impl PayloadProvider<BoxedPixelData> for PayloadHolder {
     fn get_payload(&self) -> BoxedPixelData {
         let default_frame = || -> BoxedPixelData {
-            debug!("No frame available, returning a default black frame.");
-            Box::new(RawRgbaFrame::black(640, 480))
+            debug!("No frame available, returning the placeholder.");
+            match self.placeholder.lock() {
+                Ok(placeholder) => Box::new(SharedFrameData(placeholder.clone())),
+                Err(_) => Box::new(RawFrame::black(640, 480)),
+            }
         };
 
         let curr_frame_lock = self.current_frame.lock();
         if let Ok(curr_frame) = curr_frame_lock {
             if let Some(ref frame) = *curr_frame {
-                return Box::new((**frame).clone());
+                return Box::new(SharedFrameData(frame.clone()));
             }
         } else {
             error!("current_frame mutex poisoned in get_payload");
@@ -98,7 +263,7 @@ impl PayloadProvider<BoxedPixelData> for PayloadHolder {
         if let Ok(prev_frame) = prev_frame_lock {
             if let Some(ref frame) = *prev_frame {
                 debug!("Returning previous frame");
-                return Box::new((**frame).clone());
+                return Box::new(SharedFrameData(frame.clone()));
             }
         } else {
             error!("previous_frame mutex poisoned in get_payload");
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use crate::core::texture::payload::{FrameFormat, RawFrame};
+
+/// Buffers kept for reuse; enough for the frames held by the payload holder
+/// and the output queue at the same time.
+const DEFAULT_MAX_BUFFERS: usize = 8;
+
+/// Recycles the pixel buffers of a backend's frames, so steady-state decoding
+/// doesn't allocate a new frame-sized `Vec` per sample.
+/// Frames taken from the pool return their buffer when the last reference is dropped.
+pub struct FramePool {
+    buffers: Mutex<Vec<Vec<u8>>>,
+    max_buffers: usize,
+}
+
+impl Default for FramePool {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_BUFFERS)
+    }
+}
+
+impl FramePool {
+    pub fn new(max_buffers: usize) -> Self {
+        Self {
+            buffers: Mutex::new(Vec::with_capacity(max_buffers)),
+            max_buffers,
+        }
+    }
+
+    /// An empty buffer with room for at least `capacity` bytes.
+    pub fn take(&self, capacity: usize) -> Vec<u8> {
+        let mut buffers = self.buffers.lock();
+        while let Some(mut buffer) = buffers.pop() {
+            // buffers of a previous, smaller resolution are dropped
+            if buffer.capacity() >= capacity {
+                buffer.clear();
+                return buffer;
+            }
+        }
+        Vec::with_capacity(capacity)
+    }
+
+    pub(crate) fn recycle(&self, buffer: Vec<u8>) {
+        let mut buffers = self.buffers.lock();
+        if buffers.len() < self.max_buffers {
+            buffers.push(buffer);
+        }
+    }
+
+    /// Wrap `data` (usually from `take`) in a frame that gives it back to this pool.
+    pub fn frame(
+        self: &Arc<Self>,
+        width: u32,
+        height: u32,
+        format: FrameFormat,
+        data: Vec<u8>,
+    ) -> RawFrame {
+        RawFrame::new(width, height, format, data).with_pool(Arc::downgrade(self))
+    }
+}
@@ -1,3 +1,5 @@
+use serde::Deserialize;
+
 use crate::{
     dart_types::{StreamEvent, StreamState},
     frb_generated::StreamSink,
@@ -36,7 +38,7 @@ impl VideoInfo {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 #[flutter_rust_bridge::frb(sync)]
 pub struct WscRtpSessionConfig {
     pub base_url: String,
@@ -47,11 +49,20 @@ pub struct WscRtpSessionConfig {
     pub auto_restart: bool,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 #[flutter_rust_bridge::frb(sync)]
 pub struct PlaybinConfig {
     pub uri: String,
     pub mute: bool,
+    /// Rebuild the pipeline with backoff after an error, a stall or the end of a live
+    /// source. Finite files stop at EOS.
+    pub auto_restart: bool,
+    /// Restart if no new sample arrived for this long, for live and non-live sources.
+    /// `None` disables stall detection.
+    pub stall_timeout_ms: Option<u64>,
+    /// With `auto_restart`, give up with an `Error` state after this many restarts in a
+    /// row that did not deliver a frame. `None` retries forever.
+    pub max_restart_attempts: Option<u32>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -59,6 +70,338 @@ pub struct PlaybinConfig {
 pub enum VideoConfig {
     WscRtp(WscRtpSessionConfig),
     Playbin(PlaybinConfig),
+    /// A backend registered by the embedding crate with `input::factory::register_backend`.
+    Custom {
+        kind: String,
+        json_params: String,
+    },
+}
+
+/// What a session's texture shows between `switch_source` and the new source's first frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[flutter_rust_bridge::frb(sync)]
+pub enum SwitchPlaceholder {
+    HoldLastFrame,
+    Black,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[flutter_rust_bridge::frb(sync)]
+pub struct StallWatchdogConfig {
+    /// Report `StreamEvent::Stalled` after this long without a new frame while playing.
+    pub stall_threshold_ms: u64,
+    /// Ask the backend to reconnect once the stall lasted this long.
+    /// `None` only reports stalls.
+    pub reconnect_after_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[flutter_rust_bridge::frb(sync)]
+pub enum KeepAlivePolicy {
+    /// Reap the session if `mark_session_alive` wasn't called within `timeout_ms`.
+    /// Shared sessions keep decoding until their last consumer is reaped or destroyed.
+    Timeout { timeout_ms: u64 },
+    /// Never reap the session; it lives until destroyed explicitly (e.g. background sessions).
+    Disabled,
+}
+
+/// How `OutputSize` maps the decoded video onto the requested box.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[flutter_rust_bridge::frb(sync)]
+pub enum ScaleMode {
+    /// Largest size inside the box keeping the aspect ratio.
+    Fit,
+    /// Covers the box keeping the aspect ratio; the overflow is cropped around the center,
+    /// so frames are exactly the box, or smaller with its aspect ratio since they're never upscaled.
+    Fill,
+    /// Exactly the box, stretching the video.
+    Exact,
+}
+
+/// Decode-side size of a session's frames, usually the rendered widget size in physical pixels.
+/// `Fit` and `Fill` never upscale.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[flutter_rust_bridge::frb(sync)]
+pub struct OutputSize {
+    pub width: u32,
+    pub height: u32,
+    pub mode: ScaleMode,
+}
+
+/// Clockwise rotation of a session's video.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[flutter_rust_bridge::frb(sync)]
+pub enum Rotation {
+    #[default]
+    None,
+    Clockwise90,
+    Clockwise180,
+    Clockwise270,
+}
+
+/// Rectangle of the decoded frame, as fractions (0.0..=1.0) of its width and height.
+#[derive(Debug, Clone, PartialEq)]
+#[flutter_rust_bridge::frb(sync)]
+pub struct CropRect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// Geometry applied in the decode pipeline, before the format conversion.
+/// Crop, zoom and pan are in decoded-frame coordinates; flips are applied before the rotation.
+#[derive(Debug, Clone, PartialEq)]
+#[flutter_rust_bridge::frb(sync)]
+pub struct VideoTransform {
+    pub rotation: Rotation,
+    pub flip_horizontal: bool,
+    pub flip_vertical: bool,
+    pub crop: Option<CropRect>,
+    /// 1.0 shows the whole (cropped) frame, 2.0 half of its width and height.
+    pub zoom: f64,
+    /// Center of the zoomed region within the cropped frame, 0.0..=1.0.
+    pub pan_x: f64,
+    pub pan_y: f64,
+}
+
+impl Default for VideoTransform {
+    fn default() -> Self {
+        Self {
+            rotation: Rotation::None,
+            flip_horizontal: false,
+            flip_vertical: false,
+            crop: None,
+            zoom: 1.0,
+            pan_x: 0.5,
+            pan_y: 0.5,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[flutter_rust_bridge::frb(sync)]
+pub enum DeinterlaceMode {
+    /// Deinterlace only streams whose caps say they are interlaced.
+    Auto,
+    /// Always deinterlace.
+    On,
+    #[default]
+    Off,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[flutter_rust_bridge::frb(sync)]
+pub enum DeinterlaceMethod {
+    #[default]
+    Linear,
+    GreedyHigh,
+    GreedyLow,
+    Vfir,
+    ScalerBob,
+    Yadif,
+}
+
+/// Image processing applied in the decode pipeline, ahead of any geometry transform.
+/// The defaults leave the video untouched and cost nothing.
+#[derive(Debug, Clone, PartialEq)]
+#[flutter_rust_bridge::frb(sync)]
+pub struct ImageAdjustments {
+    pub deinterlace: DeinterlaceMode,
+    pub deinterlace_method: DeinterlaceMethod,
+    /// -1.0..=1.0, default 0.0.
+    pub brightness: f64,
+    /// 0.0..=2.0, default 1.0.
+    pub contrast: f64,
+    /// 0.0..=2.0, default 1.0.
+    pub saturation: f64,
+    /// -1.0..=1.0, default 0.0.
+    pub hue: f64,
+    /// 0.01..=10.0, default 1.0.
+    pub gamma: f64,
+}
+
+impl Default for ImageAdjustments {
+    fn default() -> Self {
+        Self {
+            deinterlace: DeinterlaceMode::Off,
+            deinterlace_method: DeinterlaceMethod::Linear,
+            brightness: 0.0,
+            contrast: 1.0,
+            saturation: 1.0,
+            hue: 0.0,
+            gamma: 1.0,
+        }
+    }
+}
+
+/// What a texture shows before its session's first frame.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[flutter_rust_bridge::frb(sync)]
+pub enum PlaceholderFrame {
+    #[default]
+    Black,
+    Transparent,
+    /// Tightly packed RGBA pixels supplied by the app.
+    Rgba {
+        width: u32,
+        height: u32,
+        data: Vec<u8>,
+    },
+    /// An image decoded with GStreamer, e.g. `file:///path/poster.jpg`.
+    /// Black is shown until it is decoded.
+    Poster {
+        uri: String,
+    },
+}
+
+/// What a texture shows once its session stopped or failed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[flutter_rust_bridge::frb(sync)]
+pub enum EndFramePolicy {
+    #[default]
+    HoldLastFrame,
+    /// Hold the last frame at `brightness_percent` of its brightness.
+    Dim { brightness_percent: u8 },
+    /// Go back to the placeholder.
+    Clear,
+}
+
+/// How a session's textures present the phases without decoded frames.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[flutter_rust_bridge::frb(sync)]
+pub struct PresentationPolicy {
+    pub placeholder: PlaceholderFrame,
+    pub on_stop: EndFramePolicy,
+    pub on_error: EndFramePolicy,
+    /// The last frame is held while reconnecting; if set, this ARGB color is blended over it.
+    pub reconnect_overlay_argb: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[flutter_rust_bridge::frb(sync)]
+pub enum SnapshotFormat {
+    Png,
+    Jpeg,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[flutter_rust_bridge::frb(sync)]
+pub enum RecordingContainer {
+    #[default]
+    Mp4,
+    Mkv,
+}
+
+/// Splits a recording into consecutive files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[flutter_rust_bridge::frb(sync)]
+pub struct RecordingSegments {
+    /// Files are cut at the first keyframe after this length.
+    pub duration_ms: u64,
+    /// Keep only the newest files, reusing the oldest file names (ring recording).
+    pub max_files: Option<u32>,
+}
+
+/// In-memory ring of the latest encoded packets, for `save_pre_event_clip`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[flutter_rust_bridge::frb(sync)]
+pub struct PreEventBuffer {
+    /// At least this much is kept, rounded up to the start of a GOP.
+    pub duration_ms: u64,
+    /// Older GOPs are dropped beyond this, even if the duration isn't covered.
+    /// Defaults to 64 MiB.
+    pub max_bytes: Option<u64>,
+}
+
+/// Pixel format of the frames delivered by a frame tap.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[flutter_rust_bridge::frb(sync)]
+pub enum TapPixelFormat {
+    #[default]
+    Rgba,
+    Rgb,
+    /// Y plane followed by an interleaved UV plane at half resolution.
+    Nv12,
+    Gray8,
+}
+
+/// What a frame tap subscribed with `subscribe_frames` delivers.
+#[derive(Debug, Clone, PartialEq)]
+#[flutter_rust_bridge::frb(sync)]
+pub struct FrameTapConfig {
+    /// Frames above this rate are skipped; `None` delivers every decoded frame.
+    /// Independent of the session's own `max_frame_rate`.
+    pub max_frame_rate: Option<u32>,
+    /// Scale the frames after rotation, crop and zoom; `None` keeps that size.
+    pub size: Option<OutputSize>,
+    pub format: TapPixelFormat,
+    /// Frames waiting for the consumer; further frames are dropped while it is full.
+    pub queue_len: u32,
+}
+
+impl Default for FrameTapConfig {
+    fn default() -> Self {
+        Self {
+            max_frame_rate: None,
+            size: None,
+            format: TapPixelFormat::Rgba,
+            queue_len: 2,
+        }
+    }
+}
+
+/// Where a session's decoded frames go.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[flutter_rust_bridge::frb(sync)]
+pub enum FrameOutputMode {
+    /// A Flutter texture registered with the session's engine.
+    #[default]
+    FlutterTexture,
+    /// An in-memory sink keeping the latest frame, a frame counter and up to
+    /// `history_len` recent frames. Needs no Flutter engine (tests, CLI hosts).
+    Headless { history_len: u32 },
+}
+
+/// Backend-independent per-session options.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[flutter_rust_bridge::frb(sync)]
+pub struct SessionOptions {
+    pub stall_watchdog: Option<StallWatchdogConfig>,
+    /// Defaults to a 5 second timeout.
+    pub keep_alive: Option<KeepAlivePolicy>,
+    /// If set, `create_playable` waits up to this long for the first decoded frame
+    /// and fails with the startup error instead of returning right away.
+    pub await_first_frame_timeout_ms: Option<u64>,
+    /// Attach to a running session with an equal `VideoConfig` (also created with
+    /// `share_decode`) instead of connecting and decoding a second time.
+    /// The new session id still gets its own texture, sink and keep-alive.
+    pub share_decode: bool,
+    /// Connect without creating a texture, ready to be moved onto another session's
+    /// texture with `promote_standby_session`. Until then only keyframes are decoded,
+    /// so a stall watchdog threshold should exceed the stream's keyframe interval.
+    /// Not combined with `share_decode`.
+    pub standby: bool,
+    /// Ignored for standby sessions, which have no frame output until promoted.
+    pub frame_output: FrameOutputMode,
+    /// Shared sessions use the policy of the session that started the decode.
+    pub presentation: PresentationPolicy,
+    /// Cap on rendered frames per second; decoding keeps running at the stream's rate.
+    /// Changeable later with `set_max_frame_rate`.
+    pub max_frame_rate: Option<u32>,
+    /// Keep the last seconds of the encoded stream for `save_pre_event_clip`.
+    /// Changeable later with `set_pre_event_buffer`.
+    pub pre_event_buffer: Option<PreEventBuffer>,
+}
+
+/// Configuration of the tokio runtime owned by the crate.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[flutter_rust_bridge::frb(sync)]
+pub struct RuntimeConfig {
+    /// Defaults to the number of CPU cores.
+    pub worker_threads: Option<u32>,
+    /// Defaults to "flutter-realtime-player".
+    pub thread_name: Option<String>,
 }
 
 use crate::dart_types::StreamMessage;
@@ -1,8 +1,8 @@
 #[derive(Debug, Clone)]
 pub enum StreamState {
-    Error(String),
+    Error(StreamError),
     Loading,
-    // texture id
+    // texture id, -1 for standby and headless sessions without a texture
     Playing { texture_id: i64, seekable: bool },
     Stopped,
 }
@@ -13,13 +13,115 @@ pub enum WscRtpMode {
     Dvr { current_time_ms: i64, speed: f64 },
 }
 
+/// Time from session creation until each startup phase completed, in milliseconds.
+/// Phases that don't apply to a backend are `None`.
+#[derive(Debug, Clone)]
+pub struct StartupReport {
+    pub connect_ms: Option<u64>,
+    // handshake / SDP negotiation
+    pub handshake_ms: Option<u64>,
+    pub preroll_ms: Option<u64>,
+    pub first_frame_ms: u64,
+    // connection attempts including the successful one
+    pub attempts: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCategory {
+    // connecting failed or the connection dropped
+    Network,
+    Timeout,
+    // the server doesn't know the source / the uri points at nothing
+    SourceNotFound,
+    AccessDenied,
+    InvalidUri,
+    // no decoder or demuxer for the stream
+    UnsupportedCodec,
+    Decode,
+    // the media server reported an error
+    Server,
+    // the session is reconnecting and can't serve the request yet
+    Unavailable,
+    SessionNotFound,
+    // the session was stopped before the operation completed
+    Cancelled,
+    Internal,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorBackend {
+    WscRtp,
+    Playbin,
+    Registry,
+    Recorder,
+    // a backend registered by the embedding crate, by its kind
+    Custom(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct StreamError {
+    pub category: ErrorCategory,
+    // whether retrying the same operation may succeed
+    pub retryable: bool,
+    pub backend: ErrorBackend,
+    // the underlying error, e.g. the GStreamer or websocket error
+    pub cause: Option<String>,
+    // human-readable summary
+    pub message: String,
+}
+
 #[derive(Debug, Clone)]
 pub enum StreamEvent {
-    Error(String),
+    Error(StreamError),
     CurrentTime(i64),
-    OriginVideoSize { width: u64, height: u64 },
+    // decoded size, and the size after rotation, crop and zoom (before output scaling);
+    // sent again whenever the source changes resolution, aspect ratio or frame rate
+    OriginVideoSize {
+        width: u64,
+        height: u64,
+        transformed_width: u64,
+        transformed_height: u64,
+        // width over height of one transformed pixel, 1.0 for square pixels
+        pixel_aspect_ratio: f64,
+        // width over height to display the video at, accounts for anamorphic sources
+        display_aspect_ratio: f64,
+        // frames per second, 0.0 for a variable frame rate
+        frame_rate: f64,
+    },
     WscRtpSessionMode(WscRtpMode),
     WscRtpStreamState(String),
+    // the session is playing but no frame arrived for `since_last_frame_ms`
+    Stalled {
+        since_last_frame_ms: u64,
+    },
+    // frames are flowing again after a `Stalled` event
+    Resumed {
+        stalled_for_ms: u64,
+    },
+    StartupReport(StartupReport),
+    StartupFailed(StreamError),
+    // the backend lost its source and retries after `delay_ms`; not an error by itself
+    Reconnecting {
+        attempt: u32,
+        delay_ms: u64,
+    },
+    // the first keyframe was written to the recording
+    RecordingStarted {
+        path: String,
+    },
+    // sent once per recording, after stop, a reached limit or an error
+    RecordingStopped(RecordingInfo),
+    // writing the recording failed; `RecordingStopped` follows
+    RecordingError(StreamError),
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordingInfo {
+    // written files in recording order, only the kept ones for ring recordings
+    pub files: Vec<String>,
+    pub duration_ms: u64,
+    // total size of `files`
+    pub size_bytes: u64,
 }
 
 #[derive(Debug, Clone)]
@@ -27,3 +129,37 @@ pub enum StreamMessage {
     State(StreamState),
     Event(StreamEvent),
 }
+
+/// An encoded frame returned by `capture_snapshot`.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    // PNG or JPEG bytes, empty when written to a file
+    pub data: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+    // stream time of the frame, if the backend set one
+    pub pts_ms: Option<u64>,
+    // wall clock when the frame was decoded, in ms since the unix epoch
+    pub captured_at_ms: i64,
+}
+
+/// A decoded frame delivered by a frame tap.
+#[derive(Debug, Clone)]
+pub struct TapFrame {
+    // all planes without row padding, each starting at its offset in `plane_offsets`
+    pub data: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+    pub format: crate::core::types::TapPixelFormat,
+    // bytes per row of each plane
+    pub plane_strides: Vec<u32>,
+    pub plane_offsets: Vec<u32>,
+    // stream time of the frame, if the backend set one
+    pub pts_ms: Option<u64>,
+    // wall clock when the frame was tapped, in ms since the unix epoch
+    pub captured_at_ms: i64,
+    // frames delivered by this tap before this one
+    pub sequence: u64,
+    // frames dropped so far because the consumer's queue was full
+    pub dropped: u64,
+}
@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 454442348;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 573161397;
 
 // Section: executor
 
@@ -45,6 +45,102 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
+fn wire__crate__api__simple__capture_snapshot_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "capture_snapshot",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <i64>::sse_decode(&mut deserializer);
+            let api_format = <crate::core::types::SnapshotFormat>::sse_decode(&mut deserializer);
+            let api_quality = <Option<u8>>::sse_decode(&mut deserializer);
+            let api_max_size = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_full_resolution = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::capture_snapshot(
+                            api_session_id,
+                            api_format,
+                            api_quality,
+                            api_max_size,
+                            api_full_resolution,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__capture_snapshot_to_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "capture_snapshot_to_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <i64>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_format = <crate::core::types::SnapshotFormat>::sse_decode(&mut deserializer);
+            let api_quality = <Option<u8>>::sse_decode(&mut deserializer);
+            let api_max_size = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_full_resolution = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::capture_snapshot_to_file(
+                            api_session_id,
+                            api_path,
+                            api_format,
+                            api_quality,
+                            api_max_size,
+                            api_full_resolution,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__simple__create_new_session_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -102,6 +198,8 @@ fn wire__crate__api__simple__create_playable_impl(
             let api_session_id = <i64>::sse_decode(&mut deserializer);
             let api_engine_handle = <i64>::sse_decode(&mut deserializer);
             let api_config = <crate::core::types::VideoConfig>::sse_decode(&mut deserializer);
+            let api_options =
+                <Option<crate::core::types::SessionOptions>>::sse_decode(&mut deserializer);
             let api_combined_sink = <StreamSink<
                 crate::dart_types::StreamMessage,
                 flutter_rust_bridge::for_generated::SseCodec,
@@ -114,6 +212,7 @@ fn wire__crate__api__simple__create_playable_impl(
                             api_session_id,
                             api_engine_handle,
                             api_config,
+                            api_options,
                             api_combined_sink,
                         )
                         .await?;
@@ -195,6 +294,47 @@ fn wire__crate__api__simple__destroy_stream_session_impl(
         },
     )
 }
+fn wire__crate__api__simple__destroy_stream_session_and_wait_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "destroy_stream_session_and_wait",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <i64>::sse_decode(&mut deserializer);
+            let api_timeout_ms = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::destroy_stream_session_and_wait(
+                            api_session_id,
+                            api_timeout_ms,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__simple__flutter_realtime_player_init_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -218,11 +358,16 @@ fn wire__crate__api__simple__flutter_realtime_player_init_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_ffi_ptr = <i64>::sse_decode(&mut deserializer);
+            let api_runtime_config =
+                <Option<crate::core::types::RuntimeConfig>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok = Result::<_, ()>::Ok({
-                        crate::api::simple::flutter_realtime_player_init(api_ffi_ptr);
+                        crate::api::simple::flutter_realtime_player_init(
+                            api_ffi_ptr,
+                            api_runtime_config,
+                        );
                     })?;
                     Ok(output_ok)
                 })())
@@ -299,7 +444,7 @@ fn wire__crate__api__simple__mark_session_alive_impl(
         },
     )
 }
-fn wire__crate__api__simple__seek_to_timestamp_impl(
+fn wire__crate__api__simple__next_frame_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -307,7 +452,7 @@ fn wire__crate__api__simple__seek_to_timestamp_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "seek_to_timestamp",
+            debug_name: "next_frame",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -322,13 +467,13 @@ fn wire__crate__api__simple__seek_to_timestamp_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_session_id = <i64>::sse_decode(&mut deserializer);
-            let api_ts = <u64>::sse_decode(&mut deserializer);
+            let api_tap_id = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
                         let output_ok =
-                            crate::api::simple::seek_to_timestamp(api_session_id, api_ts).await?;
+                            crate::api::simple::next_frame(api_session_id, api_tap_id).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -337,7 +482,81 @@ fn wire__crate__api__simple__seek_to_timestamp_impl(
         },
     )
 }
-fn wire__crate__api__simple__set_speed_impl(
+fn wire__crate__api__simple__promote_standby_session_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "promote_standby_session",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_standby_session_id = <i64>::sse_decode(&mut deserializer);
+            let api_target_session_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::promote_standby_session(
+                            api_standby_session_id,
+                            api_target_session_id,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__reset_output_size_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reset_output_size",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::reset_output_size(api_session_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__save_pre_event_clip_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -345,7 +564,7 @@ fn wire__crate__api__simple__set_speed_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_speed",
+            debug_name: "save_pre_event_clip",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -360,13 +579,21 @@ fn wire__crate__api__simple__set_speed_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_session_id = <i64>::sse_decode(&mut deserializer);
-            let api_speed = <f64>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_post_event_ms = <u64>::sse_decode(&mut deserializer);
+            let api_container =
+                <Option<crate::core::types::RecordingContainer>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::simple::set_speed(api_session_id, api_speed).await?;
+                        let output_ok = crate::api::simple::save_pre_event_clip(
+                            api_session_id,
+                            api_path,
+                            api_post_event_ms,
+                            api_container,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -375,7 +602,7 @@ fn wire__crate__api__simple__set_speed_impl(
         },
     )
 }
-fn wire__crate__api__simple__wsc_rtp_go_live_impl(
+fn wire__crate__api__simple__seek_to_timestamp_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -383,7 +610,7 @@ fn wire__crate__api__simple__wsc_rtp_go_live_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wsc_rtp_go_live",
+            debug_name: "seek_to_timestamp",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -398,11 +625,13 @@ fn wire__crate__api__simple__wsc_rtp_go_live_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_session_id = <i64>::sse_decode(&mut deserializer);
+            let api_ts = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::simple::wsc_rtp_go_live(api_session_id).await?;
+                        let output_ok =
+                            crate::api::simple::seek_to_timestamp(api_session_id, api_ts).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -411,119 +640,2830 @@ fn wire__crate__api__simple__wsc_rtp_go_live_impl(
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
-    }
-}
-
-impl SseDecode
-    for StreamSink<crate::dart_types::StreamMessage, flutter_rust_bridge::for_generated::SseCodec>
-{
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
-    }
-}
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
-}
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
-    }
+fn wire__crate__api__simple__set_digital_zoom_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_digital_zoom",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <i64>::sse_decode(&mut deserializer);
+            let api_zoom = <f64>::sse_decode(&mut deserializer);
+            let api_pan_x = <f64>::sse_decode(&mut deserializer);
+            let api_pan_y = <f64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::set_digital_zoom(
+                            api_session_id,
+                            api_zoom,
+                            api_pan_x,
+                            api_pan_y,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
 }
-
-impl SseDecode for f64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+fn wire__crate__api__simple__set_image_adjustments_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_image_adjustments",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <i64>::sse_decode(&mut deserializer);
+            let api_adjustments =
+                <crate::core::types::ImageAdjustments>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::set_image_adjustments(
+                            api_session_id,
+                            api_adjustments,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__set_max_frame_rate_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_max_frame_rate",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <i64>::sse_decode(&mut deserializer);
+            let api_max_frame_rate = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::set_max_frame_rate(
+                            api_session_id,
+                            api_max_frame_rate,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__set_output_size_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_output_size",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <i64>::sse_decode(&mut deserializer);
+            let api_width = <u32>::sse_decode(&mut deserializer);
+            let api_height = <u32>::sse_decode(&mut deserializer);
+            let api_mode = <crate::core::types::ScaleMode>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::set_output_size(
+                            api_session_id,
+                            api_width,
+                            api_height,
+                            api_mode,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__set_pre_event_buffer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_pre_event_buffer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <i64>::sse_decode(&mut deserializer);
+            let api_config =
+                <Option<crate::core::types::PreEventBuffer>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::set_pre_event_buffer(api_session_id, api_config)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__set_presentation_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_presentation_policy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <i64>::sse_decode(&mut deserializer);
+            let api_policy =
+                <crate::core::types::PresentationPolicy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::set_presentation_policy(
+                            api_session_id,
+                            api_policy,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__set_speed_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_speed",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <i64>::sse_decode(&mut deserializer);
+            let api_speed = <f64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::simple::set_speed(api_session_id, api_speed).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__set_video_transform_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_video_transform",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <i64>::sse_decode(&mut deserializer);
+            let api_transform = <crate::core::types::VideoTransform>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::set_video_transform(api_session_id, api_transform)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__start_recording_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_recording",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <i64>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_container =
+                <Option<crate::core::types::RecordingContainer>>::sse_decode(&mut deserializer);
+            let api_max_duration_ms = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_max_size_bytes = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_segments =
+                <Option<crate::core::types::RecordingSegments>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::start_recording(
+                            api_session_id,
+                            api_path,
+                            api_container,
+                            api_max_duration_ms,
+                            api_max_size_bytes,
+                            api_segments,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__stop_recording_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_recording",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::stop_recording(api_session_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__subscribe_frames_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_frames",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <i64>::sse_decode(&mut deserializer);
+            let api_config =
+                <Option<crate::core::types::FrameTapConfig>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::subscribe_frames(api_session_id, api_config)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__switch_source_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "switch_source",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <i64>::sse_decode(&mut deserializer);
+            let api_config = <crate::core::types::VideoConfig>::sse_decode(&mut deserializer);
+            let api_placeholder =
+                <Option<crate::core::types::SwitchPlaceholder>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::simple::switch_source(
+                            api_session_id,
+                            api_config,
+                            api_placeholder,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__unsubscribe_frames_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unsubscribe_frames",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <i64>::sse_decode(&mut deserializer);
+            let api_tap_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::simple::unsubscribe_frames(api_session_id, api_tap_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__wsc_rtp_go_live_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wsc_rtp_go_live",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::wsc_rtp_go_live(api_session_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::dart_types::StreamMessage, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::core::types::CropRect {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_x = <f64>::sse_decode(deserializer);
+        let mut var_y = <f64>::sse_decode(deserializer);
+        let mut var_width = <f64>::sse_decode(deserializer);
+        let mut var_height = <f64>::sse_decode(deserializer);
+        return crate::core::types::CropRect {
+            x: var_x,
+            y: var_y,
+            width: var_width,
+            height: var_height,
+        };
+    }
+}
+
+impl SseDecode for crate::core::types::DeinterlaceMethod {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::core::types::DeinterlaceMethod::Linear,
+            1 => crate::core::types::DeinterlaceMethod::GreedyHigh,
+            2 => crate::core::types::DeinterlaceMethod::GreedyLow,
+            3 => crate::core::types::DeinterlaceMethod::Vfir,
+            4 => crate::core::types::DeinterlaceMethod::ScalerBob,
+            5 => crate::core::types::DeinterlaceMethod::Yadif,
+            _ => unreachable!("Invalid variant for DeinterlaceMethod: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::core::types::DeinterlaceMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::core::types::DeinterlaceMode::Auto,
+            1 => crate::core::types::DeinterlaceMode::On,
+            2 => crate::core::types::DeinterlaceMode::Off,
+            _ => unreachable!("Invalid variant for DeinterlaceMode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::core::types::EndFramePolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::core::types::EndFramePolicy::HoldLastFrame;
+            }
+            1 => {
+                let mut var_brightnessPercent = <u8>::sse_decode(deserializer);
+                return crate::core::types::EndFramePolicy::Dim {
+                    brightness_percent: var_brightnessPercent,
+                };
+            }
+            2 => {
+                return crate::core::types::EndFramePolicy::Clear;
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::dart_types::ErrorBackend {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::dart_types::ErrorBackend::WscRtp;
+            }
+            1 => {
+                return crate::dart_types::ErrorBackend::Playbin;
+            }
+            2 => {
+                return crate::dart_types::ErrorBackend::Registry;
+            }
+            3 => {
+                return crate::dart_types::ErrorBackend::Recorder;
+            }
+            4 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::dart_types::ErrorBackend::Custom(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::dart_types::ErrorCategory {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::dart_types::ErrorCategory::Network,
+            1 => crate::dart_types::ErrorCategory::Timeout,
+            2 => crate::dart_types::ErrorCategory::SourceNotFound,
+            3 => crate::dart_types::ErrorCategory::AccessDenied,
+            4 => crate::dart_types::ErrorCategory::InvalidUri,
+            5 => crate::dart_types::ErrorCategory::UnsupportedCodec,
+            6 => crate::dart_types::ErrorCategory::Decode,
+            7 => crate::dart_types::ErrorCategory::Server,
+            8 => crate::dart_types::ErrorCategory::Unavailable,
+            9 => crate::dart_types::ErrorCategory::SessionNotFound,
+            10 => crate::dart_types::ErrorCategory::Cancelled,
+            11 => crate::dart_types::ErrorCategory::Internal,
+            _ => unreachable!("Invalid variant for ErrorCategory: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::core::types::FrameOutputMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::core::types::FrameOutputMode::FlutterTexture;
+            }
+            1 => {
+                let mut var_historyLen = <u32>::sse_decode(deserializer);
+                return crate::core::types::FrameOutputMode::Headless {
+                    history_len: var_historyLen,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::core::types::FrameTapConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxFrameRate = <Option<u32>>::sse_decode(deserializer);
+        let mut var_size = <Option<crate::core::types::OutputSize>>::sse_decode(deserializer);
+        let mut var_format = <crate::core::types::TapPixelFormat>::sse_decode(deserializer);
+        let mut var_queueLen = <u32>::sse_decode(deserializer);
+        return crate::core::types::FrameTapConfig {
+            max_frame_rate: var_maxFrameRate,
+            size: var_size,
+            format: var_format,
+            queue_len: var_queueLen,
+        };
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::core::types::ImageAdjustments {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_deinterlace = <crate::core::types::DeinterlaceMode>::sse_decode(deserializer);
+        let mut var_deinterlaceMethod =
+            <crate::core::types::DeinterlaceMethod>::sse_decode(deserializer);
+        let mut var_brightness = <f64>::sse_decode(deserializer);
+        let mut var_contrast = <f64>::sse_decode(deserializer);
+        let mut var_saturation = <f64>::sse_decode(deserializer);
+        let mut var_hue = <f64>::sse_decode(deserializer);
+        let mut var_gamma = <f64>::sse_decode(deserializer);
+        return crate::core::types::ImageAdjustments {
+            deinterlace: var_deinterlace,
+            deinterlace_method: var_deinterlaceMethod,
+            brightness: var_brightness,
+            contrast: var_contrast,
+            saturation: var_saturation,
+            hue: var_hue,
+            gamma: var_gamma,
+        };
+    }
+}
+
+impl SseDecode for crate::core::types::KeepAlivePolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_timeoutMs = <u64>::sse_decode(deserializer);
+                return crate::core::types::KeepAlivePolicy::Timeout {
+                    timeout_ms: var_timeoutMs,
+                };
+            }
+            1 => {
+                return crate::core::types::KeepAlivePolicy::Disabled;
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::core::types::CropRect> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::core::types::CropRect>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::core::types::FrameTapConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::core::types::FrameTapConfig>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::core::types::KeepAlivePolicy> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::core::types::KeepAlivePolicy>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::core::types::OutputSize> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::core::types::OutputSize>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::core::types::PreEventBuffer> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::core::types::PreEventBuffer>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::core::types::RecordingContainer> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::core::types::RecordingContainer>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::core::types::RecordingSegments> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::core::types::RecordingSegments>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::core::types::RuntimeConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::core::types::RuntimeConfig>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::core::types::SessionOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::core::types::SessionOptions>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::core::types::StallWatchdogConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::core::types::StallWatchdogConfig>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::core::types::SwitchPlaceholder> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::core::types::SwitchPlaceholder>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::dart_types::TapFrame> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::dart_types::TapFrame>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u16>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u8>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::core::types::OutputSize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_mode = <crate::core::types::ScaleMode>::sse_decode(deserializer);
+        return crate::core::types::OutputSize {
+            width: var_width,
+            height: var_height,
+            mode: var_mode,
+        };
+    }
+}
+
+impl SseDecode for crate::core::types::PlaceholderFrame {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::core::types::PlaceholderFrame::Black;
+            }
+            1 => {
+                return crate::core::types::PlaceholderFrame::Transparent;
+            }
+            2 => {
+                let mut var_width = <u32>::sse_decode(deserializer);
+                let mut var_height = <u32>::sse_decode(deserializer);
+                let mut var_data = <Vec<u8>>::sse_decode(deserializer);
+                return crate::core::types::PlaceholderFrame::Rgba {
+                    width: var_width,
+                    height: var_height,
+                    data: var_data,
+                };
+            }
+            3 => {
+                let mut var_uri = <String>::sse_decode(deserializer);
+                return crate::core::types::PlaceholderFrame::Poster { uri: var_uri };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::core::types::PlaybinConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_uri = <String>::sse_decode(deserializer);
+        let mut var_mute = <bool>::sse_decode(deserializer);
+        let mut var_autoRestart = <bool>::sse_decode(deserializer);
+        let mut var_stallTimeoutMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_maxRestartAttempts = <Option<u32>>::sse_decode(deserializer);
+        return crate::core::types::PlaybinConfig {
+            uri: var_uri,
+            mute: var_mute,
+            auto_restart: var_autoRestart,
+            stall_timeout_ms: var_stallTimeoutMs,
+            max_restart_attempts: var_maxRestartAttempts,
+        };
+    }
+}
+
+impl SseDecode for crate::core::types::PreEventBuffer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_durationMs = <u64>::sse_decode(deserializer);
+        let mut var_maxBytes = <Option<u64>>::sse_decode(deserializer);
+        return crate::core::types::PreEventBuffer {
+            duration_ms: var_durationMs,
+            max_bytes: var_maxBytes,
+        };
+    }
+}
+
+impl SseDecode for crate::core::types::PresentationPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_placeholder = <crate::core::types::PlaceholderFrame>::sse_decode(deserializer);
+        let mut var_onStop = <crate::core::types::EndFramePolicy>::sse_decode(deserializer);
+        let mut var_onError = <crate::core::types::EndFramePolicy>::sse_decode(deserializer);
+        let mut var_reconnectOverlayArgb = <Option<u32>>::sse_decode(deserializer);
+        return crate::core::types::PresentationPolicy {
+            placeholder: var_placeholder,
+            on_stop: var_onStop,
+            on_error: var_onError,
+            reconnect_overlay_argb: var_reconnectOverlayArgb,
+        };
+    }
+}
+
+impl SseDecode for crate::core::types::RecordingContainer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::core::types::RecordingContainer::Mp4,
+            1 => crate::core::types::RecordingContainer::Mkv,
+            _ => unreachable!("Invalid variant for RecordingContainer: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::dart_types::RecordingInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_files = <Vec<String>>::sse_decode(deserializer);
+        let mut var_durationMs = <u64>::sse_decode(deserializer);
+        let mut var_sizeBytes = <u64>::sse_decode(deserializer);
+        return crate::dart_types::RecordingInfo {
+            files: var_files,
+            duration_ms: var_durationMs,
+            size_bytes: var_sizeBytes,
+        };
+    }
+}
+
+impl SseDecode for crate::core::types::RecordingSegments {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_durationMs = <u64>::sse_decode(deserializer);
+        let mut var_maxFiles = <Option<u32>>::sse_decode(deserializer);
+        return crate::core::types::RecordingSegments {
+            duration_ms: var_durationMs,
+            max_files: var_maxFiles,
+        };
+    }
+}
+
+impl SseDecode for crate::core::types::Rotation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::core::types::Rotation::None,
+            1 => crate::core::types::Rotation::Clockwise90,
+            2 => crate::core::types::Rotation::Clockwise180,
+            3 => crate::core::types::Rotation::Clockwise270,
+            _ => unreachable!("Invalid variant for Rotation: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::core::types::RuntimeConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_workerThreads = <Option<u32>>::sse_decode(deserializer);
+        let mut var_threadName = <Option<String>>::sse_decode(deserializer);
+        return crate::core::types::RuntimeConfig {
+            worker_threads: var_workerThreads,
+            thread_name: var_threadName,
+        };
+    }
+}
+
+impl SseDecode for crate::core::types::ScaleMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::core::types::ScaleMode::Fit,
+            1 => crate::core::types::ScaleMode::Fill,
+            2 => crate::core::types::ScaleMode::Exact,
+            _ => unreachable!("Invalid variant for ScaleMode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::core::types::SessionOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_stallWatchdog =
+            <Option<crate::core::types::StallWatchdogConfig>>::sse_decode(deserializer);
+        let mut var_keepAlive =
+            <Option<crate::core::types::KeepAlivePolicy>>::sse_decode(deserializer);
+        let mut var_awaitFirstFrameTimeoutMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_shareDecode = <bool>::sse_decode(deserializer);
+        let mut var_standby = <bool>::sse_decode(deserializer);
+        let mut var_frameOutput = <crate::core::types::FrameOutputMode>::sse_decode(deserializer);
+        let mut var_presentation =
+            <crate::core::types::PresentationPolicy>::sse_decode(deserializer);
+        let mut var_maxFrameRate = <Option<u32>>::sse_decode(deserializer);
+        let mut var_preEventBuffer =
+            <Option<crate::core::types::PreEventBuffer>>::sse_decode(deserializer);
+        return crate::core::types::SessionOptions {
+            stall_watchdog: var_stallWatchdog,
+            keep_alive: var_keepAlive,
+            await_first_frame_timeout_ms: var_awaitFirstFrameTimeoutMs,
+            share_decode: var_shareDecode,
+            standby: var_standby,
+            frame_output: var_frameOutput,
+            presentation: var_presentation,
+            max_frame_rate: var_maxFrameRate,
+            pre_event_buffer: var_preEventBuffer,
+        };
+    }
+}
+
+impl SseDecode for crate::dart_types::Snapshot {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_data = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_ptsMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_capturedAtMs = <i64>::sse_decode(deserializer);
+        return crate::dart_types::Snapshot {
+            data: var_data,
+            width: var_width,
+            height: var_height,
+            pts_ms: var_ptsMs,
+            captured_at_ms: var_capturedAtMs,
+        };
+    }
+}
+
+impl SseDecode for crate::core::types::SnapshotFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::core::types::SnapshotFormat::Png,
+            1 => crate::core::types::SnapshotFormat::Jpeg,
+            _ => unreachable!("Invalid variant for SnapshotFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::core::types::StallWatchdogConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_stallThresholdMs = <u64>::sse_decode(deserializer);
+        let mut var_reconnectAfterMs = <Option<u64>>::sse_decode(deserializer);
+        return crate::core::types::StallWatchdogConfig {
+            stall_threshold_ms: var_stallThresholdMs,
+            reconnect_after_ms: var_reconnectAfterMs,
+        };
+    }
+}
+
+impl SseDecode for crate::dart_types::StartupReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_connectMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_handshakeMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_prerollMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_firstFrameMs = <u64>::sse_decode(deserializer);
+        let mut var_attempts = <u32>::sse_decode(deserializer);
+        return crate::dart_types::StartupReport {
+            connect_ms: var_connectMs,
+            handshake_ms: var_handshakeMs,
+            preroll_ms: var_prerollMs,
+            first_frame_ms: var_firstFrameMs,
+            attempts: var_attempts,
+        };
+    }
+}
+
+impl SseDecode for crate::dart_types::StreamError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_category = <crate::dart_types::ErrorCategory>::sse_decode(deserializer);
+        let mut var_retryable = <bool>::sse_decode(deserializer);
+        let mut var_backend = <crate::dart_types::ErrorBackend>::sse_decode(deserializer);
+        let mut var_cause = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        return crate::dart_types::StreamError {
+            category: var_category,
+            retryable: var_retryable,
+            backend: var_backend,
+            cause: var_cause,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for crate::dart_types::StreamEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <crate::dart_types::StreamError>::sse_decode(deserializer);
+                return crate::dart_types::StreamEvent::Error(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <i64>::sse_decode(deserializer);
+                return crate::dart_types::StreamEvent::CurrentTime(var_field0);
+            }
+            2 => {
+                let mut var_width = <u64>::sse_decode(deserializer);
+                let mut var_height = <u64>::sse_decode(deserializer);
+                let mut var_transformedWidth = <u64>::sse_decode(deserializer);
+                let mut var_transformedHeight = <u64>::sse_decode(deserializer);
+                let mut var_pixelAspectRatio = <f64>::sse_decode(deserializer);
+                let mut var_displayAspectRatio = <f64>::sse_decode(deserializer);
+                let mut var_frameRate = <f64>::sse_decode(deserializer);
+                return crate::dart_types::StreamEvent::OriginVideoSize {
+                    width: var_width,
+                    height: var_height,
+                    transformed_width: var_transformedWidth,
+                    transformed_height: var_transformedHeight,
+                    pixel_aspect_ratio: var_pixelAspectRatio,
+                    display_aspect_ratio: var_displayAspectRatio,
+                    frame_rate: var_frameRate,
+                };
+            }
+            3 => {
+                let mut var_field0 = <crate::dart_types::WscRtpMode>::sse_decode(deserializer);
+                return crate::dart_types::StreamEvent::WscRtpSessionMode(var_field0);
+            }
+            4 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::dart_types::StreamEvent::WscRtpStreamState(var_field0);
+            }
+            5 => {
+                let mut var_sinceLastFrameMs = <u64>::sse_decode(deserializer);
+                return crate::dart_types::StreamEvent::Stalled {
+                    since_last_frame_ms: var_sinceLastFrameMs,
+                };
+            }
+            6 => {
+                let mut var_stalledForMs = <u64>::sse_decode(deserializer);
+                return crate::dart_types::StreamEvent::Resumed {
+                    stalled_for_ms: var_stalledForMs,
+                };
+            }
+            7 => {
+                let mut var_field0 = <crate::dart_types::StartupReport>::sse_decode(deserializer);
+                return crate::dart_types::StreamEvent::StartupReport(var_field0);
+            }
+            8 => {
+                let mut var_field0 = <crate::dart_types::StreamError>::sse_decode(deserializer);
+                return crate::dart_types::StreamEvent::StartupFailed(var_field0);
+            }
+            9 => {
+                let mut var_attempt = <u32>::sse_decode(deserializer);
+                let mut var_delayMs = <u64>::sse_decode(deserializer);
+                return crate::dart_types::StreamEvent::Reconnecting {
+                    attempt: var_attempt,
+                    delay_ms: var_delayMs,
+                };
+            }
+            10 => {
+                let mut var_path = <String>::sse_decode(deserializer);
+                return crate::dart_types::StreamEvent::RecordingStarted { path: var_path };
+            }
+            11 => {
+                let mut var_field0 = <crate::dart_types::RecordingInfo>::sse_decode(deserializer);
+                return crate::dart_types::StreamEvent::RecordingStopped(var_field0);
+            }
+            12 => {
+                let mut var_field0 = <crate::dart_types::StreamError>::sse_decode(deserializer);
+                return crate::dart_types::StreamEvent::RecordingError(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::dart_types::StreamMessage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <crate::dart_types::StreamState>::sse_decode(deserializer);
+                return crate::dart_types::StreamMessage::State(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <crate::dart_types::StreamEvent>::sse_decode(deserializer);
+                return crate::dart_types::StreamMessage::Event(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::dart_types::StreamState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <crate::dart_types::StreamError>::sse_decode(deserializer);
+                return crate::dart_types::StreamState::Error(var_field0);
+            }
+            1 => {
+                return crate::dart_types::StreamState::Loading;
+            }
+            2 => {
+                let mut var_textureId = <i64>::sse_decode(deserializer);
+                let mut var_seekable = <bool>::sse_decode(deserializer);
+                return crate::dart_types::StreamState::Playing {
+                    texture_id: var_textureId,
+                    seekable: var_seekable,
+                };
+            }
+            3 => {
+                return crate::dart_types::StreamState::Stopped;
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::core::types::SwitchPlaceholder {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::core::types::SwitchPlaceholder::HoldLastFrame,
+            1 => crate::core::types::SwitchPlaceholder::Black,
+            _ => unreachable!("Invalid variant for SwitchPlaceholder: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::dart_types::TapFrame {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_data = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_format = <crate::core::types::TapPixelFormat>::sse_decode(deserializer);
+        let mut var_planeStrides = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_planeOffsets = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_ptsMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_capturedAtMs = <i64>::sse_decode(deserializer);
+        let mut var_sequence = <u64>::sse_decode(deserializer);
+        let mut var_dropped = <u64>::sse_decode(deserializer);
+        return crate::dart_types::TapFrame {
+            data: var_data,
+            width: var_width,
+            height: var_height,
+            format: var_format,
+            plane_strides: var_planeStrides,
+            plane_offsets: var_planeOffsets,
+            pts_ms: var_ptsMs,
+            captured_at_ms: var_capturedAtMs,
+            sequence: var_sequence,
+            dropped: var_dropped,
+        };
+    }
+}
+
+impl SseDecode for crate::core::types::TapPixelFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::core::types::TapPixelFormat::Rgba,
+            1 => crate::core::types::TapPixelFormat::Rgb,
+            2 => crate::core::types::TapPixelFormat::Nv12,
+            3 => crate::core::types::TapPixelFormat::Gray8,
+            _ => unreachable!("Invalid variant for TapPixelFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for crate::core::types::VideoConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 =
+                    <crate::core::types::WscRtpSessionConfig>::sse_decode(deserializer);
+                return crate::core::types::VideoConfig::WscRtp(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <crate::core::types::PlaybinConfig>::sse_decode(deserializer);
+                return crate::core::types::VideoConfig::Playbin(var_field0);
+            }
+            2 => {
+                let mut var_kind = <String>::sse_decode(deserializer);
+                let mut var_jsonParams = <String>::sse_decode(deserializer);
+                return crate::core::types::VideoConfig::Custom {
+                    kind: var_kind,
+                    json_params: var_jsonParams,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::core::types::VideoTransform {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_rotation = <crate::core::types::Rotation>::sse_decode(deserializer);
+        let mut var_flipHorizontal = <bool>::sse_decode(deserializer);
+        let mut var_flipVertical = <bool>::sse_decode(deserializer);
+        let mut var_crop = <Option<crate::core::types::CropRect>>::sse_decode(deserializer);
+        let mut var_zoom = <f64>::sse_decode(deserializer);
+        let mut var_panX = <f64>::sse_decode(deserializer);
+        let mut var_panY = <f64>::sse_decode(deserializer);
+        return crate::core::types::VideoTransform {
+            rotation: var_rotation,
+            flip_horizontal: var_flipHorizontal,
+            flip_vertical: var_flipVertical,
+            crop: var_crop,
+            zoom: var_zoom,
+            pan_x: var_panX,
+            pan_y: var_panY,
+        };
+    }
+}
+
+impl SseDecode for crate::dart_types::WscRtpMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::dart_types::WscRtpMode::Live;
+            }
+            1 => {
+                let mut var_currentTimeMs = <i64>::sse_decode(deserializer);
+                let mut var_speed = <f64>::sse_decode(deserializer);
+                return crate::dart_types::WscRtpMode::Dvr {
+                    current_time_ms: var_currentTimeMs,
+                    speed: var_speed,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::core::types::WscRtpSessionConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_baseUrl = <String>::sse_decode(deserializer);
+        let mut var_sourceId = <String>::sse_decode(deserializer);
+        let mut var_clientPort = <Option<u16>>::sse_decode(deserializer);
+        let mut var_forceWebsocketTransport = <bool>::sse_decode(deserializer);
+        let mut var_autoRestart = <bool>::sse_decode(deserializer);
+        return crate::core::types::WscRtpSessionConfig {
+            base_url: var_baseUrl,
+            source_id: var_sourceId,
+            client_port: var_clientPort,
+            force_websocket_transport: var_forceWebsocketTransport,
+            auto_restart: var_autoRestart,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__simple__capture_snapshot_impl(port, ptr, rust_vec_len, data_len),
+        2 => wire__crate__api__simple__capture_snapshot_to_file_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        3 => wire__crate__api__simple__create_new_session_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__simple__create_playable_impl(port, ptr, rust_vec_len, data_len),
+        5 => {
+            wire__crate__api__simple__destroy_engine_streams_impl(port, ptr, rust_vec_len, data_len)
+        }
+        6 => {
+            wire__crate__api__simple__destroy_stream_session_impl(port, ptr, rust_vec_len, data_len)
+        }
+        7 => wire__crate__api__simple__destroy_stream_session_and_wait_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        8 => wire__crate__api__simple__flutter_realtime_player_init_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        9 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        10 => wire__crate__api__simple__mark_session_alive_impl(port, ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__simple__next_frame_impl(port, ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__simple__promote_standby_session_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        13 => wire__crate__api__simple__reset_output_size_impl(port, ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__simple__save_pre_event_clip_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__simple__seek_to_timestamp_impl(port, ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__simple__set_digital_zoom_impl(port, ptr, rust_vec_len, data_len),
+        17 => {
+            wire__crate__api__simple__set_image_adjustments_impl(port, ptr, rust_vec_len, data_len)
+        }
+        18 => wire__crate__api__simple__set_max_frame_rate_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__simple__set_output_size_impl(port, ptr, rust_vec_len, data_len),
+        20 => {
+            wire__crate__api__simple__set_pre_event_buffer_impl(port, ptr, rust_vec_len, data_len)
+        }
+        21 => wire__crate__api__simple__set_presentation_policy_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        22 => wire__crate__api__simple__set_speed_impl(port, ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__simple__set_video_transform_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__simple__start_recording_impl(port, ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__simple__stop_recording_impl(port, ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__simple__subscribe_frames_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__simple__switch_source_impl(port, ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__simple__unsubscribe_frames_impl(port, ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__simple__wsc_rtp_go_live_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::types::CropRect {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.x.into_into_dart().into_dart(),
+            self.y.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::core::types::CropRect {}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::types::CropRect>
+    for crate::core::types::CropRect
+{
+    fn into_into_dart(self) -> crate::core::types::CropRect {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::types::DeinterlaceMethod {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Linear => 0.into_dart(),
+            Self::GreedyHigh => 1.into_dart(),
+            Self::GreedyLow => 2.into_dart(),
+            Self::Vfir => 3.into_dart(),
+            Self::ScalerBob => 4.into_dart(),
+            Self::Yadif => 5.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::types::DeinterlaceMethod
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::types::DeinterlaceMethod>
+    for crate::core::types::DeinterlaceMethod
+{
+    fn into_into_dart(self) -> crate::core::types::DeinterlaceMethod {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::types::DeinterlaceMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Auto => 0.into_dart(),
+            Self::On => 1.into_dart(),
+            Self::Off => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::types::DeinterlaceMode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::types::DeinterlaceMode>
+    for crate::core::types::DeinterlaceMode
+{
+    fn into_into_dart(self) -> crate::core::types::DeinterlaceMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::types::EndFramePolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::core::types::EndFramePolicy::HoldLastFrame => [0.into_dart()].into_dart(),
+            crate::core::types::EndFramePolicy::Dim { brightness_percent } => [
+                1.into_dart(),
+                brightness_percent.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::core::types::EndFramePolicy::Clear => [2.into_dart()].into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::types::EndFramePolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::types::EndFramePolicy>
+    for crate::core::types::EndFramePolicy
+{
+    fn into_into_dart(self) -> crate::core::types::EndFramePolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::dart_types::ErrorBackend {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::dart_types::ErrorBackend::WscRtp => [0.into_dart()].into_dart(),
+            crate::dart_types::ErrorBackend::Playbin => [1.into_dart()].into_dart(),
+            crate::dart_types::ErrorBackend::Registry => [2.into_dart()].into_dart(),
+            crate::dart_types::ErrorBackend::Recorder => [3.into_dart()].into_dart(),
+            crate::dart_types::ErrorBackend::Custom(field0) => {
+                [4.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::dart_types::ErrorBackend
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::dart_types::ErrorBackend>
+    for crate::dart_types::ErrorBackend
+{
+    fn into_into_dart(self) -> crate::dart_types::ErrorBackend {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::dart_types::ErrorCategory {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Network => 0.into_dart(),
+            Self::Timeout => 1.into_dart(),
+            Self::SourceNotFound => 2.into_dart(),
+            Self::AccessDenied => 3.into_dart(),
+            Self::InvalidUri => 4.into_dart(),
+            Self::UnsupportedCodec => 5.into_dart(),
+            Self::Decode => 6.into_dart(),
+            Self::Server => 7.into_dart(),
+            Self::Unavailable => 8.into_dart(),
+            Self::SessionNotFound => 9.into_dart(),
+            Self::Cancelled => 10.into_dart(),
+            Self::Internal => 11.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::dart_types::ErrorCategory
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::dart_types::ErrorCategory>
+    for crate::dart_types::ErrorCategory
+{
+    fn into_into_dart(self) -> crate::dart_types::ErrorCategory {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::types::FrameOutputMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::core::types::FrameOutputMode::FlutterTexture => [0.into_dart()].into_dart(),
+            crate::core::types::FrameOutputMode::Headless { history_len } => {
+                [1.into_dart(), history_len.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::types::FrameOutputMode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::types::FrameOutputMode>
+    for crate::core::types::FrameOutputMode
+{
+    fn into_into_dart(self) -> crate::core::types::FrameOutputMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::types::FrameTapConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.max_frame_rate.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.queue_len.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::types::FrameTapConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::types::FrameTapConfig>
+    for crate::core::types::FrameTapConfig
+{
+    fn into_into_dart(self) -> crate::core::types::FrameTapConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::types::ImageAdjustments {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.deinterlace.into_into_dart().into_dart(),
+            self.deinterlace_method.into_into_dart().into_dart(),
+            self.brightness.into_into_dart().into_dart(),
+            self.contrast.into_into_dart().into_dart(),
+            self.saturation.into_into_dart().into_dart(),
+            self.hue.into_into_dart().into_dart(),
+            self.gamma.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::types::ImageAdjustments
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::types::ImageAdjustments>
+    for crate::core::types::ImageAdjustments
+{
+    fn into_into_dart(self) -> crate::core::types::ImageAdjustments {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::types::KeepAlivePolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::core::types::KeepAlivePolicy::Timeout { timeout_ms } => {
+                [0.into_dart(), timeout_ms.into_into_dart().into_dart()].into_dart()
+            }
+            crate::core::types::KeepAlivePolicy::Disabled => [1.into_dart()].into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::types::KeepAlivePolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::types::KeepAlivePolicy>
+    for crate::core::types::KeepAlivePolicy
+{
+    fn into_into_dart(self) -> crate::core::types::KeepAlivePolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::types::OutputSize {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.mode.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::types::OutputSize
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::types::OutputSize>
+    for crate::core::types::OutputSize
+{
+    fn into_into_dart(self) -> crate::core::types::OutputSize {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::types::PlaceholderFrame {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::core::types::PlaceholderFrame::Black => [0.into_dart()].into_dart(),
+            crate::core::types::PlaceholderFrame::Transparent => [1.into_dart()].into_dart(),
+            crate::core::types::PlaceholderFrame::Rgba {
+                width,
+                height,
+                data,
+            } => [
+                2.into_dart(),
+                width.into_into_dart().into_dart(),
+                height.into_into_dart().into_dart(),
+                data.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::core::types::PlaceholderFrame::Poster { uri } => {
+                [3.into_dart(), uri.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::types::PlaceholderFrame
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::types::PlaceholderFrame>
+    for crate::core::types::PlaceholderFrame
+{
+    fn into_into_dart(self) -> crate::core::types::PlaceholderFrame {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::types::PlaybinConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.uri.into_into_dart().into_dart(),
+            self.mute.into_into_dart().into_dart(),
+            self.auto_restart.into_into_dart().into_dart(),
+            self.stall_timeout_ms.into_into_dart().into_dart(),
+            self.max_restart_attempts.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::types::PlaybinConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::types::PlaybinConfig>
+    for crate::core::types::PlaybinConfig
+{
+    fn into_into_dart(self) -> crate::core::types::PlaybinConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::types::PreEventBuffer {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.duration_ms.into_into_dart().into_dart(),
+            self.max_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::types::PreEventBuffer
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::types::PreEventBuffer>
+    for crate::core::types::PreEventBuffer
+{
+    fn into_into_dart(self) -> crate::core::types::PreEventBuffer {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::types::PresentationPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.placeholder.into_into_dart().into_dart(),
+            self.on_stop.into_into_dart().into_dart(),
+            self.on_error.into_into_dart().into_dart(),
+            self.reconnect_overlay_argb.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::types::PresentationPolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::types::PresentationPolicy>
+    for crate::core::types::PresentationPolicy
+{
+    fn into_into_dart(self) -> crate::core::types::PresentationPolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::types::RecordingContainer {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Mp4 => 0.into_dart(),
+            Self::Mkv => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::types::RecordingContainer
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::types::RecordingContainer>
+    for crate::core::types::RecordingContainer
+{
+    fn into_into_dart(self) -> crate::core::types::RecordingContainer {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::dart_types::RecordingInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.files.into_into_dart().into_dart(),
+            self.duration_ms.into_into_dart().into_dart(),
+            self.size_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::dart_types::RecordingInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::dart_types::RecordingInfo>
+    for crate::dart_types::RecordingInfo
+{
+    fn into_into_dart(self) -> crate::dart_types::RecordingInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::types::RecordingSegments {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.duration_ms.into_into_dart().into_dart(),
+            self.max_files.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::types::RecordingSegments
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::types::RecordingSegments>
+    for crate::core::types::RecordingSegments
+{
+    fn into_into_dart(self) -> crate::core::types::RecordingSegments {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::types::Rotation {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::None => 0.into_dart(),
+            Self::Clockwise90 => 1.into_dart(),
+            Self::Clockwise180 => 2.into_dart(),
+            Self::Clockwise270 => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::core::types::Rotation {}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::types::Rotation>
+    for crate::core::types::Rotation
+{
+    fn into_into_dart(self) -> crate::core::types::Rotation {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::types::RuntimeConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.worker_threads.into_into_dart().into_dart(),
+            self.thread_name.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::types::RuntimeConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::types::RuntimeConfig>
+    for crate::core::types::RuntimeConfig
+{
+    fn into_into_dart(self) -> crate::core::types::RuntimeConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::types::ScaleMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Fit => 0.into_dart(),
+            Self::Fill => 1.into_dart(),
+            Self::Exact => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::core::types::ScaleMode {}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::types::ScaleMode>
+    for crate::core::types::ScaleMode
+{
+    fn into_into_dart(self) -> crate::core::types::ScaleMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::types::SessionOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.stall_watchdog.into_into_dart().into_dart(),
+            self.keep_alive.into_into_dart().into_dart(),
+            self.await_first_frame_timeout_ms
+                .into_into_dart()
+                .into_dart(),
+            self.share_decode.into_into_dart().into_dart(),
+            self.standby.into_into_dart().into_dart(),
+            self.frame_output.into_into_dart().into_dart(),
+            self.presentation.into_into_dart().into_dart(),
+            self.max_frame_rate.into_into_dart().into_dart(),
+            self.pre_event_buffer.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::types::SessionOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::types::SessionOptions>
+    for crate::core::types::SessionOptions
+{
+    fn into_into_dart(self) -> crate::core::types::SessionOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::dart_types::Snapshot {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.data.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.pts_ms.into_into_dart().into_dart(),
+            self.captured_at_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::dart_types::Snapshot {}
+impl flutter_rust_bridge::IntoIntoDart<crate::dart_types::Snapshot>
+    for crate::dart_types::Snapshot
+{
+    fn into_into_dart(self) -> crate::dart_types::Snapshot {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::types::SnapshotFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Png => 0.into_dart(),
+            Self::Jpeg => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::types::SnapshotFormat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::types::SnapshotFormat>
+    for crate::core::types::SnapshotFormat
+{
+    fn into_into_dart(self) -> crate::core::types::SnapshotFormat {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::types::StallWatchdogConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.stall_threshold_ms.into_into_dart().into_dart(),
+            self.reconnect_after_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::types::StallWatchdogConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::types::StallWatchdogConfig>
+    for crate::core::types::StallWatchdogConfig
+{
+    fn into_into_dart(self) -> crate::core::types::StallWatchdogConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::dart_types::StartupReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.connect_ms.into_into_dart().into_dart(),
+            self.handshake_ms.into_into_dart().into_dart(),
+            self.preroll_ms.into_into_dart().into_dart(),
+            self.first_frame_ms.into_into_dart().into_dart(),
+            self.attempts.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::dart_types::StartupReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::dart_types::StartupReport>
+    for crate::dart_types::StartupReport
+{
+    fn into_into_dart(self) -> crate::dart_types::StartupReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::dart_types::StreamError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.category.into_into_dart().into_dart(),
+            self.retryable.into_into_dart().into_dart(),
+            self.backend.into_into_dart().into_dart(),
+            self.cause.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::dart_types::StreamError
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::dart_types::StreamError>
+    for crate::dart_types::StreamError
+{
+    fn into_into_dart(self) -> crate::dart_types::StreamError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::dart_types::StreamEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::dart_types::StreamEvent::Error(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::dart_types::StreamEvent::CurrentTime(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::dart_types::StreamEvent::OriginVideoSize {
+                width,
+                height,
+                transformed_width,
+                transformed_height,
+                pixel_aspect_ratio,
+                display_aspect_ratio,
+                frame_rate,
+            } => [
+                2.into_dart(),
+                width.into_into_dart().into_dart(),
+                height.into_into_dart().into_dart(),
+                transformed_width.into_into_dart().into_dart(),
+                transformed_height.into_into_dart().into_dart(),
+                pixel_aspect_ratio.into_into_dart().into_dart(),
+                display_aspect_ratio.into_into_dart().into_dart(),
+                frame_rate.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::dart_types::StreamEvent::WscRtpSessionMode(field0) => {
+                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::dart_types::StreamEvent::WscRtpStreamState(field0) => {
+                [4.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::dart_types::StreamEvent::Stalled {
+                since_last_frame_ms,
+            } => [
+                5.into_dart(),
+                since_last_frame_ms.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::dart_types::StreamEvent::Resumed { stalled_for_ms } => {
+                [6.into_dart(), stalled_for_ms.into_into_dart().into_dart()].into_dart()
+            }
+            crate::dart_types::StreamEvent::StartupReport(field0) => {
+                [7.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::dart_types::StreamEvent::StartupFailed(field0) => {
+                [8.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::dart_types::StreamEvent::Reconnecting { attempt, delay_ms } => [
+                9.into_dart(),
+                attempt.into_into_dart().into_dart(),
+                delay_ms.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::dart_types::StreamEvent::RecordingStarted { path } => {
+                [10.into_dart(), path.into_into_dart().into_dart()].into_dart()
+            }
+            crate::dart_types::StreamEvent::RecordingStopped(field0) => {
+                [11.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::dart_types::StreamEvent::RecordingError(field0) => {
+                [12.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::dart_types::StreamEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::dart_types::StreamEvent>
+    for crate::dart_types::StreamEvent
+{
+    fn into_into_dart(self) -> crate::dart_types::StreamEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::dart_types::StreamMessage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::dart_types::StreamMessage::State(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::dart_types::StreamMessage::Event(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::dart_types::StreamMessage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::dart_types::StreamMessage>
+    for crate::dart_types::StreamMessage
+{
+    fn into_into_dart(self) -> crate::dart_types::StreamMessage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::dart_types::StreamState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::dart_types::StreamState::Error(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::dart_types::StreamState::Loading => [1.into_dart()].into_dart(),
+            crate::dart_types::StreamState::Playing {
+                texture_id,
+                seekable,
+            } => [
+                2.into_dart(),
+                texture_id.into_into_dart().into_dart(),
+                seekable.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::dart_types::StreamState::Stopped => [3.into_dart()].into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::dart_types::StreamState
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::dart_types::StreamState>
+    for crate::dart_types::StreamState
+{
+    fn into_into_dart(self) -> crate::dart_types::StreamState {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::types::SwitchPlaceholder {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::HoldLastFrame => 0.into_dart(),
+            Self::Black => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::types::SwitchPlaceholder
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::types::SwitchPlaceholder>
+    for crate::core::types::SwitchPlaceholder
+{
+    fn into_into_dart(self) -> crate::core::types::SwitchPlaceholder {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::dart_types::TapFrame {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.data.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.plane_strides.into_into_dart().into_dart(),
+            self.plane_offsets.into_into_dart().into_dart(),
+            self.pts_ms.into_into_dart().into_dart(),
+            self.captured_at_ms.into_into_dart().into_dart(),
+            self.sequence.into_into_dart().into_dart(),
+            self.dropped.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::dart_types::TapFrame {}
+impl flutter_rust_bridge::IntoIntoDart<crate::dart_types::TapFrame>
+    for crate::dart_types::TapFrame
+{
+    fn into_into_dart(self) -> crate::dart_types::TapFrame {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::types::TapPixelFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Rgba => 0.into_dart(),
+            Self::Rgb => 1.into_dart(),
+            Self::Nv12 => 2.into_dart(),
+            Self::Gray8 => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::types::TapPixelFormat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::types::TapPixelFormat>
+    for crate::core::types::TapPixelFormat
+{
+    fn into_into_dart(self) -> crate::core::types::TapPixelFormat {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::types::VideoConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::core::types::VideoConfig::WscRtp(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::core::types::VideoConfig::Playbin(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::core::types::VideoConfig::Custom { kind, json_params } => [
+                2.into_dart(),
+                kind.into_into_dart().into_dart(),
+                json_params.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::types::VideoConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::types::VideoConfig>
+    for crate::core::types::VideoConfig
+{
+    fn into_into_dart(self) -> crate::core::types::VideoConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::types::VideoTransform {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.rotation.into_into_dart().into_dart(),
+            self.flip_horizontal.into_into_dart().into_dart(),
+            self.flip_vertical.into_into_dart().into_dart(),
+            self.crop.into_into_dart().into_dart(),
+            self.zoom.into_into_dart().into_dart(),
+            self.pan_x.into_into_dart().into_dart(),
+            self.pan_y.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::types::VideoTransform
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::types::VideoTransform>
+    for crate::core::types::VideoTransform
+{
+    fn into_into_dart(self) -> crate::core::types::VideoTransform {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::dart_types::WscRtpMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::dart_types::WscRtpMode::Live => [0.into_dart()].into_dart(),
+            crate::dart_types::WscRtpMode::Dvr {
+                current_time_ms,
+                speed,
+            } => [
+                1.into_dart(),
+                current_time_ms.into_into_dart().into_dart(),
+                speed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::dart_types::WscRtpMode {}
+impl flutter_rust_bridge::IntoIntoDart<crate::dart_types::WscRtpMode>
+    for crate::dart_types::WscRtpMode
+{
+    fn into_into_dart(self) -> crate::dart_types::WscRtpMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::types::WscRtpSessionConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.base_url.into_into_dart().into_dart(),
+            self.source_id.into_into_dart().into_dart(),
+            self.client_port.into_into_dart().into_dart(),
+            self.force_websocket_transport.into_into_dart().into_dart(),
+            self.auto_restart.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::types::WscRtpSessionConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::types::WscRtpSessionConfig>
+    for crate::core::types::WscRtpSessionConfig
+{
+    fn into_into_dart(self) -> crate::core::types::WscRtpSessionConfig {
+        self
     }
 }
 
-impl SseDecode for i64 {
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode
+    for StreamSink<crate::dart_types::StreamMessage, flutter_rust_bridge::for_generated::SseCodec>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for Option<u16> {
+impl SseEncode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u16>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
     }
 }
 
-impl SseDecode for crate::core::types::PlaybinConfig {
+impl SseEncode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_uri = <String>::sse_decode(deserializer);
-        let mut var_mute = <bool>::sse_decode(deserializer);
-        return crate::core::types::PlaybinConfig {
-            uri: var_uri,
-            mute: var_mute,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
     }
 }
 
-impl SseDecode for crate::dart_types::StreamEvent {
+impl SseEncode for crate::core::types::CropRect {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::dart_types::StreamEvent::Error(var_field0);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.x, serializer);
+        <f64>::sse_encode(self.y, serializer);
+        <f64>::sse_encode(self.width, serializer);
+        <f64>::sse_encode(self.height, serializer);
+    }
+}
+
+impl SseEncode for crate::core::types::DeinterlaceMethod {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::core::types::DeinterlaceMethod::Linear => 0,
+                crate::core::types::DeinterlaceMethod::GreedyHigh => 1,
+                crate::core::types::DeinterlaceMethod::GreedyLow => 2,
+                crate::core::types::DeinterlaceMethod::Vfir => 3,
+                crate::core::types::DeinterlaceMethod::ScalerBob => 4,
+                crate::core::types::DeinterlaceMethod::Yadif => 5,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::core::types::DeinterlaceMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::core::types::DeinterlaceMode::Auto => 0,
+                crate::core::types::DeinterlaceMode::On => 1,
+                crate::core::types::DeinterlaceMode::Off => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::core::types::EndFramePolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::core::types::EndFramePolicy::HoldLastFrame => {
+                <i32>::sse_encode(0, serializer);
             }
-            1 => {
-                let mut var_field0 = <i64>::sse_decode(deserializer);
-                return crate::dart_types::StreamEvent::CurrentTime(var_field0);
+            crate::core::types::EndFramePolicy::Dim { brightness_percent } => {
+                <i32>::sse_encode(1, serializer);
+                <u8>::sse_encode(brightness_percent, serializer);
             }
-            2 => {
-                let mut var_width = <u64>::sse_decode(deserializer);
-                let mut var_height = <u64>::sse_decode(deserializer);
-                return crate::dart_types::StreamEvent::OriginVideoSize {
-                    width: var_width,
-                    height: var_height,
-                };
+            crate::core::types::EndFramePolicy::Clear => {
+                <i32>::sse_encode(2, serializer);
             }
-            3 => {
-                let mut var_field0 = <crate::dart_types::WscRtpMode>::sse_decode(deserializer);
-                return crate::dart_types::StreamEvent::WscRtpSessionMode(var_field0);
+            _ => {
+                unimplemented!("");
             }
-            4 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::dart_types::StreamEvent::WscRtpStreamState(var_field0);
+        }
+    }
+}
+
+impl SseEncode for crate::dart_types::ErrorBackend {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::dart_types::ErrorBackend::WscRtp => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::dart_types::ErrorBackend::Playbin => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::dart_types::ErrorBackend::Registry => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::dart_types::ErrorBackend::Recorder => {
+                <i32>::sse_encode(3, serializer);
+            }
+            crate::dart_types::ErrorBackend::Custom(field0) => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(field0, serializer);
             }
             _ => {
                 unimplemented!("");
@@ -532,18 +3472,49 @@ impl SseDecode for crate::dart_types::StreamEvent {
     }
 }
 
-impl SseDecode for crate::dart_types::StreamMessage {
+impl SseEncode for crate::dart_types::ErrorCategory {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::dart_types::ErrorCategory::Network => 0,
+                crate::dart_types::ErrorCategory::Timeout => 1,
+                crate::dart_types::ErrorCategory::SourceNotFound => 2,
+                crate::dart_types::ErrorCategory::AccessDenied => 3,
+                crate::dart_types::ErrorCategory::InvalidUri => 4,
+                crate::dart_types::ErrorCategory::UnsupportedCodec => 5,
+                crate::dart_types::ErrorCategory::Decode => 6,
+                crate::dart_types::ErrorCategory::Server => 7,
+                crate::dart_types::ErrorCategory::Unavailable => 8,
+                crate::dart_types::ErrorCategory::SessionNotFound => 9,
+                crate::dart_types::ErrorCategory::Cancelled => 10,
+                crate::dart_types::ErrorCategory::Internal => 11,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::core::types::FrameOutputMode {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                let mut var_field0 = <crate::dart_types::StreamState>::sse_decode(deserializer);
-                return crate::dart_types::StreamMessage::State(var_field0);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::core::types::FrameOutputMode::FlutterTexture => {
+                <i32>::sse_encode(0, serializer);
             }
-            1 => {
-                let mut var_field0 = <crate::dart_types::StreamEvent>::sse_decode(deserializer);
-                return crate::dart_types::StreamMessage::Event(var_field0);
+            crate::core::types::FrameOutputMode::Headless { history_len } => {
+                <i32>::sse_encode(1, serializer);
+                <u32>::sse_encode(history_len, serializer);
             }
             _ => {
                 unimplemented!("");
@@ -552,28 +3523,46 @@ impl SseDecode for crate::dart_types::StreamMessage {
     }
 }
 
-impl SseDecode for crate::dart_types::StreamState {
+impl SseEncode for crate::core::types::FrameTapConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::dart_types::StreamState::Error(var_field0);
-            }
-            1 => {
-                return crate::dart_types::StreamState::Loading;
-            }
-            2 => {
-                let mut var_textureId = <i64>::sse_decode(deserializer);
-                let mut var_seekable = <bool>::sse_decode(deserializer);
-                return crate::dart_types::StreamState::Playing {
-                    texture_id: var_textureId,
-                    seekable: var_seekable,
-                };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<u32>>::sse_encode(self.max_frame_rate, serializer);
+        <Option<crate::core::types::OutputSize>>::sse_encode(self.size, serializer);
+        <crate::core::types::TapPixelFormat>::sse_encode(self.format, serializer);
+        <u32>::sse_encode(self.queue_len, serializer);
+    }
+}
+
+impl SseEncode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::core::types::ImageAdjustments {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::core::types::DeinterlaceMode>::sse_encode(self.deinterlace, serializer);
+        <crate::core::types::DeinterlaceMethod>::sse_encode(self.deinterlace_method, serializer);
+        <f64>::sse_encode(self.brightness, serializer);
+        <f64>::sse_encode(self.contrast, serializer);
+        <f64>::sse_encode(self.saturation, serializer);
+        <f64>::sse_encode(self.hue, serializer);
+        <f64>::sse_encode(self.gamma, serializer);
+    }
+}
+
+impl SseEncode for crate::core::types::KeepAlivePolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::core::types::KeepAlivePolicy::Timeout { timeout_ms } => {
+                <i32>::sse_encode(0, serializer);
+                <u64>::sse_encode(timeout_ms, serializer);
             }
-            3 => {
-                return crate::dart_types::StreamState::Stopped;
+            crate::core::types::KeepAlivePolicy::Disabled => {
+                <i32>::sse_encode(1, serializer);
             }
             _ => {
                 unimplemented!("");
@@ -582,278 +3571,238 @@ impl SseDecode for crate::dart_types::StreamState {
     }
 }
 
-impl SseDecode for u16 {
+impl SseEncode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for u64 {
+impl SseEncode for Vec<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u32>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
+    }
 }
 
-impl SseDecode for crate::core::types::VideoConfig {
+impl SseEncode for Option<crate::core::types::CropRect> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                let mut var_field0 =
-                    <crate::core::types::WscRtpSessionConfig>::sse_decode(deserializer);
-                return crate::core::types::VideoConfig::WscRtp(var_field0);
-            }
-            1 => {
-                let mut var_field0 = <crate::core::types::PlaybinConfig>::sse_decode(deserializer);
-                return crate::core::types::VideoConfig::Playbin(var_field0);
-            }
-            _ => {
-                unimplemented!("");
-            }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::core::types::CropRect>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseDecode for crate::dart_types::WscRtpMode {
+impl SseEncode for Option<crate::core::types::FrameTapConfig> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                return crate::dart_types::WscRtpMode::Live;
-            }
-            1 => {
-                let mut var_currentTimeMs = <i64>::sse_decode(deserializer);
-                let mut var_speed = <f64>::sse_decode(deserializer);
-                return crate::dart_types::WscRtpMode::Dvr {
-                    current_time_ms: var_currentTimeMs,
-                    speed: var_speed,
-                };
-            }
-            _ => {
-                unimplemented!("");
-            }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::core::types::FrameTapConfig>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseDecode for crate::core::types::WscRtpSessionConfig {
+impl SseEncode for Option<crate::core::types::KeepAlivePolicy> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_baseUrl = <String>::sse_decode(deserializer);
-        let mut var_sourceId = <String>::sse_decode(deserializer);
-        let mut var_clientPort = <Option<u16>>::sse_decode(deserializer);
-        let mut var_forceWebsocketTransport = <bool>::sse_decode(deserializer);
-        let mut var_autoRestart = <bool>::sse_decode(deserializer);
-        return crate::core::types::WscRtpSessionConfig {
-            base_url: var_baseUrl,
-            source_id: var_sourceId,
-            client_port: var_clientPort,
-            force_websocket_transport: var_forceWebsocketTransport,
-            auto_restart: var_autoRestart,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::core::types::KeepAlivePolicy>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for Option<crate::core::types::OutputSize> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::core::types::OutputSize>::sse_encode(value, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__simple__create_new_session_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__simple__create_playable_impl(port, ptr, rust_vec_len, data_len),
-        3 => {
-            wire__crate__api__simple__destroy_engine_streams_impl(port, ptr, rust_vec_len, data_len)
-        }
-        4 => {
-            wire__crate__api__simple__destroy_stream_session_impl(port, ptr, rust_vec_len, data_len)
+impl SseEncode for Option<crate::core::types::PreEventBuffer> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::core::types::PreEventBuffer>::sse_encode(value, serializer);
         }
-        5 => wire__crate__api__simple__flutter_realtime_player_init_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        6 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__simple__mark_session_alive_impl(port, ptr, rust_vec_len, data_len),
-        8 => wire__crate__api__simple__seek_to_timestamp_impl(port, ptr, rust_vec_len, data_len),
-        9 => wire__crate__api__simple__set_speed_impl(port, ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__simple__wsc_rtp_go_live_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        _ => unreachable!(),
+impl SseEncode for Option<crate::core::types::RecordingContainer> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::core::types::RecordingContainer>::sse_encode(value, serializer);
+        }
     }
 }
 
-// Section: rust2dart
+impl SseEncode for Option<crate::core::types::RecordingSegments> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::core::types::RecordingSegments>::sse_encode(value, serializer);
+        }
+    }
+}
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::core::types::PlaybinConfig {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.uri.into_into_dart().into_dart(),
-            self.mute.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+impl SseEncode for Option<crate::core::types::RuntimeConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::core::types::RuntimeConfig>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::core::types::PlaybinConfig
-{
+
+impl SseEncode for Option<crate::core::types::SessionOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::core::types::SessionOptions>::sse_encode(value, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::core::types::PlaybinConfig>
-    for crate::core::types::PlaybinConfig
-{
-    fn into_into_dart(self) -> crate::core::types::PlaybinConfig {
-        self
+
+impl SseEncode for Option<crate::core::types::StallWatchdogConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::core::types::StallWatchdogConfig>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::dart_types::StreamEvent {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            crate::dart_types::StreamEvent::Error(field0) => {
-                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::dart_types::StreamEvent::CurrentTime(field0) => {
-                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::dart_types::StreamEvent::OriginVideoSize { width, height } => [
-                2.into_dart(),
-                width.into_into_dart().into_dart(),
-                height.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
-            crate::dart_types::StreamEvent::WscRtpSessionMode(field0) => {
-                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::dart_types::StreamEvent::WscRtpStreamState(field0) => {
-                [4.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            _ => {
-                unimplemented!("");
-            }
+
+impl SseEncode for Option<crate::core::types::SwitchPlaceholder> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::core::types::SwitchPlaceholder>::sse_encode(value, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::dart_types::StreamEvent
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::dart_types::StreamEvent>
-    for crate::dart_types::StreamEvent
-{
-    fn into_into_dart(self) -> crate::dart_types::StreamEvent {
-        self
+
+impl SseEncode for Option<crate::dart_types::TapFrame> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::dart_types::TapFrame>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::dart_types::StreamMessage {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            crate::dart_types::StreamMessage::State(field0) => {
-                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::dart_types::StreamMessage::Event(field0) => {
-                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            _ => {
-                unimplemented!("");
-            }
+
+impl SseEncode for Option<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u16>::sse_encode(value, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::dart_types::StreamMessage
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::dart_types::StreamMessage>
-    for crate::dart_types::StreamMessage
-{
-    fn into_into_dart(self) -> crate::dart_types::StreamMessage {
-        self
+
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::dart_types::StreamState {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            crate::dart_types::StreamState::Error(field0) => {
-                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::dart_types::StreamState::Loading => [1.into_dart()].into_dart(),
-            crate::dart_types::StreamState::Playing {
-                texture_id,
-                seekable,
-            } => [
-                2.into_dart(),
-                texture_id.into_into_dart().into_dart(),
-                seekable.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
-            crate::dart_types::StreamState::Stopped => [3.into_dart()].into_dart(),
-            _ => {
-                unimplemented!("");
-            }
+
+impl SseEncode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::dart_types::StreamState
-{
+
+impl SseEncode for Option<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u8>::sse_encode(value, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::dart_types::StreamState>
-    for crate::dart_types::StreamState
-{
-    fn into_into_dart(self) -> crate::dart_types::StreamState {
-        self
+
+impl SseEncode for crate::core::types::OutputSize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <crate::core::types::ScaleMode>::sse_encode(self.mode, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::core::types::VideoConfig {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+
+impl SseEncode for crate::core::types::PlaceholderFrame {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         match self {
-            crate::core::types::VideoConfig::WscRtp(field0) => {
-                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            crate::core::types::PlaceholderFrame::Black => {
+                <i32>::sse_encode(0, serializer);
             }
-            crate::core::types::VideoConfig::Playbin(field0) => {
-                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            crate::core::types::PlaceholderFrame::Transparent => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::core::types::PlaceholderFrame::Rgba {
+                width,
+                height,
+                data,
+            } => {
+                <i32>::sse_encode(2, serializer);
+                <u32>::sse_encode(width, serializer);
+                <u32>::sse_encode(height, serializer);
+                <Vec<u8>>::sse_encode(data, serializer);
+            }
+            crate::core::types::PlaceholderFrame::Poster { uri } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(uri, serializer);
             }
             _ => {
                 unimplemented!("");
@@ -861,139 +3810,184 @@ impl flutter_rust_bridge::IntoDart for crate::core::types::VideoConfig {
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::core::types::VideoConfig
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::core::types::VideoConfig>
-    for crate::core::types::VideoConfig
-{
-    fn into_into_dart(self) -> crate::core::types::VideoConfig {
-        self
+
+impl SseEncode for crate::core::types::PlaybinConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.uri, serializer);
+        <bool>::sse_encode(self.mute, serializer);
+        <bool>::sse_encode(self.auto_restart, serializer);
+        <Option<u64>>::sse_encode(self.stall_timeout_ms, serializer);
+        <Option<u32>>::sse_encode(self.max_restart_attempts, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::dart_types::WscRtpMode {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            crate::dart_types::WscRtpMode::Live => [0.into_dart()].into_dart(),
-            crate::dart_types::WscRtpMode::Dvr {
-                current_time_ms,
-                speed,
-            } => [
-                1.into_dart(),
-                current_time_ms.into_into_dart().into_dart(),
-                speed.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
-            _ => {
-                unimplemented!("");
-            }
-        }
+
+impl SseEncode for crate::core::types::PreEventBuffer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.duration_ms, serializer);
+        <Option<u64>>::sse_encode(self.max_bytes, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::dart_types::WscRtpMode {}
-impl flutter_rust_bridge::IntoIntoDart<crate::dart_types::WscRtpMode>
-    for crate::dart_types::WscRtpMode
-{
-    fn into_into_dart(self) -> crate::dart_types::WscRtpMode {
-        self
+
+impl SseEncode for crate::core::types::PresentationPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::core::types::PlaceholderFrame>::sse_encode(self.placeholder, serializer);
+        <crate::core::types::EndFramePolicy>::sse_encode(self.on_stop, serializer);
+        <crate::core::types::EndFramePolicy>::sse_encode(self.on_error, serializer);
+        <Option<u32>>::sse_encode(self.reconnect_overlay_argb, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::core::types::WscRtpSessionConfig {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.base_url.into_into_dart().into_dart(),
-            self.source_id.into_into_dart().into_dart(),
-            self.client_port.into_into_dart().into_dart(),
-            self.force_websocket_transport.into_into_dart().into_dart(),
-            self.auto_restart.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::core::types::RecordingContainer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::core::types::RecordingContainer::Mp4 => 0,
+                crate::core::types::RecordingContainer::Mkv => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::core::types::WscRtpSessionConfig
-{
+
+impl SseEncode for crate::dart_types::RecordingInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.files, serializer);
+        <u64>::sse_encode(self.duration_ms, serializer);
+        <u64>::sse_encode(self.size_bytes, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::core::types::WscRtpSessionConfig>
-    for crate::core::types::WscRtpSessionConfig
-{
-    fn into_into_dart(self) -> crate::core::types::WscRtpSessionConfig {
-        self
+
+impl SseEncode for crate::core::types::RecordingSegments {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.duration_ms, serializer);
+        <Option<u32>>::sse_encode(self.max_files, serializer);
     }
 }
 
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+impl SseEncode for crate::core::types::Rotation {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::core::types::Rotation::None => 0,
+                crate::core::types::Rotation::Clockwise90 => 1,
+                crate::core::types::Rotation::Clockwise180 => 2,
+                crate::core::types::Rotation::Clockwise270 => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode
-    for StreamSink<crate::dart_types::StreamMessage, flutter_rust_bridge::for_generated::SseCodec>
-{
+impl SseEncode for crate::core::types::RuntimeConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        unimplemented!("")
+        <Option<u32>>::sse_encode(self.worker_threads, serializer);
+        <Option<String>>::sse_encode(self.thread_name, serializer);
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for crate::core::types::ScaleMode {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::core::types::ScaleMode::Fit => 0,
+                crate::core::types::ScaleMode::Fill => 1,
+                crate::core::types::ScaleMode::Exact => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::core::types::SessionOptions {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <Option<crate::core::types::StallWatchdogConfig>>::sse_encode(
+            self.stall_watchdog,
+            serializer,
+        );
+        <Option<crate::core::types::KeepAlivePolicy>>::sse_encode(self.keep_alive, serializer);
+        <Option<u64>>::sse_encode(self.await_first_frame_timeout_ms, serializer);
+        <bool>::sse_encode(self.share_decode, serializer);
+        <bool>::sse_encode(self.standby, serializer);
+        <crate::core::types::FrameOutputMode>::sse_encode(self.frame_output, serializer);
+        <crate::core::types::PresentationPolicy>::sse_encode(self.presentation, serializer);
+        <Option<u32>>::sse_encode(self.max_frame_rate, serializer);
+        <Option<crate::core::types::PreEventBuffer>>::sse_encode(self.pre_event_buffer, serializer);
     }
 }
 
-impl SseEncode for f64 {
+impl SseEncode for crate::dart_types::Snapshot {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+        <Vec<u8>>::sse_encode(self.data, serializer);
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <Option<u64>>::sse_encode(self.pts_ms, serializer);
+        <i64>::sse_encode(self.captured_at_ms, serializer);
     }
 }
 
-impl SseEncode for i64 {
+impl SseEncode for crate::core::types::SnapshotFormat {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(
+            match self {
+                crate::core::types::SnapshotFormat::Png => 0,
+                crate::core::types::SnapshotFormat::Jpeg => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for crate::core::types::StallWatchdogConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+        <u64>::sse_encode(self.stall_threshold_ms, serializer);
+        <Option<u64>>::sse_encode(self.reconnect_after_ms, serializer);
     }
 }
 
-impl SseEncode for Option<u16> {
+impl SseEncode for crate::dart_types::StartupReport {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <u16>::sse_encode(value, serializer);
-        }
+        <Option<u64>>::sse_encode(self.connect_ms, serializer);
+        <Option<u64>>::sse_encode(self.handshake_ms, serializer);
+        <Option<u64>>::sse_encode(self.preroll_ms, serializer);
+        <u64>::sse_encode(self.first_frame_ms, serializer);
+        <u32>::sse_encode(self.attempts, serializer);
     }
 }
 
-impl SseEncode for crate::core::types::PlaybinConfig {
+impl SseEncode for crate::dart_types::StreamError {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.uri, serializer);
-        <bool>::sse_encode(self.mute, serializer);
+        <crate::dart_types::ErrorCategory>::sse_encode(self.category, serializer);
+        <bool>::sse_encode(self.retryable, serializer);
+        <crate::dart_types::ErrorBackend>::sse_encode(self.backend, serializer);
+        <Option<String>>::sse_encode(self.cause, serializer);
+        <String>::sse_encode(self.message, serializer);
     }
 }
 
@@ -1003,16 +3997,29 @@ impl SseEncode for crate::dart_types::StreamEvent {
         match self {
             crate::dart_types::StreamEvent::Error(field0) => {
                 <i32>::sse_encode(0, serializer);
-                <String>::sse_encode(field0, serializer);
+                <crate::dart_types::StreamError>::sse_encode(field0, serializer);
             }
             crate::dart_types::StreamEvent::CurrentTime(field0) => {
                 <i32>::sse_encode(1, serializer);
                 <i64>::sse_encode(field0, serializer);
             }
-            crate::dart_types::StreamEvent::OriginVideoSize { width, height } => {
+            crate::dart_types::StreamEvent::OriginVideoSize {
+                width,
+                height,
+                transformed_width,
+                transformed_height,
+                pixel_aspect_ratio,
+                display_aspect_ratio,
+                frame_rate,
+            } => {
                 <i32>::sse_encode(2, serializer);
                 <u64>::sse_encode(width, serializer);
                 <u64>::sse_encode(height, serializer);
+                <u64>::sse_encode(transformed_width, serializer);
+                <u64>::sse_encode(transformed_height, serializer);
+                <f64>::sse_encode(pixel_aspect_ratio, serializer);
+                <f64>::sse_encode(display_aspect_ratio, serializer);
+                <f64>::sse_encode(frame_rate, serializer);
             }
             crate::dart_types::StreamEvent::WscRtpSessionMode(field0) => {
                 <i32>::sse_encode(3, serializer);
@@ -1022,6 +4029,41 @@ impl SseEncode for crate::dart_types::StreamEvent {
                 <i32>::sse_encode(4, serializer);
                 <String>::sse_encode(field0, serializer);
             }
+            crate::dart_types::StreamEvent::Stalled {
+                since_last_frame_ms,
+            } => {
+                <i32>::sse_encode(5, serializer);
+                <u64>::sse_encode(since_last_frame_ms, serializer);
+            }
+            crate::dart_types::StreamEvent::Resumed { stalled_for_ms } => {
+                <i32>::sse_encode(6, serializer);
+                <u64>::sse_encode(stalled_for_ms, serializer);
+            }
+            crate::dart_types::StreamEvent::StartupReport(field0) => {
+                <i32>::sse_encode(7, serializer);
+                <crate::dart_types::StartupReport>::sse_encode(field0, serializer);
+            }
+            crate::dart_types::StreamEvent::StartupFailed(field0) => {
+                <i32>::sse_encode(8, serializer);
+                <crate::dart_types::StreamError>::sse_encode(field0, serializer);
+            }
+            crate::dart_types::StreamEvent::Reconnecting { attempt, delay_ms } => {
+                <i32>::sse_encode(9, serializer);
+                <u32>::sse_encode(attempt, serializer);
+                <u64>::sse_encode(delay_ms, serializer);
+            }
+            crate::dart_types::StreamEvent::RecordingStarted { path } => {
+                <i32>::sse_encode(10, serializer);
+                <String>::sse_encode(path, serializer);
+            }
+            crate::dart_types::StreamEvent::RecordingStopped(field0) => {
+                <i32>::sse_encode(11, serializer);
+                <crate::dart_types::RecordingInfo>::sse_encode(field0, serializer);
+            }
+            crate::dart_types::StreamEvent::RecordingError(field0) => {
+                <i32>::sse_encode(12, serializer);
+                <crate::dart_types::StreamError>::sse_encode(field0, serializer);
+            }
             _ => {
                 unimplemented!("");
             }
@@ -1054,7 +4096,7 @@ impl SseEncode for crate::dart_types::StreamState {
         match self {
             crate::dart_types::StreamState::Error(field0) => {
                 <i32>::sse_encode(0, serializer);
-                <String>::sse_encode(field0, serializer);
+                <crate::dart_types::StreamError>::sse_encode(field0, serializer);
             }
             crate::dart_types::StreamState::Loading => {
                 <i32>::sse_encode(1, serializer);
@@ -1077,6 +4119,56 @@ impl SseEncode for crate::dart_types::StreamState {
     }
 }
 
+impl SseEncode for crate::core::types::SwitchPlaceholder {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::core::types::SwitchPlaceholder::HoldLastFrame => 0,
+                crate::core::types::SwitchPlaceholder::Black => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::dart_types::TapFrame {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.data, serializer);
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <crate::core::types::TapPixelFormat>::sse_encode(self.format, serializer);
+        <Vec<u32>>::sse_encode(self.plane_strides, serializer);
+        <Vec<u32>>::sse_encode(self.plane_offsets, serializer);
+        <Option<u64>>::sse_encode(self.pts_ms, serializer);
+        <i64>::sse_encode(self.captured_at_ms, serializer);
+        <u64>::sse_encode(self.sequence, serializer);
+        <u64>::sse_encode(self.dropped, serializer);
+    }
+}
+
+impl SseEncode for crate::core::types::TapPixelFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::core::types::TapPixelFormat::Rgba => 0,
+                crate::core::types::TapPixelFormat::Rgb => 1,
+                crate::core::types::TapPixelFormat::Nv12 => 2,
+                crate::core::types::TapPixelFormat::Gray8 => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for u16 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1084,6 +4176,13 @@ impl SseEncode for u16 {
     }
 }
 
+impl SseEncode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u32::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for u64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1115,6 +4214,11 @@ impl SseEncode for crate::core::types::VideoConfig {
                 <i32>::sse_encode(1, serializer);
                 <crate::core::types::PlaybinConfig>::sse_encode(field0, serializer);
             }
+            crate::core::types::VideoConfig::Custom { kind, json_params } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(kind, serializer);
+                <String>::sse_encode(json_params, serializer);
+            }
             _ => {
                 unimplemented!("");
             }
@@ -1122,6 +4226,19 @@ impl SseEncode for crate::core::types::VideoConfig {
     }
 }
 
+impl SseEncode for crate::core::types::VideoTransform {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::core::types::Rotation>::sse_encode(self.rotation, serializer);
+        <bool>::sse_encode(self.flip_horizontal, serializer);
+        <bool>::sse_encode(self.flip_vertical, serializer);
+        <Option<crate::core::types::CropRect>>::sse_encode(self.crop, serializer);
+        <f64>::sse_encode(self.zoom, serializer);
+        <f64>::sse_encode(self.pan_x, serializer);
+        <f64>::sse_encode(self.pan_y, serializer);
+    }
+}
+
 impl SseEncode for crate::dart_types::WscRtpMode {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2,4 +2,5 @@ pub mod api;
 pub mod core;
 pub mod dart_types;
 mod frb_generated; /* AUTO INJECTED BY flutter_rust_bridge. This line may not be accurate, and you can change it according to your needs. */
+pub mod native;
 pub mod utils;
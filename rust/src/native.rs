@@ -0,0 +1,365 @@
+//! Plain Rust API for hosts that don't go through flutter_rust_bridge (other Rust
+//! applications, integration tests). It drives the same registry as `api::simple`;
+//! state and events arrive on a channel or any `MessageSink` instead of a Dart stream.
+
+use std::{sync::Arc, time::Duration};
+
+use crate::{
+    core::{
+        output::headless::HeadlessFrameSink,
+        session::registry,
+        types::{
+            FrameTapConfig, ImageAdjustments, OutputSize, PreEventBuffer, PresentationPolicy,
+            RecordingContainer, RecordingSegments, RuntimeConfig, SessionOptions, SnapshotFormat,
+            SwitchPlaceholder, VideoConfig, VideoTransform,
+        },
+    },
+    dart_types::{RecordingInfo, Snapshot, StreamMessage, TapFrame},
+};
+
+pub use crate::core::{
+    output::frame_tap::{FrameTapSink, SharedFrameTapSink},
+    session::sink::{MessageSink, SharedMessageSink},
+};
+
+/// Starts GStreamer, the runtime and the session supervisor. Later calls are ignored.
+pub fn init(runtime_config: RuntimeConfig) {
+    crate::core::init_services(&runtime_config, || {});
+}
+
+pub fn new_session_id() -> i64 {
+    registry::next_session_id()
+}
+
+/// Creates a session whose state and events go to `sink`.
+pub async fn create_session(
+    session_id: i64,
+    engine_handle: i64,
+    config: VideoConfig,
+    options: SessionOptions,
+    sink: impl MessageSink + 'static,
+) -> anyhow::Result<()> {
+    registry::create_session(session_id, engine_handle, config, options, Arc::new(sink)).await
+}
+
+/// Creates a session and returns the receiving end of its state and events.
+pub async fn subscribe_session(
+    session_id: i64,
+    engine_handle: i64,
+    config: VideoConfig,
+    options: SessionOptions,
+) -> anyhow::Result<flume::Receiver<StreamMessage>> {
+    let (tx, rx) = flume::unbounded();
+    create_session(session_id, engine_handle, config, options, tx).await?;
+    Ok(rx)
+}
+
+/// Like `subscribe_session`, with a tokio channel.
+pub async fn subscribe_session_tokio(
+    session_id: i64,
+    engine_handle: i64,
+    config: VideoConfig,
+    options: SessionOptions,
+) -> anyhow::Result<tokio::sync::mpsc::UnboundedReceiver<StreamMessage>> {
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+    create_session(session_id, engine_handle, config, options, tx).await?;
+    Ok(rx)
+}
+
+/// Frames of a session created with `FrameOutputMode::Headless`.
+pub fn headless_output(session_id: i64) -> Option<Arc<HeadlessFrameSink>> {
+    registry::headless_output(session_id)
+}
+
+pub async fn await_first_frame(session_id: i64, timeout: Duration) -> anyhow::Result<()> {
+    registry::await_first_frame(session_id, timeout).await
+}
+
+pub fn switch_source(
+    session_id: i64,
+    config: VideoConfig,
+    placeholder: SwitchPlaceholder,
+) -> anyhow::Result<()> {
+    registry::switch_source(session_id, config, placeholder)
+}
+
+/// `None` restores the decoded size.
+pub fn set_output_size(session_id: i64, output_size: Option<OutputSize>) -> anyhow::Result<()> {
+    registry::set_output_size_session(session_id, output_size)
+}
+
+pub fn set_video_transform(session_id: i64, transform: VideoTransform) -> anyhow::Result<()> {
+    registry::set_video_transform_session(session_id, transform)
+}
+
+pub fn set_digital_zoom(session_id: i64, zoom: f64, pan_x: f64, pan_y: f64) -> anyhow::Result<()> {
+    registry::set_digital_zoom_session(session_id, zoom, pan_x, pan_y)
+}
+
+pub fn set_max_frame_rate(session_id: i64, max_frame_rate: Option<u32>) -> anyhow::Result<()> {
+    registry::set_max_frame_rate_session(session_id, max_frame_rate)
+}
+
+pub fn set_image_adjustments(session_id: i64, adjustments: ImageAdjustments) -> anyhow::Result<()> {
+    registry::set_image_adjustments_session(session_id, adjustments)
+}
+
+/// Delivers the session's frames to `sink` (e.g. a callback wrapper running a model)
+/// from the tap's own thread; returns the tap id.
+pub fn subscribe_frames_to(
+    session_id: i64,
+    config: FrameTapConfig,
+    sink: impl FrameTapSink + 'static,
+) -> anyhow::Result<i64> {
+    registry::add_frame_tap_session(session_id, config, Arc::new(sink))
+}
+
+/// Calls `callback` with each of the session's frames from the tap's own thread; frames
+/// arriving while it runs wait in a queue of `config.queue_len`, further ones are dropped.
+pub fn subscribe_frames_with(
+    session_id: i64,
+    config: FrameTapConfig,
+    callback: impl Fn(TapFrame) + Send + Sync + 'static,
+) -> anyhow::Result<i64> {
+    subscribe_frames_to(session_id, config, CallbackSink(callback))
+}
+
+struct CallbackSink<F>(F);
+
+impl<F: Fn(TapFrame) + Send + Sync> FrameTapSink for CallbackSink<F> {
+    fn send(&self, frame: TapFrame) -> anyhow::Result<()> {
+        (self.0)(frame);
+        Ok(())
+    }
+}
+
+/// Returns the tap id and a channel bounded to `config.queue_len` receiving the session's frames.
+pub fn subscribe_frames(
+    session_id: i64,
+    config: FrameTapConfig,
+) -> anyhow::Result<(i64, flume::Receiver<TapFrame>)> {
+    let (tx, rx) = flume::bounded(config.queue_len.max(1) as usize);
+    let tap_id = subscribe_frames_to(session_id, config, tx)?;
+    Ok((tap_id, rx))
+}
+
+pub fn unsubscribe_frames(session_id: i64, tap_id: i64) -> anyhow::Result<()> {
+    registry::remove_frame_tap_session(session_id, tap_id)
+}
+
+pub fn set_presentation_policy(session_id: i64, policy: PresentationPolicy) -> anyhow::Result<()> {
+    registry::set_presentation_session(session_id, policy)
+}
+
+/// See `api::simple::capture_snapshot`; with `path` the image is written there instead.
+pub async fn capture_snapshot(
+    session_id: i64,
+    format: SnapshotFormat,
+    quality: Option<u8>,
+    max_size: Option<u32>,
+    full_resolution: bool,
+    path: Option<String>,
+) -> anyhow::Result<Snapshot> {
+    registry::capture_snapshot(session_id, format, quality, max_size, full_resolution, path).await
+}
+
+/// See `api::simple::start_recording`.
+pub fn start_recording(
+    session_id: i64,
+    path: String,
+    container: RecordingContainer,
+    max_duration_ms: Option<u64>,
+    max_size_bytes: Option<u64>,
+    segments: Option<RecordingSegments>,
+) -> anyhow::Result<()> {
+    registry::start_recording_session(
+        session_id,
+        path,
+        container,
+        max_duration_ms,
+        max_size_bytes,
+        segments,
+    )
+}
+
+pub async fn stop_recording(session_id: i64) -> anyhow::Result<RecordingInfo> {
+    registry::stop_recording_session(session_id).await
+}
+
+pub fn set_pre_event_buffer(session_id: i64, config: Option<PreEventBuffer>) -> anyhow::Result<()> {
+    registry::set_pre_event_buffer_session(session_id, config)
+}
+
+/// See `api::simple::save_pre_event_clip`.
+pub async fn save_pre_event_clip(
+    session_id: i64,
+    path: String,
+    post_event_ms: u64,
+    container: RecordingContainer,
+) -> anyhow::Result<RecordingInfo> {
+    registry::save_pre_event_clip_session(session_id, path, container, post_event_ms).await
+}
+
+pub async fn seek(session_id: i64, ts: u64) -> anyhow::Result<()> {
+    registry::seek_session(session_id, ts).await
+}
+
+pub async fn go_live(session_id: i64) -> anyhow::Result<()> {
+    registry::wsc_rtp_live_session(session_id).await
+}
+
+pub async fn set_speed(session_id: i64, speed: f64) -> anyhow::Result<()> {
+    registry::set_speed_session(session_id, speed).await
+}
+
+pub fn promote_standby_session(
+    standby_session_id: i64,
+    target_session_id: i64,
+) -> anyhow::Result<()> {
+    registry::promote_standby_session(standby_session_id, target_session_id)
+}
+
+pub fn mark_alive(session_id: i64) {
+    registry::mark_session_alive(session_id);
+}
+
+pub fn destroy(session_id: i64) {
+    registry::destroy_stream_session(session_id);
+}
+
+pub async fn destroy_and_wait(session_id: i64, timeout: Duration) -> anyhow::Result<()> {
+    registry::destroy_stream_session_and_wait(session_id, timeout).await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use gst::prelude::*;
+
+    use super::*;
+    use crate::{
+        core::{
+            runtime,
+            types::{FrameOutputMode, KeepAlivePolicy, PlaybinConfig, ScaleMode},
+        },
+        dart_types::StreamState,
+    };
+
+    const STATE_TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// Three seconds of 320x240 test pattern, written with elements from gst-plugins-good.
+    fn write_test_clip() -> PathBuf {
+        let path = std::env::temp_dir().join(format!("headless_test_{}.avi", std::process::id()));
+        let pipeline = gst::parse::launch(&format!(
+            "videotestsrc num-buffers=90 ! video/x-raw,width=320,height=240,framerate=30/1 \
+             ! jpegenc ! avimux ! filesink location={}",
+            path.display()
+        ))
+        .unwrap();
+        pipeline.set_state(gst::State::Playing).unwrap();
+        let message = pipeline
+            .bus()
+            .unwrap()
+            .timed_pop_filtered(
+                gst::ClockTime::from_seconds(10),
+                &[gst::MessageType::Eos, gst::MessageType::Error],
+            )
+            .expect("writing the test clip timed out");
+        pipeline.set_state(gst::State::Null).unwrap();
+        assert!(
+            matches!(message.view(), gst::MessageView::Eos(_)),
+            "writing the test clip failed: {:?}",
+            message
+        );
+        path
+    }
+
+    fn file_uri(path: &Path) -> String {
+        url::Url::from_file_path(path).unwrap().to_string()
+    }
+
+    async fn next_state(rx: &flume::Receiver<StreamMessage>) -> StreamState {
+        loop {
+            let message = tokio::time::timeout(STATE_TIMEOUT, rx.recv_async())
+                .await
+                .expect("no state change")
+                .expect("session sink closed");
+            if let StreamMessage::State(state) = message {
+                return state;
+            }
+        }
+    }
+
+    #[test]
+    fn headless_playbin_plays_a_file_to_the_end() {
+        init(RuntimeConfig::default());
+        let path = write_test_clip();
+        let session_id = new_session_id();
+        let config = VideoConfig::Playbin(PlaybinConfig {
+            uri: file_uri(&path),
+            mute: true,
+            auto_restart: true,
+            stall_timeout_ms: None,
+            max_restart_attempts: None,
+        });
+        let options = SessionOptions {
+            keep_alive: Some(KeepAlivePolicy::Disabled),
+            frame_output: FrameOutputMode::Headless { history_len: 0 },
+            ..Default::default()
+        };
+
+        runtime::runtime().block_on(async {
+            let rx = subscribe_session(session_id, 0, config, options)
+                .await
+                .unwrap();
+            let output = headless_output(session_id).expect("headless output");
+
+            let mut state = next_state(&rx).await;
+            while matches!(state, StreamState::Loading) {
+                state = next_state(&rx).await;
+            }
+            assert!(
+                matches!(state, StreamState::Playing { texture_id: -1, .. }),
+                "unexpected state {:?}",
+                state
+            );
+            output.wait_for_frames(5, STATE_TIMEOUT).await.unwrap();
+            let frame = output.latest_frame().unwrap();
+            assert_eq!((frame.width, frame.height), (320, 240));
+
+            set_output_size(
+                session_id,
+                Some(OutputSize {
+                    width: 160,
+                    height: 160,
+                    mode: ScaleMode::Fill,
+                }),
+            )
+            .unwrap();
+            let resized = tokio::time::timeout(STATE_TIMEOUT, async {
+                loop {
+                    let count = output.frame_count();
+                    output
+                        .wait_for_frames(count + 1, STATE_TIMEOUT)
+                        .await
+                        .unwrap();
+                    let frame = output.latest_frame().unwrap();
+                    if (frame.width, frame.height) != (320, 240) {
+                        return frame;
+                    }
+                }
+            })
+            .await
+            .expect("output size not applied");
+            assert_eq!((resized.width, resized.height), (160, 160));
+            assert_eq!(resized.data.len(), 160 * 160 * 4);
+
+            // a finite file stops at EOS even with auto_restart
+            assert!(matches!(next_state(&rx).await, StreamState::Stopped));
+
+            destroy_and_wait(session_id, STATE_TIMEOUT).await.unwrap();
+        });
+        let _ = std::fs::remove_file(path);
+    }
+}
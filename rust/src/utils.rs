@@ -1,5 +1,6 @@
 use irondash_run_loop::RunLoop;
 use log::{error, trace};
+use tokio::sync::oneshot;
 
 /// Inboke the given function on the flutter engine main thread.
 pub(crate) fn invoke_on_platform_main_thread<F, T>(func: F) -> T
@@ -21,6 +22,31 @@ where
         })
 }
 
+/// Run the given function on the flutter engine main thread without blocking the caller,
+/// e.g. from a tokio worker. The returned receiver resolves with its result.
+pub(crate) fn spawn_on_platform_main_thread<F, T>(func: F) -> oneshot::Receiver<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let (tx, rx) = oneshot::channel();
+    if RunLoop::is_main_thread().unwrap_or(false) {
+        trace!("spawn_on_platform_main_thread: already on main thread");
+        let _ = tx.send(func());
+        return rx;
+    }
+
+    trace!("spawn_on_platform_main_thread: sending to main thread");
+    RunLoop::sender_for_main_thread()
+        .expect("failed to get main thread sender")
+        .send(move || {
+            trace!("in main thread");
+            // the caller may not wait for the result
+            let _ = tx.send(func());
+        });
+    rx
+}
+
 #[allow(unused)]
 pub(crate) fn is_fl_main_thread() -> bool {
     RunLoop::is_main_thread().unwrap_or(false)